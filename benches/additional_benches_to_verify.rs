@@ -4,7 +4,7 @@ use rand::Rng;
 
 #[allow(dead_code)]
 fn abs_vs_equality_benchmarks(c: &mut Criterion) {
-    let test_cases = vec![
+    let test_cases = [
         (1.0f64, 1.0f64),                // Identical values
         (1.0f64, 1.0f64 + f64::EPSILON), // Nearly identical values
         (1.0f64, 2.0f64),                // Significantly different values
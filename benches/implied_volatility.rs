@@ -1,4 +1,4 @@
-use blackscholes::{ImpliedVolatility, Inputs, OptionType};
+use blackscholes::{calc_rational_iv_batch, ImpliedVolatility, Inputs, OptionType};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 const INPUTS: Inputs = Inputs {
@@ -16,6 +16,14 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("calc_rational_iv", |b| {
         b.iter(|| black_box(INPUTS.calc_rational_iv()))
     });
+
+    let chain = vec![INPUTS; 10_000];
+    c.bench_function("calc_rational_iv_batch", |b| {
+        b.iter(|| black_box(calc_rational_iv_batch(&chain, 1)))
+    });
+    c.bench_function("calc_rational_iv_batch parallel", |b| {
+        b.iter(|| black_box(calc_rational_iv_batch(&chain, 4)))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,179 @@
+use crate::{implied_volatility::rational_iv, numerics::brent, Inputs, OptionType};
+
+/// Default number of steps in the binomial tree.
+pub const DEFAULT_TREE_STEPS: usize = 200;
+
+/// Highest volatility searched when matching the tree to an American premium.
+const MAXIMUM_VOLATILITY: f64 = 5.0;
+const TOLERANCE: f64 = 1e-10;
+const MAX_ITERATIONS: usize = 100;
+
+/// An American option, priced on a Cox-Ross-Rubinstein binomial tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmericanInputs {
+    /// The option type, strike, market parameters and, for implied volatility, the American premium `p`
+    pub underlying: Inputs,
+    /// Number of steps in the binomial tree
+    pub steps: usize,
+}
+
+/// Implied volatility of an American premium after removing its early-exercise premium.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AmericanIv {
+    /// "Let's be rational" implied volatility of the European-equivalent premium
+    pub sigma: f64,
+    /// The American premium less the early-exercise premium
+    pub european_price: f64,
+    /// Value of the right to exercise early, removed from the American premium
+    pub early_exercise_premium: f64,
+}
+
+impl AmericanInputs {
+    /// Creates instance of the `AmericanInputs` struct.
+    /// # Arguments
+    /// * `underlying` - The option type, strike and market parameters.
+    /// * `steps` - The number of steps in the binomial tree.
+    /// # Example
+    /// ```
+    /// use blackscholes::{AmericanInputs, Inputs, OptionType, DEFAULT_TREE_STEPS};
+    /// let underlying = Inputs::new(OptionType::Put, 100.0, 100.0, Some(6.2), 0.05, 0.0, 0.5, None);
+    /// let inputs = AmericanInputs::new(underlying, DEFAULT_TREE_STEPS);
+    /// ```
+    /// # Returns
+    /// An instance of the `AmericanInputs` struct.
+    pub fn new(underlying: Inputs, steps: usize) -> Self {
+        Self { underlying, steps }
+    }
+
+    /// American and European values of the option at volatility `sigma` on the same tree.
+    fn tree(&self, sigma: f64) -> Result<(f64, f64), String> {
+        let Inputs {
+            option_type,
+            s,
+            k,
+            r,
+            q,
+            t,
+            ..
+        } = self.underlying;
+        if self.steps == 0 {
+            return Err("Binomial tree requires at least one step".to_string());
+        }
+        if t <= 0.0 || sigma <= 0.0 {
+            return Err(
+                "Binomial tree requires positive time to maturity and volatility".to_string(),
+            );
+        }
+        let dt = t / self.steps as f64;
+        let up = (sigma * dt.sqrt()).exp();
+        let down = 1.0 / up;
+        let probability = (((r - q) * dt).exp() - down) / (up - down);
+        if !(0.0..=1.0).contains(&probability) {
+            return Err("Volatility is too low for the carry over one tree step".to_string());
+        }
+        let discount = (-r * dt).exp();
+        let payoff = |spot: f64| match option_type {
+            OptionType::Call => (spot - k).max(0.0),
+            OptionType::Put => (k - spot).max(0.0),
+        };
+
+        let mut american: Vec<f64> = (0..=self.steps)
+            .map(|i| payoff(s * up.powi(2 * i as i32 - self.steps as i32)))
+            .collect();
+        let mut european = american.clone();
+        for step in (0..self.steps).rev() {
+            for i in 0..=step {
+                let spot = s * up.powi(2 * i as i32 - step as i32);
+                let continuation =
+                    discount * (probability * american[i + 1] + (1.0 - probability) * american[i]);
+                american[i] = continuation.max(payoff(spot));
+                european[i] =
+                    discount * (probability * european[i + 1] + (1.0 - probability) * european[i]);
+            }
+        }
+        Ok((american[0], european[0]))
+    }
+
+    /// Calculates the price of the American option on the binomial tree.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying.
+    /// # Returns
+    /// f64 of the American price.
+    /// # Example
+    /// ```
+    /// use blackscholes::{AmericanInputs, Inputs, OptionType, Pricing, DEFAULT_TREE_STEPS};
+    /// let underlying = Inputs::new(OptionType::Put, 100.0, 100.0, None, 0.05, 0.0, 0.5, Some(0.25));
+    /// let american = AmericanInputs::new(underlying.clone(), DEFAULT_TREE_STEPS)
+    ///     .calc_price()
+    ///     .unwrap();
+    /// assert!(american > underlying.calc_price().unwrap());
+    /// ```
+    pub fn calc_price(&self) -> Result<f64, String> {
+        let sigma = self
+            .underlying
+            .sigma
+            .ok_or("Expected Some(f64) for self.sigma, received None")?;
+        Ok(self.tree(sigma)?.0)
+    }
+
+    /// Calculates the implied volatility of the American premium `p` by de-Americanisation: the tree is matched to
+    /// the premium, the early-exercise premium it implies (American less European value on the same tree) is
+    /// subtracted, and the resulting European-equivalent premium is inverted with the "Let's be rational" method.
+    /// Taking the difference of two values on one tree cancels most of the discretisation error.
+    /// # Requires
+    /// s, k, r, q, t, p of the underlying. p must lie above the value of immediate exercise.
+    /// # Returns
+    /// AmericanIv of the implied volatility, the European-equivalent premium and the early-exercise premium.
+    /// # Example
+    /// ```
+    /// use blackscholes::{AmericanInputs, Inputs, OptionType, DEFAULT_TREE_STEPS};
+    /// let underlying = Inputs::new(OptionType::Put, 100.0, 100.0, Some(6.2), 0.05, 0.0, 0.5, None);
+    /// let iv = AmericanInputs::new(underlying, DEFAULT_TREE_STEPS)
+    ///     .calc_american_iv()
+    ///     .unwrap();
+    /// assert!(iv.early_exercise_premium > 0.0);
+    /// ```
+    pub fn calc_american_iv(&self) -> Result<AmericanIv, String> {
+        let p = self
+            .underlying
+            .p
+            .ok_or("inputs.p must contain Some(f64), found None".to_string())?;
+        let Inputs { r, q, t, .. } = self.underlying;
+        if self.steps == 0 || t <= 0.0 {
+            return Err(
+                "Binomial tree requires at least one step and positive time to maturity"
+                    .to_string(),
+            );
+        }
+
+        // Twice the lowest volatility at which the tree's probabilities stay within [0, 1].
+        let dt = t / self.steps as f64;
+        let lower = (2.0 * (r - q).abs() * dt.sqrt()).max(1e-4);
+        let error = |sigma: f64| Ok(self.tree(sigma)?.0 - p);
+        if error(lower)? >= 0.0 {
+            return Err(
+                "Price is at or below the value of the option at the lowest volatility".to_string(),
+            );
+        }
+        if error(MAXIMUM_VOLATILITY)? <= 0.0 {
+            return Err(
+                "Price is above the value of the option at the highest volatility".to_string(),
+            );
+        }
+        let american_sigma = brent(error, lower, MAXIMUM_VOLATILITY, TOLERANCE, MAX_ITERATIONS)?;
+
+        let (american, european) = self.tree(american_sigma)?;
+        let early_exercise_premium = (american - european).max(0.0);
+        let european_price = p - early_exercise_premium;
+        let sigma = rational_iv(&Inputs {
+            p: Some(european_price),
+            ..self.underlying.clone()
+        })
+        .map_err(|kind| kind.to_string())?;
+        Ok(AmericanIv {
+            sigma,
+            european_price,
+            early_exercise_premium,
+        })
+    }
+}
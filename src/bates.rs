@@ -0,0 +1,208 @@
+use num_complex::Complex64;
+
+use crate::{
+    characteristic_function::levy_characteristic_function, numerics::nelder_mead,
+    CharacteristicFunction, CosMethod, ImpliedVolatility, Inputs, OptionType,
+};
+
+/// Convergence tolerance on the mean squared implied volatility error during calibration.
+const CALIBRATION_TOLERANCE: f64 = 1e-14;
+const CALIBRATION_ITERATIONS: usize = 5_000;
+
+/// Heston (1993) stochastic volatility: the variance follows the square-root process
+/// `dv = kappa (theta - v) dt + xi sqrt(v) dW` with correlation `rho` to the price.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Heston {
+    /// Initial variance
+    pub v0: f64,
+    /// Speed of mean reversion of the variance
+    pub kappa: f64,
+    /// Long-run variance
+    pub theta: f64,
+    /// Volatility of the variance
+    pub xi: f64,
+    /// Correlation between the price and variance shocks
+    pub rho: f64,
+}
+
+/// Bates (1996): Heston stochastic volatility plus lognormal Merton jumps in the price.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bates {
+    /// The stochastic volatility part of the model
+    pub heston: Heston,
+    /// Jump intensity, the expected number of jumps per year
+    pub lambda: f64,
+    /// Mean of the log jump size
+    pub jump_mean: f64,
+    /// Standard deviation of the log jump size
+    pub jump_vol: f64,
+}
+
+/// The result of fitting a [`Bates`] model to an implied volatility grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatesCalibration {
+    /// The fitted model
+    pub model: Bates,
+    /// Root mean squared difference between model and market implied volatilities
+    pub rmse: f64,
+    /// Number of optimiser iterations used
+    pub iterations: usize,
+    /// Whether the optimiser met its tolerance within the iteration limit; if not, the best fit found is returned
+    pub converged: bool,
+}
+
+impl Heston {
+    /// Integrated variance over `[0, t]` when the variance is deterministic (`xi = 0`).
+    fn integrated_variance(&self, t: f64) -> f64 {
+        if self.kappa == 0.0 {
+            self.v0 * t
+        } else {
+            self.theta * t + (self.v0 - self.theta) * (1.0 - (-self.kappa * t).exp()) / self.kappa
+        }
+    }
+}
+
+impl CharacteristicFunction for Heston {
+    /// Uses the formulation of Albrecher et al. (2007), "The little Heston trap",
+    /// which keeps the complex logarithm on its principal branch.
+    fn calc_characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        if self.xi == 0.0 {
+            return (-0.5 * self.integrated_variance(t) * (u * u + i * u)).exp();
+        }
+        let xi2 = self.xi * self.xi;
+        let beta = self.kappa - self.rho * self.xi * i * u;
+        let d = (beta * beta + xi2 * (u * u + i * u)).sqrt();
+        let g = (beta - d) / (beta + d);
+        let decay = (-d * t).exp();
+        let c = self.kappa * self.theta / xi2
+            * ((beta - d) * t - 2.0 * ((1.0 - g * decay) / (1.0 - g)).ln());
+        let d_term = (beta - d) / xi2 * (1.0 - decay) / (1.0 - g * decay);
+        (c + d_term * self.v0).exp()
+    }
+}
+
+impl CharacteristicFunction for Bates {
+    fn calc_characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        let psi = |u: Complex64| {
+            self.lambda
+                * ((i * u * self.jump_mean - 0.5 * self.jump_vol * self.jump_vol * u * u).exp()
+                    - 1.0)
+        };
+        self.heston.calc_characteristic_function(u, t) * levy_characteristic_function(psi, u, t)
+    }
+}
+
+impl Bates {
+    /// Fits all eight parameters to an implied volatility grid.
+    /// Minimises the squared differences between model and market implied volatilities,
+    /// with model prices from the default [`CosMethod`] on out-of-the-money options
+    /// and model volatilities backed out using `calc_rational_iv`.
+    /// # Arguments
+    /// * `underlying` - The market parameters s, r and q; the option type, strike, maturity and volatility are ignored.
+    /// * `maturities` - The maturities of the grid rows.
+    /// * `strikes` - The strikes of the grid columns.
+    /// * `vols` - The market implied volatilities, one row per maturity and one column per strike.
+    /// # Returns
+    /// BatesCalibration with the fitted model, the implied volatility fit error, the iterations used and whether the fit converged.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Bates, Inputs, OptionType};
+    /// let market = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.02, 0.0, 0.0, None);
+    /// let maturities = [0.5];
+    /// let strikes = [90.0, 100.0, 110.0];
+    /// let vols = [vec![0.24, 0.2, 0.18]];
+    /// let calibration = Bates::calibrate(&market, &maturities, &strikes, &vols).unwrap();
+    /// assert!(calibration.rmse < 0.01);
+    /// ```
+    pub fn calibrate(
+        underlying: &Inputs,
+        maturities: &[f64],
+        strikes: &[f64],
+        vols: &[Vec<f64>],
+    ) -> Result<BatesCalibration, String> {
+        if maturities.is_empty()
+            || strikes.is_empty()
+            || vols.len() != maturities.len()
+            || vols.iter().any(|row| row.len() != strikes.len())
+        {
+            return Err("Each maturity and strike requires exactly one volatility".to_string());
+        }
+        let engine = CosMethod::default();
+        // Positive parameters are optimised on a log scale and the correlation through tanh.
+        let model = |x: &[f64]| Bates {
+            heston: Heston {
+                v0: x[0].exp(),
+                kappa: x[1].exp(),
+                theta: x[2].exp(),
+                xi: x[3].exp(),
+                rho: x[4].tanh(),
+            },
+            lambda: x[5].exp(),
+            jump_mean: x[6],
+            jump_vol: x[7].exp(),
+        };
+        let objective = |x: &[f64]| -> Result<f64, String> {
+            let bates = model(x);
+            let mut sum = 0.0;
+            for (&t, row) in maturities.iter().zip(vols) {
+                let forward = underlying.s * ((underlying.r - underlying.q) * t).exp();
+                for (&k, &vol) in strikes.iter().zip(row) {
+                    let option_type = if k < forward {
+                        OptionType::Put
+                    } else {
+                        OptionType::Call
+                    };
+                    let mut priced = Inputs {
+                        option_type,
+                        k,
+                        t,
+                        p: None,
+                        sigma: None,
+                        ..underlying.clone()
+                    };
+                    // Prices the engine or the inversion cannot handle are penalised rather than aborting the fit.
+                    let error = match engine.calc_price(&bates, &priced) {
+                        Ok(price) => {
+                            priced.p = Some(price);
+                            match priced.calc_rational_iv() {
+                                Ok(model_vol) if model_vol.is_finite() => model_vol - vol,
+                                _ => 1.0,
+                            }
+                        }
+                        Err(_) => 1.0,
+                    };
+                    sum += error * error;
+                }
+            }
+            Ok(sum / (maturities.len() * strikes.len()) as f64)
+        };
+        // Start from a flat term structure at the average market variance with moderate skew and jumps.
+        let count = (maturities.len() * strikes.len()) as f64;
+        let variance = (vols.iter().flatten().map(|vol| vol * vol).sum::<f64>() / count).ln();
+        let start = [
+            variance,
+            1.0_f64.ln(),
+            variance,
+            0.5_f64.ln(),
+            -0.5,
+            0.1_f64.ln(),
+            -0.1,
+            0.1_f64.ln(),
+        ];
+        let minimum = nelder_mead(
+            objective,
+            &start,
+            &[0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.1, 0.5],
+            CALIBRATION_TOLERANCE,
+            CALIBRATION_ITERATIONS,
+        )?;
+        Ok(BatesCalibration {
+            model: model(&minimum.point),
+            rmse: minimum.value.sqrt(),
+            iterations: minimum.iterations,
+            converged: minimum.converged,
+        })
+    }
+}
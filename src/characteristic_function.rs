@@ -0,0 +1,139 @@
+use num_complex::Complex64;
+use statrs::function::gamma::gamma;
+
+/// Step used to differentiate the cumulant generating function numerically.
+const CUMULANT_STEP: f64 = 0.01;
+
+/// The characteristic function of a pricing model, which is all the Fourier engines
+/// ([`CarrMadan`](crate::CarrMadan) and [`CosMethod`](crate::CosMethod)) need to price European options.
+///
+/// Models describe the log price relative to the forward, `X = ln(S_T / F_T)`, so `E[e^X] = 1`
+/// and rates and dividends are handled by the engines.
+pub trait CharacteristicFunction {
+    /// Calculates `E[exp(i u X)]` for `X = ln(S_T / F_T)` at maturity `t`.
+    fn calc_characteristic_function(&self, u: Complex64, t: f64) -> Complex64;
+
+    /// Calculates the first, second and fourth cumulants of `X` at maturity `t`,
+    /// used by the COS method to choose its truncation range.
+    /// Defaults to finite differences of the cumulant generating function `ln E[e^(vX)]`.
+    fn calc_cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let k = |v: f64| {
+            self.calc_characteristic_function(Complex64::new(0.0, -v), t)
+                .re
+                .ln()
+        };
+        let h = CUMULANT_STEP;
+        let (k0, k1, k_1, k2, k_2) = (k(0.0), k(h), k(-h), k(2.0 * h), k(-2.0 * h));
+        let c1 = (k1 - k_1) / (2.0 * h);
+        let c2 = (k1 - 2.0 * k0 + k_1) / (h * h);
+        let c4 = (k2 - 4.0 * k1 + 6.0 * k0 - 4.0 * k_1 + k_2) / h.powi(4);
+        (c1, c2, c4)
+    }
+}
+
+/// Characteristic function of a Levy process with characteristic exponent `psi`,
+/// made a martingale by removing `psi(-i)` from the drift.
+pub(crate) fn levy_characteristic_function<F>(psi: F, u: Complex64, t: f64) -> Complex64
+where
+    F: Fn(Complex64) -> Complex64,
+{
+    let i = Complex64::i();
+    (t * (psi(u) - i * u * psi(-i))).exp()
+}
+
+/// Black-Scholes-Merton: the log price is normal with variance `sigma^2 t`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BsmModel {
+    /// Volatility
+    pub sigma: f64,
+}
+
+impl CharacteristicFunction for BsmModel {
+    fn calc_characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        (-0.5 * self.sigma * self.sigma * t * (u * u + i * u)).exp()
+    }
+
+    fn calc_cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let variance = self.sigma * self.sigma * t;
+        (-0.5 * variance, variance, 0.0)
+    }
+}
+
+/// Variance Gamma (Madan, Carr & Chang, 1998): Brownian motion with drift `theta` and volatility `sigma`
+/// evaluated at a gamma time change with variance rate `nu`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VarianceGamma {
+    /// Volatility of the Brownian motion
+    pub sigma: f64,
+    /// Variance rate of the gamma time change, controlling kurtosis
+    pub nu: f64,
+    /// Drift of the Brownian motion, controlling skewness
+    pub theta: f64,
+}
+
+impl CharacteristicFunction for VarianceGamma {
+    fn calc_characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        let psi = |u: Complex64| {
+            -(1.0 - i * u * self.theta * self.nu + 0.5 * self.sigma * self.sigma * self.nu * u * u)
+                .ln()
+                / self.nu
+        };
+        levy_characteristic_function(psi, u, t)
+    }
+}
+
+/// Normal Inverse Gaussian (Barndorff-Nielsen, 1997) with tail heaviness `alpha`, asymmetry `beta`
+/// and scale `delta`; requires `alpha > |beta + 1|` for the forward to exist.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NormalInverseGaussian {
+    /// Tail heaviness
+    pub alpha: f64,
+    /// Asymmetry
+    pub beta: f64,
+    /// Scale
+    pub delta: f64,
+}
+
+impl CharacteristicFunction for NormalInverseGaussian {
+    fn calc_characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        let alpha2 = self.alpha * self.alpha;
+        let psi = |u: Complex64| {
+            let shifted = self.beta + i * u;
+            self.delta
+                * ((alpha2 - self.beta * self.beta).sqrt() - (alpha2 - shifted * shifted).sqrt())
+        };
+        levy_characteristic_function(psi, u, t)
+    }
+}
+
+/// CGMY (Carr, Geman, Madan & Yor, 2002) tempered stable process with activity `c`,
+/// tempering rates `g` (downward) and `m` (upward) and fine-structure index `y`;
+/// requires `y < 2`, `y != 0`, `y != 1` and `m > 1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cgmy {
+    /// Overall activity
+    pub c: f64,
+    /// Exponential decay of the left tail
+    pub g: f64,
+    /// Exponential decay of the right tail
+    pub m: f64,
+    /// Fine-structure index
+    pub y: f64,
+}
+
+impl CharacteristicFunction for Cgmy {
+    fn calc_characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        let scale = self.c * gamma(-self.y);
+        let psi = |u: Complex64| {
+            scale
+                * ((self.m - i * u).powf(self.y) - self.m.powf(self.y)
+                    + (self.g + i * u).powf(self.y)
+                    - self.g.powf(self.y))
+        };
+        levy_characteristic_function(psi, u, t)
+    }
+}
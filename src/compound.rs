@@ -0,0 +1,354 @@
+use crate::{
+    lets_be_rational::normal_distribution::{bivariate_normal_cdf, standard_normal_cdf},
+    numerical_greeks::{bump_inputs, Bump, NumericalGreeks},
+    numerics::{brent, expand_bracket},
+    Inputs, OptionType, Pricing,
+};
+
+const CRITICAL_PRICE_TOLERANCE: f64 = 1e-12;
+const CRITICAL_PRICE_MAX_ITERATIONS: usize = 200;
+
+/// An option on an option (Geske, 1979).
+///
+/// The compound option expires at `t` and, if exercised for `k`, delivers the `underlying` option,
+/// which expires at `underlying.t` (measured from today, so `underlying.t > t`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompoundInputs {
+    /// The type of the compound option (call or put on the underlying option)
+    pub option_type: OptionType,
+    /// Strike price of the compound option
+    pub k: f64,
+    /// Time to maturity of the compound option in years
+    pub t: f64,
+    /// The underlying option; `s`, `r`, `q` and `sigma` describe the market
+    pub underlying: Inputs,
+}
+
+impl CompoundInputs {
+    /// Creates instance of the `CompoundInputs` struct.
+    /// # Arguments
+    /// * `option_type` - The type of the compound option.
+    /// * `k` - The strike price of the compound option.
+    /// * `t` - The time to maturity of the compound option in years.
+    /// * `underlying` - The option delivered on exercise.
+    /// # Example
+    /// ```
+    /// use blackscholes::{CompoundInputs, Inputs, OptionType};
+    /// let underlying = Inputs::new(OptionType::Call, 500.0, 520.0, None, 0.08, 0.03, 0.5, Some(0.35));
+    /// let inputs = CompoundInputs::new(OptionType::Put, 50.0, 0.25, underlying);
+    /// ```
+    /// # Returns
+    /// An instance of the `CompoundInputs` struct.
+    pub fn new(option_type: OptionType, k: f64, t: f64, underlying: Inputs) -> Self {
+        Self {
+            option_type,
+            k,
+            t,
+            underlying,
+        }
+    }
+
+    /// Calculates the critical underlying price at which the underlying option is worth the compound strike at `t`.
+    /// # Requires
+    /// k, t, underlying.
+    /// # Returns
+    /// f64 of the critical underlying price.
+    pub fn calc_critical_price(&self) -> Result<f64, String> {
+        if self.t <= 0.0 || self.underlying.t <= self.t {
+            return Err("Compound option must expire before the underlying option".to_string());
+        }
+        let residual = Inputs {
+            t: self.underlying.t - self.t,
+            ..self.underlying.clone()
+        };
+        critical_price(&residual, |inputs| Ok(inputs.calc_price()? - self.k))
+    }
+}
+
+impl NumericalGreeks for CompoundInputs {
+    /// Calculates the price of the compound option.
+    /// # Requires
+    /// k, t, underlying (s, k, r, q, t, sigma).
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{CompoundInputs, Inputs, NumericalGreeks, OptionType};
+    /// let underlying = Inputs::new(OptionType::Call, 500.0, 520.0, None, 0.08, 0.03, 0.5, Some(0.35));
+    /// let inputs = CompoundInputs::new(OptionType::Put, 50.0, 0.25, underlying);
+    /// let price = inputs.calc_price().unwrap();
+    /// ```
+    fn calc_price(&self) -> Result<f64, String> {
+        let sigma = self
+            .underlying
+            .sigma
+            .ok_or("Expected Some(f64) for underlying.sigma, received None")?;
+        let critical = self.calc_critical_price()?;
+
+        let u = &self.underlying;
+        let (t1, t2) = (self.t, u.t);
+        let y1 =
+            ((u.s / critical).ln() + (u.r - u.q + 0.5 * sigma * sigma) * t1) / (sigma * t1.sqrt());
+        let y2 = y1 - sigma * t1.sqrt();
+        let z1 = ((u.s / u.k).ln() + (u.r - u.q + 0.5 * sigma * sigma) * t2) / (sigma * t2.sqrt());
+        let z2 = z1 - sigma * t2.sqrt();
+        let rho = (t1 / t2).sqrt();
+
+        let s = u.s * (-u.q * t2).exp();
+        let k2 = u.k * (-u.r * t2).exp();
+        let k1 = self.k * (-u.r * t1).exp();
+
+        let price = match (self.option_type, u.option_type) {
+            (OptionType::Call, OptionType::Call) => {
+                s * bivariate_normal_cdf(z1, y1, rho)
+                    - k2 * bivariate_normal_cdf(z2, y2, rho)
+                    - k1 * standard_normal_cdf(y2)
+            }
+            (OptionType::Put, OptionType::Call) => {
+                k2 * bivariate_normal_cdf(z2, -y2, -rho) - s * bivariate_normal_cdf(z1, -y1, -rho)
+                    + k1 * standard_normal_cdf(-y2)
+            }
+            (OptionType::Call, OptionType::Put) => {
+                k2 * bivariate_normal_cdf(-z2, -y2, rho)
+                    - s * bivariate_normal_cdf(-z1, -y1, rho)
+                    - k1 * standard_normal_cdf(-y2)
+            }
+            (OptionType::Put, OptionType::Put) => {
+                s * bivariate_normal_cdf(-z1, y1, -rho) - k2 * bivariate_normal_cdf(-z2, y2, -rho)
+                    + k1 * standard_normal_cdf(y2)
+            }
+        };
+        Ok(price.max(0.0))
+    }
+
+    fn calc_bumped_price(&self, bump: Bump, size: f64) -> Result<f64, String> {
+        let t = if bump == Bump::Time {
+            self.t - size
+        } else {
+            self.t
+        };
+        CompoundInputs {
+            t,
+            underlying: bump_inputs(&self.underlying, bump, size),
+            ..self.clone()
+        }
+        .calc_price()
+    }
+
+    fn spot(&self) -> f64 {
+        self.underlying.s
+    }
+}
+
+/// A chooser option (Rubinstein, 1991): at time `t` the holder picks either the `call` or the `put`.
+///
+/// When both legs share the same strike and expiry this is a simple chooser, otherwise a complex chooser.
+/// The legs' times to maturity are measured from today and must be longer than `t`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChooserInputs {
+    /// Time until the choice is made, in years
+    pub t: f64,
+    /// The call that can be chosen
+    pub call: Inputs,
+    /// The put that can be chosen
+    pub put: Inputs,
+}
+
+impl ChooserInputs {
+    /// Creates a simple chooser, where the call and put share the strike and expiry of `inputs`.
+    /// # Example
+    /// ```
+    /// use blackscholes::{ChooserInputs, Inputs, OptionType};
+    /// let inputs = Inputs::new(OptionType::Call, 50.0, 50.0, None, 0.08, 0.0, 0.5, Some(0.25));
+    /// let chooser = ChooserInputs::simple(&inputs, 0.25);
+    /// ```
+    pub fn simple(inputs: &Inputs, t: f64) -> Self {
+        Self {
+            t,
+            call: Inputs {
+                option_type: OptionType::Call,
+                ..inputs.clone()
+            },
+            put: Inputs {
+                option_type: OptionType::Put,
+                ..inputs.clone()
+            },
+        }
+    }
+
+    /// Creates a complex chooser with separate call and put strikes and expiries.
+    /// # Arguments
+    /// * `inputs` - Market data (s, r, q, sigma); its strike and expiry are ignored.
+    /// * `t` - Time until the choice is made, in years.
+    /// * `call_k`, `call_t` - Strike and time to maturity of the call.
+    /// * `put_k`, `put_t` - Strike and time to maturity of the put.
+    /// # Example
+    /// ```
+    /// use blackscholes::{ChooserInputs, Inputs, OptionType};
+    /// let inputs = Inputs::new(OptionType::Call, 50.0, 50.0, None, 0.1, 0.05, 0.5, Some(0.35));
+    /// let chooser = ChooserInputs::complex(&inputs, 0.25, 55.0, 0.5, 48.0, 0.5833);
+    /// ```
+    pub fn complex(
+        inputs: &Inputs,
+        t: f64,
+        call_k: f64,
+        call_t: f64,
+        put_k: f64,
+        put_t: f64,
+    ) -> Self {
+        Self {
+            t,
+            call: Inputs {
+                option_type: OptionType::Call,
+                k: call_k,
+                t: call_t,
+                ..inputs.clone()
+            },
+            put: Inputs {
+                option_type: OptionType::Put,
+                k: put_k,
+                t: put_t,
+                ..inputs.clone()
+            },
+        }
+    }
+
+    fn validate(&self) -> Result<f64, String> {
+        let sigma = self
+            .call
+            .sigma
+            .ok_or("Expected Some(f64) for call.sigma, received None")?;
+        if self.call.option_type != OptionType::Call || self.put.option_type != OptionType::Put {
+            return Err("Chooser legs must be a call and a put".to_string());
+        }
+        if self.call.s != self.put.s
+            || self.call.r != self.put.r
+            || self.call.q != self.put.q
+            || self.call.sigma != self.put.sigma
+        {
+            return Err("Chooser legs must share s, r, q and sigma".to_string());
+        }
+        if self.t <= 0.0 || self.call.t <= self.t || self.put.t <= self.t {
+            return Err(
+                "The choice time must be positive and strictly before both legs expire".to_string(),
+            );
+        }
+        Ok(sigma)
+    }
+}
+
+impl NumericalGreeks for ChooserInputs {
+    /// Calculates the price of the chooser option.
+    /// # Requires
+    /// t, call (s, k, r, q, t, sigma), put (k, t).
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{ChooserInputs, Inputs, NumericalGreeks, OptionType};
+    /// let inputs = Inputs::new(OptionType::Call, 50.0, 50.0, None, 0.08, 0.0, 0.5, Some(0.25));
+    /// let price = ChooserInputs::simple(&inputs, 0.25).calc_price().unwrap();
+    /// ```
+    fn calc_price(&self) -> Result<f64, String> {
+        let sigma = self.validate()?;
+        if self.call.k == self.put.k && self.call.t == self.put.t {
+            simple_chooser(&self.call, self.t, sigma)
+        } else {
+            complex_chooser(self, sigma)
+        }
+    }
+
+    fn calc_bumped_price(&self, bump: Bump, size: f64) -> Result<f64, String> {
+        ChooserInputs {
+            t: if bump == Bump::Time {
+                self.t - size
+            } else {
+                self.t
+            },
+            call: bump_inputs(&self.call, bump, size),
+            put: bump_inputs(&self.put, bump, size),
+        }
+        .calc_price()
+    }
+
+    fn spot(&self) -> f64 {
+        self.call.s
+    }
+}
+
+fn simple_chooser(inputs: &Inputs, t: f64, sigma: f64) -> Result<f64, String> {
+    // Rubinstein (1991): a call to `inputs.t` plus a put to `t` on the carry-adjusted strike.
+    let big_t = inputs.t;
+    let b = inputs.r - inputs.q;
+    let d =
+        ((inputs.s / inputs.k).ln() + (b + 0.5 * sigma * sigma) * big_t) / (sigma * big_t.sqrt());
+    let y = ((inputs.s / inputs.k).ln() + b * big_t + 0.5 * sigma * sigma * t) / (sigma * t.sqrt());
+    let s = inputs.s * (-inputs.q * big_t).exp();
+    let k = inputs.k * (-inputs.r * big_t).exp();
+    let price = s * standard_normal_cdf(d)
+        - k * standard_normal_cdf(d - sigma * big_t.sqrt())
+        - s * standard_normal_cdf(-y)
+        + k * standard_normal_cdf(-y + sigma * t.sqrt());
+    Ok(price.max(0.0))
+}
+
+fn complex_chooser(chooser: &ChooserInputs, sigma: f64) -> Result<f64, String> {
+    let t = chooser.t;
+    let (call, put) = (&chooser.call, &chooser.put);
+    let residual = |inputs: &Inputs| Inputs {
+        t: inputs.t - t,
+        ..inputs.clone()
+    };
+    let (call_residual, put_residual) = (residual(call), residual(put));
+    // The holder is indifferent between the legs at the critical price.
+    let critical = critical_price(&call_residual, |inputs| {
+        let put = Inputs {
+            s: inputs.s,
+            ..put_residual.clone()
+        };
+        Ok(inputs.calc_price()? - put.calc_price()?)
+    })?;
+
+    let b = call.r - call.q;
+    let d1 = ((call.s / critical).ln() + (b + 0.5 * sigma * sigma) * t) / (sigma * t.sqrt());
+    let d2 = d1 - sigma * t.sqrt();
+    let y1 =
+        ((call.s / call.k).ln() + (b + 0.5 * sigma * sigma) * call.t) / (sigma * call.t.sqrt());
+    let y2 = ((call.s / put.k).ln() + (b + 0.5 * sigma * sigma) * put.t) / (sigma * put.t.sqrt());
+    let rho1 = (t / call.t).sqrt();
+    let rho2 = (t / put.t).sqrt();
+
+    let price = call.s * (-call.q * call.t).exp() * bivariate_normal_cdf(d1, y1, rho1)
+        - call.k
+            * (-call.r * call.t).exp()
+            * bivariate_normal_cdf(d2, y1 - sigma * call.t.sqrt(), rho1)
+        - call.s * (-call.q * put.t).exp() * bivariate_normal_cdf(-d1, -y2, rho2)
+        + put.k
+            * (-call.r * put.t).exp()
+            * bivariate_normal_cdf(-d2, -y2 + sigma * put.t.sqrt(), rho2);
+    Ok(price.max(0.0))
+}
+
+/// Solves `f(inputs with s = S) = 0` for the underlying price `S`.
+fn critical_price<F>(inputs: &Inputs, f: F) -> Result<f64, String>
+where
+    F: Fn(&Inputs) -> Result<f64, String>,
+{
+    let g = |s: f64| {
+        f(&Inputs {
+            s,
+            ..inputs.clone()
+        })
+    };
+    let floor = inputs.k * 1e-8;
+    let (lower, upper) = expand_bracket(g, 0.5 * inputs.k, inputs.k, floor).map_err(|_| {
+        "No critical price exists for these strikes; the option is never exercised".to_string()
+    })?;
+    brent(
+        g,
+        lower,
+        upper,
+        CRITICAL_PRICE_TOLERANCE * inputs.k,
+        CRITICAL_PRICE_MAX_ITERATIONS,
+    )
+}
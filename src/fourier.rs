@@ -0,0 +1,282 @@
+use std::f64::consts::PI;
+
+use num_complex::Complex64;
+
+use crate::{CharacteristicFunction, Inputs, OptionType};
+
+/// Carr & Madan (1999) engine pricing a whole grid of strikes with one fast Fourier transform
+/// of the damped call price.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CarrMadan {
+    /// Damping exponent applied to the call price; must be positive and keep `E[S_T^(alpha + 1)]` finite
+    pub alpha: f64,
+    /// Number of grid points, a power of two
+    pub points: usize,
+    /// Spacing of the integration grid in the Fourier domain
+    pub eta: f64,
+}
+
+impl Default for CarrMadan {
+    fn default() -> Self {
+        Self {
+            alpha: 1.5,
+            points: 4096,
+            eta: 0.25,
+        }
+    }
+}
+
+/// Fang & Oosterlee (2008) COS engine, expanding the density in a Fourier cosine series on a truncated range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CosMethod {
+    /// Number of cosine terms
+    pub terms: usize,
+    /// Width of the truncation range in units of the cumulant-based standard deviation
+    pub truncation: f64,
+}
+
+impl Default for CosMethod {
+    fn default() -> Self {
+        Self {
+            terms: 256,
+            truncation: 10.0,
+        }
+    }
+}
+
+fn validate(inputs: &Inputs) -> Result<f64, String> {
+    if inputs.t <= 0.0 {
+        return Err("Time to maturity must be positive".to_string());
+    }
+    if inputs.s <= 0.0 {
+        return Err("Spot price must be positive".to_string());
+    }
+    Ok(inputs.s * ((inputs.r - inputs.q) * inputs.t).exp())
+}
+
+/// Converts a call price into the price of `option_type` by put-call parity.
+fn from_call(call: f64, inputs: &Inputs, k: f64, option_type: OptionType) -> f64 {
+    match option_type {
+        OptionType::Call => call,
+        OptionType::Put => {
+            call - inputs.s * (-inputs.q * inputs.t).exp() + k * (-inputs.r * inputs.t).exp()
+        }
+    }
+}
+
+impl CarrMadan {
+    /// Calculates call prices on the engine's log-strike grid, which is centred on the forward.
+    /// # Arguments
+    /// * `model` - The characteristic function of the log price.
+    /// * `inputs` - The market parameters s, r, q and t; the option type, strike and volatility are ignored.
+    /// # Returns
+    /// Tuple (Vec<f64>, Vec<f64>) of the strikes and the call prices at those strikes.
+    pub fn calc_strike_grid<M>(
+        &self,
+        model: &M,
+        inputs: &Inputs,
+    ) -> Result<(Vec<f64>, Vec<f64>), String>
+    where
+        M: CharacteristicFunction + ?Sized,
+    {
+        let forward = validate(inputs)?;
+        let n = self.points;
+        if n < 4 || !n.is_power_of_two() {
+            return Err("Number of grid points must be a power of two".to_string());
+        }
+        if self.alpha <= 0.0 || self.eta <= 0.0 {
+            return Err("Damping and grid spacing must be positive".to_string());
+        }
+        let i = Complex64::i();
+        let t = inputs.t;
+        let alpha = self.alpha;
+        let discount = (-inputs.r * t).exp();
+        let lambda = 2.0 * PI / (n as f64 * self.eta);
+        let lower = forward.ln() - 0.5 * n as f64 * lambda;
+
+        let mut values: Vec<Complex64> = (0..n)
+            .map(|j| {
+                let v = self.eta * j as f64;
+                let u = Complex64::new(v, -(alpha + 1.0));
+                // Characteristic function of ln(S_T) from that of ln(S_T / F_T).
+                let phi = (i * u * forward.ln()).exp() * model.calc_characteristic_function(u, t);
+                let psi = discount * phi
+                    / Complex64::new(alpha * alpha + alpha - v * v, (2.0 * alpha + 1.0) * v);
+                let simpson = if j == 0 {
+                    1.0 / 3.0
+                } else if j % 2 == 1 {
+                    4.0 / 3.0
+                } else {
+                    2.0 / 3.0
+                };
+                (-i * v * lower).exp() * psi * self.eta * simpson
+            })
+            .collect();
+        fft(&mut values);
+
+        let strikes: Vec<f64> = (0..n).map(|u| (lower + lambda * u as f64).exp()).collect();
+        let prices = values
+            .iter()
+            .zip(&strikes)
+            .map(|(value, k)| (-alpha * k.ln()).exp() / PI * value.re)
+            .collect::<Vec<f64>>();
+        if prices.iter().any(|price| !price.is_finite()) {
+            return Err("Characteristic function produced a non-finite price".to_string());
+        }
+        Ok((strikes, prices))
+    }
+
+    /// Calculates option prices at arbitrary strikes, interpolating the FFT grid with cubic polynomials in log-strike.
+    /// # Arguments
+    /// * `model` - The characteristic function of the log price.
+    /// * `inputs` - The option type and market parameters s, r, q and t; the strike and volatility are ignored.
+    /// * `strikes` - The strikes to price, which must lie inside the grid.
+    /// # Returns
+    /// Vec<f64> of the option prices at `strikes`.
+    /// # Example
+    /// ```
+    /// use blackscholes::{BsmModel, CarrMadan, Inputs, OptionType};
+    /// let inputs = Inputs::new(OptionType::Put, 100.0, 100.0, None, 0.05, 0.02, 0.5, None);
+    /// let prices = CarrMadan::default()
+    ///     .calc_prices(&BsmModel { sigma: 0.2 }, &inputs, &[90.0, 100.0, 110.0])
+    ///     .unwrap();
+    /// ```
+    pub fn calc_prices<M>(
+        &self,
+        model: &M,
+        inputs: &Inputs,
+        strikes: &[f64],
+    ) -> Result<Vec<f64>, String>
+    where
+        M: CharacteristicFunction + ?Sized,
+    {
+        let (grid, calls) = self.calc_strike_grid(model, inputs)?;
+        let lower = grid[0].ln();
+        let step = grid[1].ln() - lower;
+        strikes
+            .iter()
+            .map(|&k| {
+                let position = (k.ln() - lower) / step;
+                if !(1.0..=(grid.len() - 3) as f64).contains(&position) {
+                    return Err(format!("Strike {k} lies outside the FFT grid"));
+                }
+                // Four-point Lagrange interpolation around the strike.
+                let base = position.floor() as usize - 1;
+                let x = position - base as f64;
+                let call: f64 = (0..4)
+                    .map(|j| {
+                        let weight: f64 = (0..4)
+                            .filter(|&m| m != j)
+                            .map(|m| (x - m as f64) / (j as f64 - m as f64))
+                            .product();
+                        weight * calls[base + j]
+                    })
+                    .sum();
+                Ok(from_call(call, inputs, k, inputs.option_type))
+            })
+            .collect()
+    }
+}
+
+impl CosMethod {
+    /// Calculates the price of a European option with the COS method.
+    /// Puts are priced directly and calls follow from put-call parity, which is the more stable choice.
+    /// # Arguments
+    /// * `model` - The characteristic function of the log price.
+    /// * `inputs` - The option type, strike and market parameters s, r, q and t; the volatility is ignored.
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{CosMethod, Inputs, OptionType, VarianceGamma};
+    /// let inputs = Inputs::new(OptionType::Call, 100.0, 90.0, None, 0.1, 0.0, 0.1, None);
+    /// let model = VarianceGamma { sigma: 0.12, nu: 0.2, theta: -0.14 };
+    /// let price = CosMethod::default().calc_price(&model, &inputs).unwrap();
+    /// ```
+    pub fn calc_price<M>(&self, model: &M, inputs: &Inputs) -> Result<f64, String>
+    where
+        M: CharacteristicFunction + ?Sized,
+    {
+        let forward = validate(inputs)?;
+        let k = inputs.k;
+        if k <= 0.0 {
+            return Err("Strike must be positive".to_string());
+        }
+        if self.terms == 0 || self.truncation <= 0.0 {
+            return Err("COS method requires terms and a positive truncation width".to_string());
+        }
+        let t = inputs.t;
+        // Work with y = ln(S_T / K) = x0 + X.
+        let x0 = (forward / k).ln();
+        let (c1, c2, c4) = model.calc_cumulants(t);
+        let width = self.truncation * (c2.abs() + c4.abs().sqrt()).sqrt();
+        if !width.is_finite() || width <= 0.0 {
+            return Err("Model cumulants do not give a valid truncation range".to_string());
+        }
+        let (a, b) = (x0 + c1 - width, x0 + c1 + width);
+
+        // Cosine coefficients of the put payoff K (1 - e^y) on [a, min(b, 0)].
+        let d = b.min(0.0);
+        let put = if a >= 0.0 {
+            0.0
+        } else {
+            let mut sum = 0.0;
+            for j in 0..self.terms {
+                let u = j as f64 * PI / (b - a);
+                let chi = (((u * (d - a)).cos() + u * (u * (d - a)).sin()) * d.exp() - a.exp())
+                    / (1.0 + u * u);
+                let psi = if j == 0 {
+                    d - a
+                } else {
+                    (u * (d - a)).sin() / u
+                };
+                let coefficient = 2.0 / (b - a) * (psi - chi);
+                let phi = model.calc_characteristic_function(Complex64::new(u, 0.0), t)
+                    * Complex64::new(0.0, u * (x0 - a)).exp();
+                let weight = if j == 0 { 0.5 } else { 1.0 };
+                sum += weight * phi.re * coefficient;
+            }
+            k * (-inputs.r * t).exp() * sum
+        };
+        if !put.is_finite() {
+            return Err("Characteristic function produced a non-finite price".to_string());
+        }
+        let call = put + inputs.s * (-inputs.q * t).exp() - k * (-inputs.r * t).exp();
+        Ok(match inputs.option_type {
+            OptionType::Call => call,
+            OptionType::Put => put,
+        })
+    }
+}
+
+/// In-place iterative radix-2 fast Fourier transform computing `sum_j x_j exp(-2 pi i j k / n)`.
+fn fft(values: &mut [Complex64]) {
+    let n = values.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut length = 2;
+    while length <= n {
+        let root = Complex64::from_polar(1.0, -2.0 * PI / length as f64);
+        for start in (0..n).step_by(length) {
+            let mut w = Complex64::new(1.0, 0.0);
+            for offset in 0..length / 2 {
+                let even = values[start + offset];
+                let odd = values[start + offset + length / 2] * w;
+                values[start + offset] = even + odd;
+                values[start + offset + length / 2] = even - odd;
+                w *= root;
+            }
+        }
+        length <<= 1;
+    }
+}
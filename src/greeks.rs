@@ -383,11 +383,11 @@ impl Greeks<f64> for Inputs {
     /// ```
     fn calc_dual_delta(&self) -> Result<f64, String> {
         let (_, nd2) = calc_nd1nd2(self)?;
-        let e_negqt = (-self.q * self.t).exp();
+        let e_negrt = (-self.r * self.t).exp();
 
         let dual_delta = match self.option_type {
-            OptionType::Call => -e_negqt * nd2,
-            OptionType::Put => e_negqt * nd2,
+            OptionType::Call => -e_negrt * nd2,
+            OptionType::Put => e_negrt * nd2,
         };
         Ok(dual_delta)
     }
@@ -408,9 +408,9 @@ impl Greeks<f64> for Inputs {
             .sigma
             .ok_or("Expected Some(f64) for self.sigma, received None")?;
         let nprimed2 = calc_nprimed2(self)?;
-        let e_negqt = (-self.q * self.t).exp();
+        let e_negrt = (-self.r * self.t).exp();
 
-        let dual_gamma = e_negqt * (nprimed2 / (self.k * sigma * self.t.sqrt()));
+        let dual_gamma = e_negrt * (nprimed2 / (self.k * sigma * self.t.sqrt()));
         Ok(dual_gamma)
     }
 
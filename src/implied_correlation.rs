@@ -0,0 +1,194 @@
+use crate::{lets_be_rational::normal_distribution::standard_normal_cdf, IvErrorKind, OptionChain};
+
+/// Expiries closer than this are treated as the same expiry.
+const EXPIRY_TOLERANCE: f64 = 1e-9;
+
+/// Where on each smile the index and constituent volatilities are read.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CorrelationLevel {
+    /// At the strike equal to the forward
+    AtTheMoney,
+    /// At the strike equal to this fraction of the forward, K / F
+    Moneyness(f64),
+    /// At the strike whose forward call delta N(d1) equals this value
+    Delta(f64),
+}
+
+/// Implied correlation at one expiry, with the volatilities it was computed from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImpliedCorrelation {
+    /// Time to maturity in years
+    pub expiry: f64,
+    /// Average pairwise correlation of the constituents implied by the index volatility
+    pub correlation: f64,
+    /// Implied volatility of the index at the level
+    pub index_iv: f64,
+    /// Implied volatility of each constituent at the level
+    pub constituent_ivs: Vec<f64>,
+}
+
+/// An index option chain with the chains of its constituents and their weights in the index,
+/// for the average correlation implied by dispersion between index and constituent volatilities.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DispersionInputs<'a> {
+    /// Options on the index
+    pub index: OptionChain<'a>,
+    /// Options on each constituent
+    pub constituents: &'a [OptionChain<'a>],
+    /// Weight of each constituent in the index
+    pub weights: &'a [f64],
+}
+
+impl<'a> DispersionInputs<'a> {
+    /// Creates instance of the `DispersionInputs` struct, checking that there is one non-negative weight per constituent.
+    /// # Arguments
+    /// * `index` - The options on the index.
+    /// * `constituents` - The options on each constituent.
+    /// * `weights` - The weight of each constituent in the index.
+    /// # Example
+    /// ```
+    /// use blackscholes::{DispersionInputs, OptionChain, OptionType};
+    /// let types = [OptionType::Put, OptionType::Call];
+    /// let index = OptionChain::new(&types, &[100.0; 2], &[95.0, 105.0], &[0.5; 2], &[3.2, 3.6]).unwrap();
+    /// let first = OptionChain::new(&types, &[50.0; 2], &[47.5, 52.5], &[0.5; 2], &[2.4, 2.6]).unwrap();
+    /// let second = OptionChain::new(&types, &[80.0; 2], &[76.0, 84.0], &[0.5; 2], &[3.3, 3.6]).unwrap();
+    /// let constituents = [first, second];
+    /// let inputs = DispersionInputs::new(index, &constituents, &[0.4, 0.6]).unwrap();
+    /// ```
+    /// # Returns
+    /// An instance of the `DispersionInputs` struct.
+    pub fn new(
+        index: OptionChain<'a>,
+        constituents: &'a [OptionChain<'a>],
+        weights: &'a [f64],
+    ) -> Result<Self, String> {
+        if constituents.len() != weights.len() {
+            return Err("There must be one weight per constituent".to_string());
+        }
+        if constituents.len() < 2 {
+            return Err("Implied correlation requires at least two constituents".to_string());
+        }
+        if weights.iter().any(|&weight| weight < 0.0) {
+            return Err("Constituent weights must be non-negative".to_string());
+        }
+        Ok(Self {
+            index,
+            constituents,
+            weights,
+        })
+    }
+
+    /// Calculates the implied correlation at every expiry of the index chain,
+    /// `(sigma_I^2 - sum w_i^2 sigma_i^2) / (sum_{i != j} w_i w_j sigma_i sigma_j)`.
+    /// Every quote is solved with the "Let's be rational" method, and the volatility at the level is linearly
+    /// interpolated between the quotes either side of it, in log-moneyness or in delta; quotes with no implied
+    /// volatility are skipped.
+    /// # Arguments
+    /// * `level` - Where on each smile to read the volatilities.
+    /// # Requires
+    /// Every constituent chain quotes every expiry of the index chain, on both sides of the level.
+    /// # Returns
+    /// Vec<ImpliedCorrelation> with the implied correlation and volatilities at each expiry, shortest first.
+    /// # Example
+    /// ```
+    /// use blackscholes::{CorrelationLevel, DispersionInputs, OptionChain, OptionType};
+    /// let types = [OptionType::Put, OptionType::Call];
+    /// let index = OptionChain::new(&types, &[100.0; 2], &[95.0, 105.0], &[0.5; 2], &[3.2, 3.6]).unwrap();
+    /// let first = OptionChain::new(&types, &[50.0; 2], &[47.5, 52.5], &[0.5; 2], &[2.4, 2.6]).unwrap();
+    /// let second = OptionChain::new(&types, &[80.0; 2], &[76.0, 84.0], &[0.5; 2], &[3.3, 3.6]).unwrap();
+    /// let constituents = [first, second];
+    /// let correlations = DispersionInputs::new(index, &constituents, &[0.4, 0.6])
+    ///     .unwrap()
+    ///     .calc_implied_correlation(CorrelationLevel::AtTheMoney)
+    ///     .unwrap();
+    /// assert!(correlations[0].correlation > 0.0 && correlations[0].correlation < 1.0);
+    /// ```
+    pub fn calc_implied_correlation(
+        &self,
+        level: CorrelationLevel,
+    ) -> Result<Vec<ImpliedCorrelation>, String> {
+        let index_vols = self.index.calc_rational_iv(1);
+        let constituent_vols: Vec<_> = self
+            .constituents
+            .iter()
+            .map(|chain| chain.calc_rational_iv(1))
+            .collect();
+
+        let mut expiries = self.index.expiries.to_vec();
+        expiries.sort_by(f64::total_cmp);
+        expiries.dedup_by(|a, b| (*a - *b).abs() <= EXPIRY_TOLERANCE);
+
+        expiries
+            .into_iter()
+            .map(|expiry| {
+                let index_iv = smile_iv(&self.index, &index_vols, expiry, level)?;
+                let constituent_ivs = self
+                    .constituents
+                    .iter()
+                    .zip(&constituent_vols)
+                    .map(|(chain, vols)| smile_iv(chain, vols, expiry, level))
+                    .collect::<Result<Vec<f64>, String>>()?;
+
+                let (mut weighted_sum, mut own_variance) = (0.0, 0.0);
+                for (weight, sigma) in self.weights.iter().zip(&constituent_ivs) {
+                    weighted_sum += weight * sigma;
+                    own_variance += (weight * sigma).powi(2);
+                }
+                let cross_variance = weighted_sum * weighted_sum - own_variance;
+                if cross_variance <= 0.0 {
+                    return Err(
+                        "Fewer than two constituents carry weight and volatility".to_string()
+                    );
+                }
+                Ok(ImpliedCorrelation {
+                    expiry,
+                    correlation: (index_iv * index_iv - own_variance) / cross_variance,
+                    index_iv,
+                    constituent_ivs,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Implied volatility of `chain` at `level` on the smile of `expiry`, interpolated from the solved `vols`.
+fn smile_iv(
+    chain: &OptionChain,
+    vols: &[Result<f64, IvErrorKind>],
+    expiry: f64,
+    level: CorrelationLevel,
+) -> Result<f64, String> {
+    // Each quote's position on the smile: log-moneyness ln(K / F), or the forward call delta N(d1).
+    let mut points: Vec<(f64, f64)> = (0..chain.len())
+        .filter(|&i| (chain.expiries[i] - expiry).abs() <= EXPIRY_TOLERANCE)
+        .filter_map(|i| {
+            let sigma = *vols[i].as_ref().ok()?;
+            let log_moneyness = (chain.strikes[i] / chain.forwards[i]).ln();
+            let x = match level {
+                CorrelationLevel::AtTheMoney | CorrelationLevel::Moneyness(_) => log_moneyness,
+                CorrelationLevel::Delta(_) => {
+                    let total_volatility = sigma * expiry.sqrt();
+                    standard_normal_cdf(-log_moneyness / total_volatility + 0.5 * total_volatility)
+                }
+            };
+            Some((x, sigma))
+        })
+        .collect();
+    let target = match level {
+        CorrelationLevel::AtTheMoney => 0.0,
+        CorrelationLevel::Moneyness(moneyness) => moneyness.ln(),
+        CorrelationLevel::Delta(delta) => delta,
+    };
+    points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    match points.iter().position(|&(x, _)| x >= target) {
+        Some(i) if points[i].0 == target => Ok(points[i].1),
+        Some(i) if i > 0 => {
+            let ((x0, sigma0), (x1, sigma1)) = (points[i - 1], points[i]);
+            Ok(sigma0 + (sigma1 - sigma0) * (target - x0) / (x1 - x0))
+        }
+        _ => Err(format!(
+            "No quotes on both sides of {level:?} at expiry {expiry}"
+        )),
+    }
+}
@@ -0,0 +1,166 @@
+use num_traits::Float;
+
+use crate::{numerics::newton_bracketed, Greeks, Inputs, Pricing, DAYS_PER_YEAR};
+
+/// Relative tolerance on the solved parameter.
+const TOLERANCE: f64 = 1e-12;
+const MAX_ITERATIONS: usize = 100;
+
+/// A Black-Scholes-Merton input to solve for, with the other inputs, including the volatility, known.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImpliedParameter {
+    /// The spot price at which the option is worth `p`
+    Spot,
+    /// The dividend yield at which the option is worth `p`
+    DividendYield,
+    /// The risk-free rate at which the option is worth `p`
+    Rate,
+    /// The time to maturity at which the option is worth `p`
+    Time,
+    /// The strike at which the option is worth `p`
+    Strike,
+    /// The strike at which the delta of the option equals the target delta
+    StrikeForDelta(f64),
+}
+
+/// A solved implied parameter with diagnostics of the solve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImpliedParameterResult {
+    /// The solved parameter
+    pub value: f64,
+    /// Iterations used
+    pub iterations: usize,
+    /// Price, or delta for `StrikeForDelta`, at `value` less the target
+    pub residual: f64,
+}
+
+pub trait ImpliedParameters<T>
+where
+    T: Float,
+{
+    fn calc_implied_parameter(
+        &self,
+        parameter: ImpliedParameter,
+        lower: T,
+        upper: T,
+    ) -> Result<ImpliedParameterResult, String>;
+}
+
+impl ImpliedParameters<f64> for Inputs {
+    /// Calculates the value of `parameter` within `[lower, upper]` at which the option is worth `p`,
+    /// or for `StrikeForDelta` the strike at which its delta equals the target.
+    /// Uses Newton's method on the analytic derivative of the price (delta, epsilon, rho, theta or dual delta),
+    /// or of the delta for `StrikeForDelta`, falling back to Brent's method when a step leaves the bracket
+    /// narrowed so far or fails to halve the previous step.
+    /// # Requires
+    /// s, k, r, q, t, sigma, and p unless solving for a strike from a delta.
+    /// The target must be bracketed: the price or delta less the target must change sign over `[lower, upper]`.
+    /// # Returns
+    /// ImpliedParameterResult of the solved parameter, the iterations used and the final residual.
+    /// # Example
+    /// ```
+    /// use blackscholes::{ImpliedParameter, ImpliedParameters, Inputs, OptionType};
+    /// let inputs = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.01, 0.5, Some(0.2));
+    /// let strike = inputs
+    ///     .calc_implied_parameter(ImpliedParameter::StrikeForDelta(0.25), 100.0, 200.0)
+    ///     .unwrap();
+    /// assert!(strike.residual.abs() < 1e-10);
+    /// ```
+    fn calc_implied_parameter(
+        &self,
+        parameter: ImpliedParameter,
+        lower: f64,
+        upper: f64,
+    ) -> Result<ImpliedParameterResult, String> {
+        if upper <= lower {
+            return Err("Parameter range requires lower < upper".to_string());
+        }
+        let target = match parameter {
+            ImpliedParameter::StrikeForDelta(delta) => delta,
+            _ => self
+                .p
+                .ok_or("inputs.p must contain Some(f64), found None".to_string())?,
+        };
+        let with = |x: f64| {
+            let mut inputs = self.clone();
+            match parameter {
+                ImpliedParameter::Spot => inputs.s = x,
+                ImpliedParameter::DividendYield => inputs.q = x,
+                ImpliedParameter::Rate => inputs.r = x,
+                ImpliedParameter::Time => inputs.t = x,
+                ImpliedParameter::Strike | ImpliedParameter::StrikeForDelta(_) => inputs.k = x,
+            }
+            inputs
+        };
+        let error = |x: f64| -> Result<f64, String> {
+            let inputs = with(x);
+            let value = match parameter {
+                ImpliedParameter::StrikeForDelta(_) => inputs.calc_delta()?,
+                _ => inputs.calc_price()?,
+            };
+            Ok(value - target)
+        };
+        // Rho is quoted per 1% change in the rate and theta per day of decay.
+        let slope = |x: f64| -> Result<f64, String> {
+            let inputs = with(x);
+            match parameter {
+                ImpliedParameter::Spot => inputs.calc_delta(),
+                ImpliedParameter::DividendYield => inputs.calc_epsilon(),
+                ImpliedParameter::Rate => Ok(100.0 * inputs.calc_rho()?),
+                ImpliedParameter::Time => Ok(-DAYS_PER_YEAR * inputs.calc_theta()?),
+                ImpliedParameter::Strike => inputs.calc_dual_delta(),
+                ImpliedParameter::StrikeForDelta(_) => Ok(-inputs.calc_gamma()? * inputs.s / x),
+            }
+        };
+
+        let f_lower = error(lower)?;
+        let f_upper = error(upper)?;
+        if f_lower == 0.0 {
+            return Ok(ImpliedParameterResult {
+                value: lower,
+                iterations: 0,
+                residual: 0.0,
+            });
+        }
+        if f_upper == 0.0 {
+            return Ok(ImpliedParameterResult {
+                value: upper,
+                iterations: 0,
+                residual: 0.0,
+            });
+        }
+        if f_lower * f_upper > 0.0 {
+            return Err("Target is not bracketed by the parameter range".to_string());
+        }
+
+        let tolerance = TOLERANCE * (1.0 + lower.abs().max(upper.abs()));
+        let start = match parameter {
+            ImpliedParameter::Spot => self.s,
+            ImpliedParameter::DividendYield => self.q,
+            ImpliedParameter::Rate => self.r,
+            ImpliedParameter::Time => self.t,
+            ImpliedParameter::Strike | ImpliedParameter::StrikeForDelta(_) => self.k,
+        };
+        let start = if start > lower && start < upper {
+            start
+        } else {
+            0.5 * (lower + upper)
+        };
+        let (value, iterations) = newton_bracketed(
+            error,
+            |x, diff| Ok(diff / slope(x)?),
+            lower,
+            upper,
+            start,
+            f_lower < 0.0,
+            tolerance,
+            0.0,
+            MAX_ITERATIONS,
+        )?;
+        Ok(ImpliedParameterResult {
+            value,
+            iterations,
+            residual: error(value)?,
+        })
+    }
+}
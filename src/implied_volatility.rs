@@ -1,11 +1,13 @@
-use std::f64::consts::PI;
+use std::fmt::{Display, Formatter, Result as fmtResult};
 
 use num_traits::Float;
-use statrs::consts::SQRT_2PI;
 
 use crate::{
-    greeks::Greeks, lets_be_rational::implied_volatility_from_a_transformed_rational_guess,
-    pricing::Pricing, Inputs, *,
+    greeks::Greeks,
+    iv_solver::{solve_iv, solve_rational_iv},
+    lets_be_rational::implied_volatility_from_a_transformed_rational_guess,
+    pricing::Pricing,
+    Inputs, *,
 };
 
 pub trait ImpliedVolatility<T>: Pricing<T> + Greeks<T>
@@ -13,7 +15,10 @@ where
     T: Float,
 {
     fn calc_iv(&self, tolerance: T) -> Result<T, String>;
+    fn calc_iv_with(&self, options: &IvOptions) -> Result<T, String>;
+    fn calc_iv_result_with(&self, options: &IvOptions) -> Result<IvResult, String>;
     fn calc_rational_iv(&self) -> Result<f64, String>;
+    fn calc_rational_iv_result(&self) -> Result<IvResult, String>;
 }
 
 impl ImpliedVolatility<f64> for Inputs {
@@ -36,7 +41,6 @@ impl ImpliedVolatility<f64> for Inputs {
     /// Initial estimation of sigma using Modified Corrado-Miller from ["A MODIFIED CORRADO-MILLER IMPLIED VOLATILITY ESTIMATOR" (2007) by Piotr P√luciennik](https://sin.put.poznan.pl/files/download/37938) method of calculating initial iv estimation.
     /// A more accurate method is the "Let's be rational" method from ["Let’s be rational" (2016) by Peter Jackel](http://www.jaeckel.org/LetsBeRational.pdf)
     /// however this method is much more complicated, it is available as calc_rational_iv().
-    #[allow(non_snake_case)]
     fn calc_iv(&self, tolerance: f64) -> Result<f64, String> {
         let mut inputs: Inputs = self.clone();
 
@@ -47,27 +51,10 @@ impl ImpliedVolatility<f64> for Inputs {
         // commented out to replace with modified corrado-miller method.
         // let mut sigma: f64 = (PI2 / inputs.t).sqrt() * (p / inputs.s);
 
-        let X: f64 = inputs.k * (-inputs.r * inputs.t).exp();
-        let fminusX: f64 = inputs.s - X;
-        let fplusX: f64 = inputs.s + X;
-        let oneoversqrtT: f64 = 1.0 / inputs.t.sqrt();
-
-        let x: f64 = oneoversqrtT * (SQRT_2PI / (fplusX));
-        let y: f64 = p - (inputs.s - inputs.k) / 2.0
-            + ((p - fminusX / 2.0).powi(2) - fminusX.powi(2) / PI).sqrt();
-
-        let mut sigma: f64 = oneoversqrtT
-            * (SQRT_2PI / fplusX)
-            * (p - fminusX / 2.0 + ((p - fminusX / 2.0).powi(2) - fminusX.powi(2) / PI).sqrt())
-            + A
-            + B / x
-            + C * y
-            + D / x.powi(2)
-            + _E * y.powi(2)
-            + F * y / x;
-
-        if sigma.is_nan() {
-            Err("Failed to converge".to_string())?
+        // The modified Corrado-Miller estimate can be negative at low volatility, where Brenner-Subrahmanyam is not.
+        let mut sigma: f64 = IvEstimator::ModifiedCorradoMiller.estimate(&inputs)?.sigma;
+        if sigma <= 0.0 {
+            sigma = IvEstimator::BrennerSubrahmanyam.estimate(&inputs)?.sigma;
         }
 
         // Initialize diff to 100 for use in while loop
@@ -88,6 +75,57 @@ impl ImpliedVolatility<f64> for Inputs {
         Ok(sigma)
     }
 
+    /// Calculates the implied volatility of the option with a configurable solver:
+    /// the root finder, iteration cap, volatility bracket and whether the tolerance is on the price or the volatility.
+    /// Newton and Halley steps that leave the bracket, such as when the vega vanishes deep in or out of the money,
+    /// or that fail to halve the previous step fall back to Brent's method on the bracket narrowed so far.
+    /// # Requires
+    /// s, k, r, q, t, p
+    /// # Returns
+    /// f64 of the implied volatility of the option, or an error if the price is outside the prices at the ends of the
+    /// bracket or the solver does not converge within the iteration cap.
+    /// # Example:
+    /// ```
+    /// use blackscholes::{ImpliedVolatility, Inputs, IvMethod, IvOptions, IvTolerance, OptionType};
+    /// let inputs = Inputs::new(OptionType::Call, 100.0, 100.0, Some(2.5), 0.05, 0.02, 20.0/365.25, None);
+    /// let options = IvOptions {
+    ///     method: IvMethod::Halley,
+    ///     tolerance: IvTolerance::Price(1e-10),
+    ///     ..IvOptions::default()
+    /// };
+    /// let iv = inputs.calc_iv_with(&options).unwrap();
+    /// ```
+    fn calc_iv_with(&self, options: &IvOptions) -> Result<f64, String> {
+        let result = solve_iv(self, options)?;
+        match result.status {
+            IvStatus::Converged => Ok(result.sigma),
+            IvStatus::BelowIntrinsic => Err("Option price is below intrinsic value".to_string()),
+            IvStatus::AboveMaximum => Err("Option price is above its maximum value".to_string()),
+            IvStatus::Clamped => {
+                Err("Implied volatility is outside the volatility bracket".to_string())
+            }
+        }
+    }
+
+    /// Calculates the implied volatility of the option with a configurable solver, as `calc_iv_with`,
+    /// together with the iterations used, the price residual and vega at the solution and how the solve ended.
+    /// Prices below intrinsic value, at or above the maximum value, or outside the prices at the ends of the bracket
+    /// are reported through the status instead of as errors.
+    /// # Requires
+    /// s, k, r, q, t, p
+    /// # Returns
+    /// IvResult of the implied volatility and its diagnostics, or an error if the solver does not converge.
+    /// # Example:
+    /// ```
+    /// use blackscholes::{ImpliedVolatility, Inputs, IvOptions, IvStatus, OptionType};
+    /// let inputs = Inputs::new(OptionType::Call, 100.0, 90.0, Some(9.0), 0.05, 0.0, 0.5, None);
+    /// let result = inputs.calc_iv_result_with(&IvOptions::default()).unwrap();
+    /// assert_eq!(result.status, IvStatus::BelowIntrinsic);
+    /// ```
+    fn calc_iv_result_with(&self, options: &IvOptions) -> Result<IvResult, String> {
+        solve_iv(self, options)
+    }
+
     /// Calculates the implied volatility of the option.
     /// # Requires
     /// s, k, r, t, p
@@ -104,29 +142,89 @@ impl ImpliedVolatility<f64> for Inputs {
     /// from Jackel's C++ implementation, imported through the C FFI.  The C++ implementation is available at [here](http://www.jaeckel.org/LetsBeRational.7z)
     /// Per Jackel's whitepaper, this method can solve for the implied volatility to f64 precision in 2 iterations.
     fn calc_rational_iv(&self) -> Result<f64, String> {
-        // extract price, or return error
-        let p = self.p.ok_or("Option price is required".to_string())?;
-
-        // "let's be rational" works with the forward and undiscounted option price, so remove the discount
-        let rate_inv_discount = (self.r * self.t).exp();
-        let p = p * rate_inv_discount;
-
-        // compute the forward price
-        let f = self.s * rate_inv_discount;
-        // The Black-Scholes-Merton formula takes into account dividend yield by setting S = S * e^{-qt}, do this here with the forward
-        let f = f * (-self.q * self.t).exp();
-
-        let sigma = implied_volatility_from_a_transformed_rational_guess(
-            p,
-            f,
-            self.k,
-            self.t,
-            self.option_type,
-        );
-
-        if sigma.is_nan() || sigma.is_infinite() || sigma < 0.0 {
-            Err("Implied volatility failed to converge".to_string())?
+        rational_iv(self).map_err(|kind| match kind {
+            IvErrorKind::MissingPrice => "Option price is required".to_string(),
+            _ => "Implied volatility failed to converge".to_string(),
+        })
+    }
+
+    /// Calculates the implied volatility of the option with the "Let's be rational" method, as `calc_rational_iv`,
+    /// together with the price residual and vega at the solution. Prices below intrinsic value or at or above the
+    /// maximum value are reported through the status rather than collapsed into an error.
+    /// # Requires
+    /// s, k, r, q, t, p
+    /// # Returns
+    /// IvResult of the implied volatility and its diagnostics.
+    /// # Example:
+    /// ```
+    /// use blackscholes::{ImpliedVolatility, Inputs, IvStatus, OptionType};
+    /// let inputs = Inputs::new(OptionType::Put, 100.0, 100.0, Some(101.0), 0.05, 0.0, 0.5, None);
+    /// let result = inputs.calc_rational_iv_result().unwrap();
+    /// assert_eq!(result.status, IvStatus::AboveMaximum);
+    /// ```
+    fn calc_rational_iv_result(&self) -> Result<IvResult, String> {
+        solve_rational_iv(self)
+    }
+}
+
+/// Why no implied volatility could be found for a quote.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IvErrorKind {
+    /// The quote has no option price
+    MissingPrice,
+    /// The price is below the intrinsic value of the option
+    BelowIntrinsic,
+    /// The price is above the maximum value of the option (the forward for calls, the strike for puts)
+    AboveMaximum,
+    /// The solver did not produce a finite, non-negative volatility
+    NotConverged,
+}
+
+impl Display for IvErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmtResult {
+        match self {
+            IvErrorKind::MissingPrice => write!(f, "Option price is required"),
+            IvErrorKind::BelowIntrinsic => write!(f, "Option price is below intrinsic value"),
+            IvErrorKind::AboveMaximum => write!(f, "Option price is above its maximum value"),
+            IvErrorKind::NotConverged => write!(f, "Implied volatility failed to converge"),
         }
+    }
+}
+
+/// The "Let's be rational" implied volatility of `inputs`, shared by `calc_rational_iv` and the batch solvers.
+pub(crate) fn rational_iv(inputs: &Inputs) -> Result<f64, IvErrorKind> {
+    // extract price, or return error
+    let p = inputs.p.ok_or(IvErrorKind::MissingPrice)?;
+
+    // "let's be rational" works with the forward and undiscounted option price, so remove the discount
+    let rate_inv_discount = (inputs.r * inputs.t).exp();
+    let p = p * rate_inv_discount;
+
+    // compute the forward price
+    let f = inputs.s * rate_inv_discount;
+    // The Black-Scholes-Merton formula takes into account dividend yield by setting S = S * e^{-qt}, do this here with the forward
+    let f = f * (-inputs.q * inputs.t).exp();
+
+    forward_rational_iv(p, f, inputs.k, inputs.t, inputs.option_type)
+}
+
+/// The "Let's be rational" implied volatility of an undiscounted price on a forward,
+/// with the solver's sentinel values mapped to error kinds.
+pub(crate) fn forward_rational_iv(
+    p: f64,
+    f: f64,
+    k: f64,
+    t: f64,
+    option_type: OptionType,
+) -> Result<f64, IvErrorKind> {
+    let sigma = implied_volatility_from_a_transformed_rational_guess(p, f, k, t, option_type);
+    if sigma == -f64::MAX {
+        Err(IvErrorKind::BelowIntrinsic)
+    } else if sigma == f64::MAX {
+        Err(IvErrorKind::AboveMaximum)
+    } else if sigma.is_nan() || sigma.is_infinite() || sigma < 0.0 {
+        Err(IvErrorKind::NotConverged)
+    } else {
         Ok(sigma)
     }
 }
@@ -0,0 +1,169 @@
+use std::thread;
+
+use crate::{
+    implied_volatility::{forward_rational_iv, rational_iv},
+    Inputs, IvErrorKind, OptionType,
+};
+
+/// An option chain stored as a struct of arrays, one row per quote, for batch implied volatility solving.
+///
+/// Premiums are undiscounted (forward) prices, so each row is solved exactly as
+/// [`implied_volatility_from_a_transformed_rational_guess`](crate::lets_be_rational::implied_volatility_from_a_transformed_rational_guess)
+/// would solve it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptionChain<'a> {
+    /// The type of each option
+    pub option_types: &'a [OptionType],
+    /// Forward price of the underlying for each option's expiry
+    pub forwards: &'a [f64],
+    /// Strike prices
+    pub strikes: &'a [f64],
+    /// Times to maturity in years
+    pub expiries: &'a [f64],
+    /// Undiscounted option prices
+    pub premiums: &'a [f64],
+}
+
+impl<'a> OptionChain<'a> {
+    /// Creates instance of the `OptionChain` struct, checking that every column has one value per row.
+    /// # Arguments
+    /// * `option_types` - The type of each option.
+    /// * `forwards` - The forward prices.
+    /// * `strikes` - The strike prices.
+    /// * `expiries` - The times to maturity in years.
+    /// * `premiums` - The undiscounted option prices.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionChain, OptionType};
+    /// let chain = OptionChain::new(
+    ///     &[OptionType::Put, OptionType::Call],
+    ///     &[100.0, 100.0],
+    ///     &[90.0, 110.0],
+    ///     &[0.5, 0.5],
+    ///     &[2.1, 2.9],
+    /// )
+    /// .unwrap();
+    /// ```
+    /// # Returns
+    /// An instance of the `OptionChain` struct.
+    pub fn new(
+        option_types: &'a [OptionType],
+        forwards: &'a [f64],
+        strikes: &'a [f64],
+        expiries: &'a [f64],
+        premiums: &'a [f64],
+    ) -> Result<Self, String> {
+        let rows = option_types.len();
+        if [
+            forwards.len(),
+            strikes.len(),
+            expiries.len(),
+            premiums.len(),
+        ]
+        .iter()
+        .any(|&len| len != rows)
+        {
+            return Err("Every column of the option chain must have the same length".to_string());
+        }
+        Ok(Self {
+            option_types,
+            forwards,
+            strikes,
+            expiries,
+            premiums,
+        })
+    }
+
+    /// Number of quotes in the chain.
+    pub fn len(&self) -> usize {
+        self.option_types.len()
+    }
+
+    /// Whether the chain has no quotes.
+    pub fn is_empty(&self) -> bool {
+        self.option_types.is_empty()
+    }
+
+    /// Calculates the implied volatility of every quote with the "Let's be rational" method.
+    /// # Arguments
+    /// * `threads` - The number of threads to split the chain across; 0 or 1 solves on the calling thread.
+    /// # Returns
+    /// Vec<Result<f64, IvErrorKind>> with the implied volatility, or the reason there is none, for each row.
+    /// # Example
+    /// ```
+    /// use blackscholes::{IvErrorKind, OptionChain, OptionType};
+    /// let chain = OptionChain::new(
+    ///     &[OptionType::Put, OptionType::Call],
+    ///     &[100.0, 100.0],
+    ///     &[90.0, 110.0],
+    ///     &[0.5, 0.5],
+    ///     &[2.1, -1.0],
+    /// )
+    /// .unwrap();
+    /// let vols = chain.calc_rational_iv(2);
+    /// assert!(vols[0].is_ok());
+    /// assert_eq!(vols[1], Err(IvErrorKind::BelowIntrinsic));
+    /// ```
+    pub fn calc_rational_iv(&self, threads: usize) -> Vec<Result<f64, IvErrorKind>> {
+        solve_rows(self.len(), threads, |i| {
+            forward_rational_iv(
+                self.premiums[i],
+                self.forwards[i],
+                self.strikes[i],
+                self.expiries[i],
+                self.option_types[i],
+            )
+        })
+    }
+}
+
+/// Calculates the implied volatility of every option in `inputs` with the "Let's be rational" method,
+/// giving the same values as calling `calc_rational_iv` on each one.
+/// # Arguments
+/// * `inputs` - The options, each with its price `p`.
+/// * `threads` - The number of threads to split the options across; 0 or 1 solves on the calling thread.
+/// # Returns
+/// Vec<Result<f64, IvErrorKind>> with the implied volatility, or the reason there is none, for each option.
+/// # Example
+/// ```
+/// use blackscholes::{calc_rational_iv_batch, Inputs, IvErrorKind, OptionType};
+/// let quotes = vec![
+///     Inputs::new(OptionType::Call, 100.0, 100.0, Some(6.0), 0.05, 0.0, 0.5, None),
+///     Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 0.5, None),
+/// ];
+/// let vols = calc_rational_iv_batch(&quotes, 1);
+/// assert!(vols[0].is_ok());
+/// assert_eq!(vols[1], Err(IvErrorKind::MissingPrice));
+/// ```
+pub fn calc_rational_iv_batch(inputs: &[Inputs], threads: usize) -> Vec<Result<f64, IvErrorKind>> {
+    solve_rows(inputs.len(), threads, |i| rational_iv(&inputs[i]))
+}
+
+/// Applies `solve` to every row index, splitting the rows into contiguous blocks across scoped threads.
+fn solve_rows<F>(rows: usize, threads: usize, solve: F) -> Vec<Result<f64, IvErrorKind>>
+where
+    F: Fn(usize) -> Result<f64, IvErrorKind> + Sync,
+{
+    let threads = threads.clamp(1, rows.max(1));
+    if threads == 1 {
+        return (0..rows).map(solve).collect();
+    }
+    let block = rows.div_ceil(threads);
+    let solve = &solve;
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..rows)
+            .step_by(block)
+            .map(|start| {
+                scope.spawn(move || {
+                    (start..(start + block).min(rows))
+                        .map(solve)
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("implied volatility worker panicked"))
+            .collect()
+    })
+}
@@ -0,0 +1,228 @@
+use std::f64::consts::{PI, SQRT_2};
+
+use statrs::consts::SQRT_2PI;
+
+use crate::{iv_solver::price_limits, Inputs, OptionType};
+
+// Regression coefficients of the modified Corrado-Miller estimator.
+const A: f64 = 4.626_275_3e-1;
+const B: f64 = -1.168_519_2e-2;
+const C: f64 = 9.635_418_5e-4;
+const D: f64 = 7.535_022_5e-5;
+const E: f64 = 1.424_516_45e-5;
+const F: f64 = -2.102_376_9e-5;
+/// Spot price of the options the regression was fitted to.
+const REGRESSION_SPOT: f64 = 100.0;
+
+// Rational approximation after Li (2008): the total volatility is `P(a, b) / Q(a, b)` for the absolute
+// log-moneyness `a` and the square root `b` of the out-of-the-money price over `sqrt(F K)`, with `P` and `Q` cubic.
+// The coefficients were fitted for this crate, by least squares on the relative error over
+// `a <= 0.5` and total volatilities in `[0.25, 1.5]`, rather than taken from the paper.
+/// Powers of `(a, b)` of each term of the rational approximation.
+const LI_POWERS: [(i32, i32); 10] = [
+    (0, 0),
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (1, 0),
+    (1, 1),
+    (1, 2),
+    (2, 0),
+    (2, 1),
+    (3, 0),
+];
+const LI_NUMERATOR: [f64; 10] = [
+    -0.072_020_783_555_378_35,
+    1.517_648_346_439_172_8,
+    -7.855_142_483_820_083,
+    22.480_344_951_837_612,
+    0.808_374_047_716_420_4,
+    -5.633_035_724_852_966,
+    38.810_792_572_316_2,
+    -1.662_660_931_987_878_2,
+    20.582_710_849_499_13,
+    2.146_254_604_009_944_6,
+];
+const LI_DENOMINATOR: [f64; 10] = [
+    1.0,
+    -3.945_609_446_428_152_4,
+    19.529_277_335_357_662,
+    -12.029_436_934_843_327,
+    -1.902_170_089_993_680_6,
+    28.508_008_310_016_32,
+    -21.097_240_456_445_65,
+    4.117_695_223_203_242,
+    -4.351_091_845_932_835,
+    0.232_531_904_382_941_47,
+];
+
+/// A closed-form implied volatility estimator, for screening quotes or starting a solver without a full solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IvEstimator {
+    /// Brenner and Subrahmanyam (1988): the first-order expansion of the at-the-money forward price
+    BrennerSubrahmanyam,
+    /// Corrado and Miller (1996): the quadratic approximation extending Brenner-Subrahmanyam away from the money
+    CorradoMiller,
+    /// Pluciennik (2007): Corrado-Miller corrected by a regression on its own inputs
+    ModifiedCorradoMiller,
+    /// Li (2005): the closed-form root of the third-order expansion of the at-the-money forward price.
+    /// This is Li's at-the-money formula, applied to the half straddle away from the money;
+    /// his 2008 rational approximation is [`IvEstimator::LiRational`].
+    LiAtTheMoney,
+    /// Li (2008): a ratio of cubics in the log-moneyness and the normalised out-of-the-money price,
+    /// accurate much further from the money than the expansions.
+    /// The coefficients were fitted for this crate over its domain rather than taken from the paper.
+    LiRational,
+}
+
+/// Where an estimator is within 5% of the true volatility, in terms of the absolute log-moneyness
+/// `|ln(F / K)|`, the total volatility `sigma * sqrt(t)` and the time to maturity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IvEstimatorDomain {
+    /// Largest absolute log-moneyness `|ln(F / K)|`
+    pub max_log_moneyness: f64,
+    /// Smallest total volatility `sigma * sqrt(t)`
+    pub min_total_volatility: f64,
+    /// Largest total volatility `sigma * sqrt(t)`
+    pub max_total_volatility: f64,
+    /// Shortest time to maturity in years
+    pub min_t: f64,
+    /// Longest time to maturity in years
+    pub max_t: f64,
+}
+
+/// A closed-form implied volatility estimate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IvEstimate {
+    /// The estimated volatility
+    pub sigma: f64,
+    /// Whether the option's moneyness and the estimated total volatility lie in the estimator's accuracy domain
+    pub within_domain: bool,
+}
+
+/// The price of `inputs` as a call, through put-call parity for puts, with the dividend-discounted spot
+/// and the discounted strike, the present values of the forward and strike.
+fn present_values(inputs: &Inputs) -> Result<(f64, f64, f64), String> {
+    let p = inputs
+        .p
+        .ok_or("inputs.p must contain Some(f64), found None".to_string())?;
+    if inputs.t <= 0.0 {
+        return Err("Time to maturity must be positive".to_string());
+    }
+    let (intrinsic, maximum) = price_limits(inputs);
+    if p <= intrinsic || p >= maximum {
+        return Err(
+            "Option price must lie strictly between its intrinsic and maximum values".to_string(),
+        );
+    }
+    let spot = inputs.s * (-inputs.q * inputs.t).exp();
+    let strike = inputs.k * (-inputs.r * inputs.t).exp();
+    let call = match inputs.option_type {
+        OptionType::Call => p,
+        OptionType::Put => p + spot - strike,
+    };
+    Ok((call, spot, strike))
+}
+
+impl IvEstimator {
+    /// The accuracy domain of the estimator.
+    /// # Returns
+    /// IvEstimatorDomain of the log-moneyness, total volatility and time to maturity within which the estimate is within 5% of the
+    /// true volatility.
+    /// # Example
+    /// ```
+    /// use blackscholes::IvEstimator;
+    /// let domain = IvEstimator::CorradoMiller.domain();
+    /// assert!(domain.max_log_moneyness > IvEstimator::BrennerSubrahmanyam.domain().max_log_moneyness);
+    /// ```
+    pub fn domain(&self) -> IvEstimatorDomain {
+        let (max_log_moneyness, min_total_volatility, max_total_volatility, min_t, max_t) =
+            match self {
+                IvEstimator::BrennerSubrahmanyam => (0.02, 0.1, 1.0, 0.0, f64::INFINITY),
+                IvEstimator::CorradoMiller => (0.1, 0.15, 1.0, 0.0, f64::INFINITY),
+                // The regression terms are not scale free in time, so it only holds near the one-year options it was fitted to.
+                IvEstimator::ModifiedCorradoMiller => (0.1, 0.3, 1.5, 0.8, 1.25),
+                IvEstimator::LiAtTheMoney => (0.02, 0.1, 1.5, 0.0, f64::INFINITY),
+                IvEstimator::LiRational => (0.5, 0.25, 1.5, 0.0, f64::INFINITY),
+            };
+        IvEstimatorDomain {
+            max_log_moneyness,
+            min_total_volatility,
+            max_total_volatility,
+            min_t,
+            max_t,
+        }
+    }
+
+    /// Estimates the implied volatility of the option in closed form.
+    /// # Requires
+    /// s, k, r, q, t, p, with p strictly between the intrinsic and maximum values of the option.
+    /// # Returns
+    /// IvEstimate of the estimated volatility and whether it lies in the accuracy domain of the estimator.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, IvEstimator, OptionType};
+    /// let inputs = Inputs::new(OptionType::Call, 100.0, 102.0, Some(6.5), 0.04, 0.01, 0.5, None);
+    /// let estimate = IvEstimator::CorradoMiller.estimate(&inputs).unwrap();
+    /// assert!(estimate.within_domain);
+    /// ```
+    pub fn estimate(&self, inputs: &Inputs) -> Result<IvEstimate, String> {
+        let (call, spot, strike) = present_values(inputs)?;
+        let sqrt_t = inputs.t.sqrt();
+        let half_moneyness = 0.5 * (spot - strike);
+        // Half the straddle, which is close to the at-the-money price near the money.
+        let half_straddle = call - half_moneyness;
+        let sigma = match self {
+            IvEstimator::BrennerSubrahmanyam => {
+                SQRT_2PI * 2.0 * half_straddle / ((spot + strike) * sqrt_t)
+            }
+            IvEstimator::CorradoMiller | IvEstimator::ModifiedCorradoMiller => {
+                // The discriminant is negative far from the money, where the estimate does not exist; clamping it
+                // keeps the estimate finite, and such options are outside the domain anyway.
+                let discriminant = (half_straddle.powi(2) - (spot - strike).powi(2) / PI).max(0.0);
+                let y = half_straddle + discriminant.sqrt();
+                let x = SQRT_2PI / ((spot + strike) * sqrt_t);
+                let corrado_miller = x * y;
+                if *self == IvEstimator::CorradoMiller {
+                    corrado_miller
+                } else {
+                    // The regression was fitted to options on a spot of 100, so rescale to that level.
+                    let scale = REGRESSION_SPOT / inputs.s;
+                    let (x, y) = (x / scale, y * scale);
+                    corrado_miller + A + B / x + C * y + D / x.powi(2) + E * y.powi(2) + F * y / x
+                }
+            }
+            IvEstimator::LiAtTheMoney => {
+                // Root of alpha = v - v^3 / 24 below 2 sqrt(2), from the expansion of 2 N(v / 2) - 1.
+                let alpha = SQRT_2PI * 2.0 * half_straddle / (spot + strike);
+                let angle = (-3.0 * alpha / (4.0 * SQRT_2)).max(-1.0).acos();
+                4.0 * SQRT_2 * (angle / 3.0 - 2.0 * PI / 3.0).cos() / sqrt_t
+            }
+            IvEstimator::LiRational => {
+                // By put-call symmetry the normalised out-of-the-money price depends only on |x|.
+                let x = (spot / strike).ln();
+                let out_of_the_money =
+                    call / (spot * strike).sqrt() - (2.0 * (0.5 * x).sinh()).max(0.0);
+                let (a, b) = (x.abs(), out_of_the_money.max(0.0).sqrt());
+                let polynomial = |coefficients: &[f64; 10]| -> f64 {
+                    LI_POWERS
+                        .iter()
+                        .zip(coefficients)
+                        .map(|(&(i, j), coefficient)| coefficient * a.powi(i) * b.powi(j))
+                        .sum()
+                };
+                polynomial(&LI_NUMERATOR) / polynomial(&LI_DENOMINATOR) / sqrt_t
+            }
+        };
+        let domain = self.domain();
+        let total_volatility = sigma * sqrt_t;
+        Ok(IvEstimate {
+            sigma,
+            within_domain: (spot / strike).ln().abs() <= domain.max_log_moneyness
+                && total_volatility >= domain.min_total_volatility
+                && total_volatility <= domain.max_total_volatility
+                && inputs.t >= domain.min_t
+                && inputs.t <= domain.max_t,
+        })
+    }
+}
@@ -0,0 +1,347 @@
+use crate::{
+    implied_volatility::rational_iv,
+    lets_be_rational::IMPLIED_VOLATILITY_MAXIMUM_ITERATIONS,
+    numerics::{brent_with, newton_bracketed},
+    Greeks, Inputs, IvErrorKind, IvEstimator, OptionType, Pricing,
+};
+
+/// Starting volatility of the Newton and Halley iterations when the modified Corrado-Miller estimate is unusable.
+const INITIAL_GUESS: f64 = 0.5;
+
+/// The root finder used by `calc_iv_with`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IvMethod {
+    /// Newton-Raphson on the vega, falling back to Brent's method if a step leaves the bracket
+    /// or fails to halve the previous one
+    Newton,
+    /// Halley's method on the vega and vomma, with the same fallback as Newton
+    Halley,
+    /// Brent's method on the bracket
+    Brent,
+    /// Bisection of the bracket
+    Bisection,
+    /// Jäckel's "Let's be rational", which ignores the tolerance and iteration cap
+    Rational,
+}
+
+/// What the solver's tolerance is measured on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IvTolerance {
+    /// Stop when the model price is within this amount of the option price
+    Price(f64),
+    /// Stop when the volatility is known to within this amount
+    Volatility(f64),
+}
+
+/// Settings of the implied volatility solver behind `calc_iv_with`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IvOptions {
+    /// The root finder
+    pub method: IvMethod,
+    /// Maximum number of price evaluations after the bracket is checked
+    pub max_iterations: usize,
+    /// Lowest volatility considered
+    pub lower: f64,
+    /// Highest volatility considered
+    pub upper: f64,
+    /// When to stop iterating
+    pub tolerance: IvTolerance,
+}
+
+impl IvTolerance {
+    /// The volatility and price tolerances, with zero for the one not in use.
+    fn split(self) -> (f64, f64) {
+        match self {
+            IvTolerance::Price(tolerance) => (0.0, tolerance),
+            IvTolerance::Volatility(tolerance) => (tolerance, 0.0),
+        }
+    }
+}
+
+impl Default for IvOptions {
+    fn default() -> Self {
+        Self {
+            method: IvMethod::Newton,
+            max_iterations: 100,
+            lower: 1e-6,
+            upper: 10.0,
+            tolerance: IvTolerance::Volatility(1e-10),
+        }
+    }
+}
+
+impl IvOptions {
+    /// Creates instance of the `IvOptions` struct.
+    /// # Arguments
+    /// * `method` - The root finder.
+    /// * `max_iterations` - The maximum number of iterations.
+    /// * `lower` - The lowest volatility considered.
+    /// * `upper` - The highest volatility considered.
+    /// * `tolerance` - When to stop iterating.
+    /// # Example
+    /// ```
+    /// use blackscholes::{IvMethod, IvOptions, IvTolerance};
+    /// let options = IvOptions::new(IvMethod::Halley, 50, 0.01, 3.0, IvTolerance::Price(1e-8));
+    /// ```
+    /// # Returns
+    /// An instance of the `IvOptions` struct.
+    pub fn new(
+        method: IvMethod,
+        max_iterations: usize,
+        lower: f64,
+        upper: f64,
+        tolerance: IvTolerance,
+    ) -> Self {
+        Self {
+            method,
+            max_iterations,
+            lower,
+            upper,
+            tolerance,
+        }
+    }
+}
+
+/// How an implied volatility solve ended.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IvStatus {
+    /// The volatility reprices the option within the tolerance
+    Converged,
+    /// The price is below the discounted intrinsic value, so no volatility reprices it; the volatility is zero
+    BelowIntrinsic,
+    /// The price is at or above the discounted asset (calls) or strike (puts), reached only as the volatility
+    /// tends to infinity; the volatility is infinite
+    AboveMaximum,
+    /// The implied volatility lies outside the bracket, so the nearest end of the bracket is returned
+    Clamped,
+}
+
+/// An implied volatility together with diagnostics of the solve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IvResult {
+    /// The implied volatility
+    pub sigma: f64,
+    /// Iterations used; for Let's Be Rational, the fixed number of refinement steps it runs at most
+    pub iterations: usize,
+    /// Model price at `sigma` less the option price
+    pub residual: f64,
+    /// Vega at `sigma`, per 1% change in volatility
+    pub vega: f64,
+    /// How the solve ended
+    pub status: IvStatus,
+}
+
+impl IvResult {
+    /// The result at `sigma`, with the residual and vega evaluated there.
+    fn at(
+        inputs: &Inputs,
+        p: f64,
+        sigma: f64,
+        iterations: usize,
+        status: IvStatus,
+    ) -> Result<Self, String> {
+        let at = Inputs {
+            sigma: Some(sigma),
+            ..inputs.clone()
+        };
+        Ok(Self {
+            sigma,
+            iterations,
+            residual: at.calc_price()? - p,
+            vega: at.calc_vega()?,
+            status,
+        })
+    }
+
+    /// The result at a limit of the attainable prices, where the vega vanishes.
+    fn limit(sigma: f64, residual: f64, status: IvStatus) -> Self {
+        Self {
+            sigma,
+            iterations: 0,
+            residual,
+            vega: 0.0,
+            status,
+        }
+    }
+}
+
+/// Solves for the volatility at which `inputs` is worth `inputs.p` with the chosen method,
+/// reporting prices outside the attainable range or the bracket through the status rather than as errors.
+pub(crate) fn solve_iv(inputs: &Inputs, options: &IvOptions) -> Result<IvResult, String> {
+    let p = inputs
+        .p
+        .ok_or("inputs.p must contain Some(f64), found None".to_string())?;
+    let IvOptions {
+        lower,
+        upper,
+        tolerance,
+        ..
+    } = *options;
+    if !(lower > 0.0 && upper > lower) {
+        return Err("Volatility bracket requires 0 < lower < upper".to_string());
+    }
+    let (vol_tolerance, price_tolerance) = tolerance.split();
+    if !(vol_tolerance >= 0.0 && price_tolerance >= 0.0) {
+        return Err("Tolerance must be non-negative".to_string());
+    }
+
+    let (intrinsic, maximum) = price_limits(inputs);
+    if p < intrinsic {
+        return Ok(IvResult::limit(
+            0.0,
+            intrinsic - p,
+            IvStatus::BelowIntrinsic,
+        ));
+    }
+    if p >= maximum {
+        return Ok(IvResult::limit(
+            f64::INFINITY,
+            maximum - p,
+            IvStatus::AboveMaximum,
+        ));
+    }
+
+    // The price increases with volatility, so the root is bracketed when the errors have opposite signs.
+    let error = |sigma: f64| -> Result<f64, String> {
+        Ok(Inputs {
+            sigma: Some(sigma),
+            ..inputs.clone()
+        }
+        .calc_price()?
+            - p)
+    };
+    let (f_lo, f_hi) = (error(lower)?, error(upper)?);
+    if f_lo > price_tolerance {
+        return IvResult::at(inputs, p, lower, 0, IvStatus::Clamped);
+    }
+    if f_hi < -price_tolerance {
+        return IvResult::at(inputs, p, upper, 0, IvStatus::Clamped);
+    }
+    if f_lo.abs() <= price_tolerance {
+        return IvResult::at(inputs, p, lower, 0, IvStatus::Converged);
+    }
+    if f_hi.abs() <= price_tolerance {
+        return IvResult::at(inputs, p, upper, 0, IvStatus::Converged);
+    }
+    let (sigma, iterations) = find_root(inputs, p, options)?;
+    IvResult::at(inputs, p, sigma, iterations, IvStatus::Converged)
+}
+
+/// Solves for the implied volatility with "Let's be rational", reporting its sentinel values through the status.
+pub(crate) fn solve_rational_iv(inputs: &Inputs) -> Result<IvResult, String> {
+    let p = inputs.p.ok_or("Option price is required".to_string())?;
+    let (intrinsic, maximum) = price_limits(inputs);
+    match rational_iv(inputs) {
+        Ok(sigma) => IvResult::at(
+            inputs,
+            p,
+            sigma,
+            IMPLIED_VOLATILITY_MAXIMUM_ITERATIONS as usize,
+            IvStatus::Converged,
+        ),
+        Err(IvErrorKind::BelowIntrinsic) => Ok(IvResult::limit(
+            0.0,
+            intrinsic - p,
+            IvStatus::BelowIntrinsic,
+        )),
+        Err(IvErrorKind::AboveMaximum) => Ok(IvResult::limit(
+            f64::INFINITY,
+            maximum - p,
+            IvStatus::AboveMaximum,
+        )),
+        Err(kind) => Err(kind.to_string()),
+    }
+}
+
+/// The discounted intrinsic value and the maximum value of the option, its prices at zero and infinite volatility.
+pub(crate) fn price_limits(inputs: &Inputs) -> (f64, f64) {
+    let forward = inputs.s * (-inputs.q * inputs.t).exp();
+    let strike = inputs.k * (-inputs.r * inputs.t).exp();
+    let intrinsic = (inputs.option_type * (forward - strike)).max(0.0);
+    match inputs.option_type {
+        OptionType::Call => (intrinsic, forward),
+        OptionType::Put => (intrinsic, strike),
+    }
+}
+
+/// Runs the chosen root finder on a bracket known to contain the implied volatility.
+/// # Returns
+/// The implied volatility and the number of iterations used.
+fn find_root(inputs: &Inputs, p: f64, options: &IvOptions) -> Result<(f64, usize), String> {
+    let IvOptions {
+        method,
+        max_iterations,
+        lower,
+        upper,
+        tolerance,
+    } = *options;
+    let (vol_tolerance, price_tolerance) = tolerance.split();
+    let at = |sigma: f64| Inputs {
+        sigma: Some(sigma),
+        ..inputs.clone()
+    };
+    let error = |sigma: f64| Ok(at(sigma).calc_price()? - p);
+    let (mut lo, mut hi) = (lower, upper);
+
+    match method {
+        IvMethod::Newton | IvMethod::Halley => {
+            let guess = IvEstimator::ModifiedCorradoMiller
+                .estimate(inputs)
+                .map_or(f64::NAN, |estimate| estimate.sigma);
+            let start = if guess > lo && guess < hi {
+                guess
+            } else {
+                INITIAL_GUESS.clamp(lo, hi)
+            };
+            // Vega and vomma are quoted per 1% change in volatility.
+            let step = |sigma: f64, diff: f64| -> Result<f64, String> {
+                let vega = 100.0 * at(sigma).calc_vega()?;
+                let newton = diff / vega;
+                if method == IvMethod::Halley {
+                    let vomma = 100.0 * at(sigma).calc_vomma()?;
+                    Ok(newton / (1.0 - 0.5 * newton * vomma / vega))
+                } else {
+                    Ok(newton)
+                }
+            };
+            newton_bracketed(
+                error,
+                step,
+                lo,
+                hi,
+                start,
+                true,
+                vol_tolerance,
+                price_tolerance,
+                max_iterations,
+            )
+        }
+        IvMethod::Brent => brent_with(
+            error,
+            lo,
+            hi,
+            vol_tolerance,
+            price_tolerance,
+            max_iterations,
+        ),
+        IvMethod::Bisection => {
+            for iteration in 1..=max_iterations {
+                let mid = 0.5 * (lo + hi);
+                let diff = error(mid)?;
+                if diff.abs() <= price_tolerance || 0.5 * (hi - lo) <= vol_tolerance {
+                    return Ok((mid, iteration));
+                }
+                if diff < 0.0 {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            Err("Implied volatility failed to converge within the iteration limit".to_string())
+        }
+        IvMethod::Rational => match rational_iv(inputs) {
+            Ok(sigma) => Ok((sigma, IMPLIED_VOLATILITY_MAXIMUM_ITERATIONS as usize)),
+            Err(kind) => Err(kind.to_string()),
+        },
+    }
+}
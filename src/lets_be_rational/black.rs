@@ -60,12 +60,11 @@ fn normalised_black_call_with_optimal_use_of_codys_functions(x: f64, s: f64) ->
 ///
 /// # Returns
 ///
-/// * `Some(f64)` containing the computed value if t < 0.21
-/// * `None` if t >= 0.21, indicating the approximation is not valid
+/// * `Some(f64)` containing the computed value if t < 2 * DBL_EPSILON^(1/16) (about 0.211)
+/// * `None` otherwise, indicating the approximation is not valid
 pub fn small_t_expansion_of_normalised_black_call(h: f64, t: f64) -> Option<f64> {
-    const T_THRESHOLD: f64 = 0.21;
-
-    if t >= T_THRESHOLD {
+    // The same threshold `normalised_black_call` uses to choose this expansion.
+    if t >= SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD {
         return None;
     }
 
@@ -177,7 +176,25 @@ pub(crate) fn normalised_black_call(x: f64, s: f64) -> f64 {
     normalised_black_call_with_optimal_use_of_codys_functions(x, s)
 }
 
-pub(crate) fn normalised_black(x: f64, s: f64, option_type: OptionType) -> f64 {
+/// Calculates the normalised Black price `b = B / sqrt(F K)` in terms of log-moneyness and total volatility.
+///
+/// # Arguments
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `s` - The total volatility `sigma * sqrt(T)`.
+/// * `option_type` - The type of the option (call or put), represented by `OptionType`.
+///
+/// # Returns
+/// The normalised price; multiply by `sqrt(F K)` for the undiscounted Black price.
+///
+/// # Examples
+/// ```
+/// use blackscholes::{OptionType, lets_be_rational::{black, normalised_black}};
+///
+/// let (f, k, sigma, t) = (105.0_f64, 100.0_f64, 0.2_f64, 0.5_f64);
+/// let b = normalised_black((f / k).ln(), sigma * t.sqrt(), OptionType::Call);
+/// assert!(((f * k).sqrt() * b - black(f, k, sigma, t, OptionType::Call)).abs() < 1e-12);
+/// ```
+pub fn normalised_black(x: f64, s: f64, option_type: OptionType) -> f64 {
     normalised_black_call(option_type * x, s) /* Reciprocal-strike call-put equivalence */
 }
 /// Computes the asymptotic expansion of the normalized Black call price.
@@ -192,7 +209,8 @@ pub(crate) fn normalised_black(x: f64, s: f64, option_type: OptionType) -> f64 {
 /// * `Ok(f64)` - The computed price
 /// * `Err(String)` - An error message if the input is out of the valid range
 pub fn asymptotic_expansion_of_normalised_black_call(h: f64, t: f64) -> Result<f64, &'static str> {
-    let tau_small: f64 = 2.0 * f64::EPSILON.sqrt().powi(16);
+    // The same thresholds `normalised_black_call` uses to choose this expansion.
+    let tau_small: f64 = SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD;
 
     // Check if we're in the correct region
     // From section 6: "In the region of large negative h, we can realize these preferences by the aid of the formulation (6.10) for the normalized Black function"
@@ -213,7 +231,7 @@ pub fn asymptotic_expansion_of_normalised_black_call(h: f64, t: f64) -> Result<f
         + 3.0 / (h_minus_t_sq * h_minus_t_sq * h_minus_t);
 
     // Initial factorial and sign values for the series expansion
-    let mut factorial = 15.0; // 3! = 6, next factorial would be for 5!
+    let factorial = 15.0; // 3! = 6, next factorial would be for 5!
     let mut sign = -1.0;
 
     let mut term_plus = 1.0 / (factorial * h_plus_t_sq * h_plus_t_sq * h_plus_t);
@@ -225,11 +243,10 @@ pub fn asymptotic_expansion_of_normalised_black_call(h: f64, t: f64) -> Result<f
         y_h_plus_t += sign * term_plus;
         y_h_minus_t += sign * term_minus;
 
-        // Update sign and factorial for the next term
+        // Update sign and the running term for the next iteration
         sign = -sign;
         let i_f64 = i as f64;
         let factor = (2.0 * i_f64 + 2.0) * (2.0 * i_f64 + 3.0);
-        factorial *= factor;
         term_plus *= h_plus_t_sq / factor;
         term_minus *= h_minus_t_sq / factor;
     }
@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+
+use crate::{
+    implied_volatility::forward_rational_iv,
+    lets_be_rational::{
+        black,
+        normal_distribution::{standard_normal_cdf, standard_normal_pdf},
+    },
+    OptionType, DAYS_PER_YEAR,
+};
+
+/// How the premium of an option on a futures contract is settled.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Margining {
+    /// The premium is paid up front, so Black-76 prices are discounted at the risk-free rate
+    Premium,
+    /// Futures-style margining (Asay, 1982): no premium changes hands up front and the option is marked to market
+    /// like the future, so prices are not discounted and do not depend on the interest rate
+    FuturesStyle,
+}
+
+/// When the option can be exercised.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Exercise {
+    /// Only at expiry
+    European,
+    /// At any time up to expiry
+    American,
+}
+
+/// An option on a futures contract under the Black (1976) model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Black76Inputs {
+    /// The type of the option (call or put)
+    pub option_type: OptionType,
+    /// Futures price
+    pub f: f64,
+    /// Strike price
+    pub k: f64,
+    /// Option price
+    pub p: Option<f64>,
+    /// Risk-free interest rate, only used with premium margining
+    pub r: f64,
+    /// Time to maturity in years
+    pub t: f64,
+    /// Volatility
+    pub sigma: Option<f64>,
+    /// How the premium is settled
+    pub margining: Margining,
+    /// When the option can be exercised
+    pub exercise: Exercise,
+}
+
+impl Black76Inputs {
+    /// Creates instance of the `Black76Inputs` struct.
+    /// # Arguments
+    /// * `option_type` - The type of option to be priced.
+    /// * `f` - The futures price.
+    /// * `k` - The strike price of the option.
+    /// * `p` - The price of the option.
+    /// * `r` - The risk-free interest rate.
+    /// * `t` - The time to maturity of the option in years.
+    /// * `sigma` - The volatility of the futures price.
+    /// * `margining` - How the premium is settled.
+    /// * `exercise` - When the option can be exercised.
+    /// # Example
+    /// ```
+    /// use blackscholes::OptionType;
+    /// use blackscholes::lets_be_rational::{Black76Inputs, Exercise, Margining};
+    /// let inputs = Black76Inputs::new(
+    ///     OptionType::Call, 100.0, 95.0, None, 0.05, 0.5, Some(0.2), Margining::FuturesStyle, Exercise::American,
+    /// );
+    /// ```
+    /// # Returns
+    /// An instance of the `Black76Inputs` struct.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        option_type: OptionType,
+        f: f64,
+        k: f64,
+        p: Option<f64>,
+        r: f64,
+        t: f64,
+        sigma: Option<f64>,
+        margining: Margining,
+        exercise: Exercise,
+    ) -> Self {
+        Self {
+            option_type,
+            f,
+            k,
+            p,
+            r,
+            t,
+            sigma,
+            margining,
+            exercise,
+        }
+    }
+
+    /// The rate prices are discounted at: `r` with premium margining and zero under futures-style margining.
+    /// American exercise is only supported under futures-style margining, where early exercise is never optimal
+    /// because the option's value always exceeds its intrinsic value, so the American price is the European one.
+    fn discount_rate(&self) -> Result<f64, String> {
+        match (self.margining, self.exercise) {
+            (Margining::FuturesStyle, _) => Ok(0.0),
+            (Margining::Premium, Exercise::European) => Ok(self.r),
+            (Margining::Premium, Exercise::American) => Err(
+                "American options with premium margining require an early exercise model"
+                    .to_string(),
+            ),
+        }
+    }
+
+    fn sigma(&self) -> Result<f64, String> {
+        let sigma = self
+            .sigma
+            .ok_or("Expected Some(f64) for self.sigma, received None")?;
+        if self.f <= 0.0 || self.k <= 0.0 || self.t <= 0.0 || sigma <= 0.0 {
+            return Err("Futures price, strike, time and volatility must be positive".to_string());
+        }
+        Ok(sigma)
+    }
+
+    /// Returns the discount factor and d1 of the option.
+    fn discount_and_d1(&self) -> Result<(f64, f64), String> {
+        let sigma = self.sigma()?;
+        let discount = (-self.discount_rate()? * self.t).exp();
+        let d1 = (self.f / self.k).ln() / (sigma * self.t.sqrt()) + 0.5 * sigma * self.t.sqrt();
+        Ok((discount, d1))
+    }
+
+    /// Calculates the price of the option, using `black` for the undiscounted value.
+    /// # Requires
+    /// f, k, r, t, sigma, margining, exercise.
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::OptionType;
+    /// use blackscholes::lets_be_rational::{Black76Inputs, Exercise, Margining};
+    /// let inputs = Black76Inputs::new(
+    ///     OptionType::Put, 100.0, 95.0, None, 0.05, 0.5, Some(0.2), Margining::Premium, Exercise::European,
+    /// );
+    /// let price = inputs.calc_price().unwrap();
+    /// ```
+    pub fn calc_price(&self) -> Result<f64, String> {
+        let sigma = self.sigma()?;
+        let discount = (-self.discount_rate()? * self.t).exp();
+        Ok(discount * black(self.f, self.k, sigma, self.t, self.option_type))
+    }
+
+    /// Calculates the delta of the option with respect to the futures price.
+    /// # Requires
+    /// f, k, r, t, sigma, margining, exercise.
+    /// # Returns
+    /// f64 of the delta of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::OptionType;
+    /// use blackscholes::lets_be_rational::{Black76Inputs, Exercise, Margining};
+    /// let inputs = Black76Inputs::new(
+    ///     OptionType::Call, 100.0, 95.0, None, 0.05, 0.5, Some(0.2), Margining::Premium, Exercise::European,
+    /// );
+    /// let delta = inputs.calc_delta().unwrap();
+    /// assert!(delta > 0.0 && delta < 1.0);
+    /// ```
+    pub fn calc_delta(&self) -> Result<f64, String> {
+        let (discount, d1) = self.discount_and_d1()?;
+        Ok(self.option_type * discount * standard_normal_cdf(self.option_type * d1))
+    }
+
+    /// Calculates the gamma of the option with respect to the futures price.
+    /// # Requires
+    /// f, k, r, t, sigma, margining, exercise.
+    /// # Returns
+    /// f64 of the gamma of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::OptionType;
+    /// use blackscholes::lets_be_rational::{Black76Inputs, Exercise, Margining};
+    /// let inputs = Black76Inputs::new(
+    ///     OptionType::Put, 100.0, 95.0, None, 0.05, 0.5, Some(0.2), Margining::Premium, Exercise::European,
+    /// );
+    /// let gamma = inputs.calc_gamma().unwrap();
+    /// ```
+    pub fn calc_gamma(&self) -> Result<f64, String> {
+        let (discount, d1) = self.discount_and_d1()?;
+        let sigma = self.sigma()?;
+        Ok(discount * standard_normal_pdf(d1) / (self.f * sigma * self.t.sqrt()))
+    }
+
+    /// Calculates the vega of the option per 1% change in volatility.
+    /// # Requires
+    /// f, k, r, t, sigma, margining, exercise.
+    /// # Returns
+    /// f64 of the vega of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::OptionType;
+    /// use blackscholes::lets_be_rational::{Black76Inputs, Exercise, Margining};
+    /// let inputs = Black76Inputs::new(
+    ///     OptionType::Call, 100.0, 95.0, None, 0.05, 0.5, Some(0.2), Margining::FuturesStyle, Exercise::American,
+    /// );
+    /// let vega = inputs.calc_vega().unwrap();
+    /// ```
+    pub fn calc_vega(&self) -> Result<f64, String> {
+        let (discount, d1) = self.discount_and_d1()?;
+        Ok(0.01 * discount * self.f * standard_normal_pdf(d1) * self.t.sqrt())
+    }
+
+    /// Calculates the theta of the option per day.
+    /// # Requires
+    /// f, k, r, t, sigma, margining, exercise.
+    /// # Returns
+    /// f64 of the theta of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::OptionType;
+    /// use blackscholes::lets_be_rational::{Black76Inputs, Exercise, Margining};
+    /// let inputs = Black76Inputs::new(
+    ///     OptionType::Put, 100.0, 95.0, None, 0.05, 0.5, Some(0.2), Margining::Premium, Exercise::European,
+    /// );
+    /// let theta = inputs.calc_theta().unwrap();
+    /// ```
+    pub fn calc_theta(&self) -> Result<f64, String> {
+        let (discount, d1) = self.discount_and_d1()?;
+        let sigma = self.sigma()?;
+        let decay = discount * self.f * standard_normal_pdf(d1) * sigma / (2.0 * self.t.sqrt());
+        let carry = self.discount_rate()? * self.calc_price()?;
+        Ok((carry - decay) / DAYS_PER_YEAR)
+    }
+
+    /// Calculates the rho of the option per 1% change in the risk-free rate;
+    /// zero under futures-style margining.
+    /// # Requires
+    /// f, k, r, t, sigma, margining, exercise.
+    /// # Returns
+    /// f64 of the rho of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::OptionType;
+    /// use blackscholes::lets_be_rational::{Black76Inputs, Exercise, Margining};
+    /// let inputs = Black76Inputs::new(
+    ///     OptionType::Call, 100.0, 95.0, None, 0.05, 0.5, Some(0.2), Margining::FuturesStyle, Exercise::American,
+    /// );
+    /// let rho = inputs.calc_rho().unwrap();
+    /// assert_eq!(rho, 0.0);
+    /// ```
+    pub fn calc_rho(&self) -> Result<f64, String> {
+        let price = self.calc_price()?;
+        match self.margining {
+            Margining::Premium => Ok(-self.t * price / 100.0),
+            Margining::FuturesStyle => Ok(0.0),
+        }
+    }
+
+    /// Calculates the price and greeks of the option.
+    /// # Requires
+    /// f, k, r, t, sigma, margining, exercise.
+    /// # Returns
+    /// HashMap of type <String, f64> with the price, delta, gamma, vega, theta and rho.
+    /// # Example
+    /// ```
+    /// use blackscholes::OptionType;
+    /// use blackscholes::lets_be_rational::{Black76Inputs, Exercise, Margining};
+    /// let inputs = Black76Inputs::new(
+    ///     OptionType::Call, 100.0, 95.0, None, 0.05, 0.5, Some(0.2), Margining::Premium, Exercise::European,
+    /// );
+    /// let greeks = inputs.calc_all_greeks().unwrap();
+    /// ```
+    pub fn calc_all_greeks(&self) -> Result<HashMap<String, f64>, String> {
+        let mut greeks: HashMap<String, f64> = HashMap::with_capacity(6);
+        greeks.insert("price".into(), self.calc_price()?);
+        greeks.insert("delta".into(), self.calc_delta()?);
+        greeks.insert("gamma".into(), self.calc_gamma()?);
+        greeks.insert("vega".into(), self.calc_vega()?);
+        greeks.insert("theta".into(), self.calc_theta()?);
+        greeks.insert("rho".into(), self.calc_rho()?);
+        Ok(greeks)
+    }
+
+    /// Calculates the implied volatility of the option price `p` using the "Let's be rational" method.
+    /// # Requires
+    /// f, k, r, t, p, margining, exercise.
+    /// # Returns
+    /// f64 of the implied volatility of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::OptionType;
+    /// use blackscholes::lets_be_rational::{Black76Inputs, Exercise, Margining};
+    /// let inputs = Black76Inputs::new(
+    ///     OptionType::Call, 100.0, 95.0, Some(8.0), 0.05, 0.5, None, Margining::FuturesStyle, Exercise::American,
+    /// );
+    /// let iv = inputs.calc_iv().unwrap();
+    /// ```
+    pub fn calc_iv(&self) -> Result<f64, String> {
+        let p = self.p.ok_or("Option price is required".to_string())?;
+        if self.f <= 0.0 || self.k <= 0.0 || self.t <= 0.0 {
+            return Err("Futures price, strike and time must be positive".to_string());
+        }
+        let undiscounted = p * (self.discount_rate()? * self.t).exp();
+        forward_rational_iv(undiscounted, self.f, self.k, self.t, self.option_type)
+            .map_err(|kind| kind.to_string())
+    }
+}
@@ -111,6 +111,7 @@ pub fn erfc(x: f64) -> f64 {
     }
 }
 
+#[allow(clippy::neg_cmp_op_on_partial_ord)]
 fn erfcx_above_threshold(y: f64) -> f64 {
     debug_assert!(!(y <= THRESHOLD));
     if y <= 4.0 {
@@ -1,19 +1,31 @@
 use statrs::consts::SQRT_2PI;
 
-use crate::{lets_be_rational::black::normalised_black, OptionType};
+use crate::OptionType;
 
 // NOTE: if black is public then `calc_rational_iv` is decreased to 320
 // ns but when private everything twice lower - wtf
 // if someone know why I will be glad to know too...
 mod black;
+mod black76;
 
 mod cody;
 mod intrinsic;
-pub(crate) mod normal_distribution;
+pub mod normal_distribution;
 mod rational_cubic;
 mod so_rational;
 
-const IMPLIED_VOLATILITY_MAXIMUM_ITERATIONS: i32 = 2;
+pub use black::normalised_black;
+pub use black76::{Black76Inputs, Exercise, Margining};
+pub use so_rational::{
+    implied_volatility_from_a_transformed_rational_guess_with_limited_iterations,
+    normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations,
+    normalised_vega, normalised_volga, VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM,
+    VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC,
+};
+
+/// The number of refinements `implied_volatility_from_a_transformed_rational_guess` uses,
+/// enough for full double precision.
+pub const IMPLIED_VOLATILITY_MAXIMUM_ITERATIONS: i32 = 2;
 pub(crate) const DENORMALISATION_CUTOFF: f64 = 0.0;
 pub(crate) const ONE_OVER_SQRT_TWO_PI: f64 = 1.0 / SQRT_2PI;
 
@@ -49,6 +61,7 @@ pub(crate) const ONE_OVER_SQRT_TWO_PI: f64 = 1.0 / SQRT_2PI;
 /// The function uses the natural logarithm of the forward price over the strike price,
 /// multiplies it by the square root of time to maturity, and applies the option type
 /// to determine the final price. It's suitable for European options *only*.
+/// The price is undiscounted; see [`Black76Inputs`] for discounted and futures-style margined options on futures.
 pub fn black(
     forward_price: f64,
     strike_price: f64,
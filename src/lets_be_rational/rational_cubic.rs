@@ -3,6 +3,7 @@ use num_traits::Zero;
 const MINIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE: f64 = -(1.0 - 1.4901161193847656e-8); // -(1.0 - f64::EPSILON.sqrt());
 const MAXIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE: f64 = 2.0 / (f64::EPSILON * f64::EPSILON);
 
+#[allow(clippy::too_many_arguments)]
 pub fn rational_cubic_interpolation(
     x: f64,
     x_l: f64,
@@ -100,6 +101,7 @@ pub enum Side {
     Right,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn rational_cubic_control_parameter(
     x_l: f64,
     x_r: f64,
@@ -131,6 +133,7 @@ fn rational_cubic_control_parameter(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn convex_rational_cubic_control_parameter(
     x_l: f64,
     x_r: f64,
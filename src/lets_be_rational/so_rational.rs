@@ -13,9 +13,11 @@ use crate::{
     OptionType,
 };
 
-const VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM: f64 = f64::MAX;
+/// Returned by the implied volatility functions when the price is at or above the maximum value of the option.
+pub const VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM: f64 = f64::MAX;
 
-const VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC: f64 = -f64::MAX;
+/// Returned by the implied volatility functions when the price is below the intrinsic value of the option.
+pub const VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC: f64 = -f64::MAX;
 
 const SQRT_DBL_MIN: f64 = 1.4916681462400413e-154;
 const SQRT_DBL_MAX: f64 = 1.340_780_792_994_259_6e154;
@@ -34,7 +36,23 @@ fn is_below_horizon(x: f64) -> bool {
     x.abs() < DENORMALISATION_CUTOFF
 }
 
-pub(crate) fn normalised_vega(x: f64, s: f64) -> f64 {
+/// Calculates the normalised vega, the derivative of the normalised Black price with respect to the total volatility.
+///
+/// # Arguments
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `s` - The total volatility `sigma * sqrt(T)`.
+///
+/// # Returns
+/// `d b / d s`, the same for calls and puts; the undiscounted vega is `sqrt(F K) sqrt(T)` times this.
+///
+/// # Examples
+/// ```
+/// use blackscholes::lets_be_rational::normalised_vega;
+///
+/// let vega = normalised_vega(0.0, 0.2);
+/// assert!((vega - (-0.005_f64).exp() / (2.0 * std::f64::consts::PI).sqrt()).abs() < 1e-15);
+/// ```
+pub fn normalised_vega(x: f64, s: f64) -> f64 {
     let ax = x.abs();
     if ax <= 0.0 {
         ONE_OVER_SQRT_TWO_PI * (-0.125 * s * s).exp()
@@ -45,6 +63,31 @@ pub(crate) fn normalised_vega(x: f64, s: f64) -> f64 {
     }
 }
 
+/// Calculates the normalised volga, the second derivative of the normalised Black price with respect to the
+/// total volatility.
+///
+/// # Arguments
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `s` - The total volatility `sigma * sqrt(T)`.
+///
+/// # Returns
+/// `d^2 b / d s^2 = normalised_vega(x, s) (x^2 / s^3 - s / 4)`, the same for calls and puts.
+///
+/// # Examples
+/// ```
+/// use blackscholes::lets_be_rational::{normalised_vega, normalised_volga};
+///
+/// let (x, s, h) = (-0.3, 0.4, 1e-5);
+/// let difference = (normalised_vega(x, s + h) - normalised_vega(x, s - h)) / (2.0 * h);
+/// assert!((normalised_volga(x, s) - difference).abs() < 1e-8);
+/// ```
+pub fn normalised_volga(x: f64, s: f64) -> f64 {
+    if s <= 0.0 {
+        return 0.0;
+    }
+    normalised_vega(x, s) * (x * x / (s * s * s) - 0.25 * s)
+}
+
 fn householder_factor(newton: f64, halley: f64, hh3: f64) -> f64 {
     (1.0 + 0.5 * halley * newton) / (1.0 + newton * (halley + hh3 * newton / 6.0))
 }
@@ -105,7 +148,32 @@ fn compute_f_lower_map_and_first_two_derivatives(x: f64, s: f64) -> (f64, f64, f
     (f, fp, fpp)
 }
 
-pub(crate) fn implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
+/// Calculates the implied volatility of an undiscounted Black price, as
+/// [`implied_volatility_from_a_transformed_rational_guess`](super::implied_volatility_from_a_transformed_rational_guess),
+/// with at most `max_iteration` Householder refinements of the initial guess.
+///
+/// # Arguments
+/// * `market_price` - The undiscounted price of the option.
+/// * `forward_price` - The forward price of the underlying asset.
+/// * `strike_price` - The strike price of the option.
+/// * `time_to_maturity` - The time to maturity of the option, in years.
+/// * `option_type` - The type of the option (call or put), represented by `OptionType`.
+/// * `max_iteration` - The maximum number of refinements; two reach full precision.
+///
+/// # Returns
+/// The implied volatility, [`VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC`] for a price below intrinsic value
+/// or [`VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM`] for a price at or above the forward (calls) or strike (puts).
+///
+/// # Examples
+/// ```
+/// use blackscholes::{OptionType, lets_be_rational::{black, implied_volatility_from_a_transformed_rational_guess_with_limited_iterations}};
+///
+/// let price = black(100.0, 95.0, 0.25, 1.0, OptionType::Call);
+/// let guess = implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, 100.0, 95.0, 1.0, OptionType::Call, 0);
+/// let refined = implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, 100.0, 95.0, 1.0, OptionType::Call, 2);
+/// assert!((refined - 0.25).abs() <= (guess - 0.25).abs());
+/// ```
+pub fn implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
     market_price: f64,
     forward_price: f64,
     strike_price: f64,
@@ -119,8 +187,8 @@ pub(crate) fn implied_volatility_from_a_transformed_rational_guess_with_limited_
         return VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC;
     }
     let max_price = match option_type {
-        OptionType::Call => strike_price,
-        OptionType::Put => forward_price,
+        OptionType::Call => forward_price,
+        OptionType::Put => strike_price,
     };
     if price >= max_price {
         return VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM;
@@ -137,8 +205,28 @@ pub(crate) fn implied_volatility_from_a_transformed_rational_guess_with_limited_
     ) / time_to_maturity.sqrt()
 }
 
-#[allow(dead_code)]
-fn normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
+/// Calculates the total implied volatility `sigma * sqrt(T)` of a normalised Black price
+/// `beta = B / sqrt(F K)`, with at most `max_iteration` Householder refinements of the initial guess.
+///
+/// # Arguments
+/// * `beta` - The normalised price of the option.
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `option_type` - The type of the option (call or put), represented by `OptionType`.
+/// * `max_iteration` - The maximum number of refinements; two reach full precision.
+///
+/// # Returns
+/// The total implied volatility, [`VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC`] for a price below intrinsic
+/// value or [`VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM`] for a price at or above the maximum.
+///
+/// # Examples
+/// ```
+/// use blackscholes::{OptionType, lets_be_rational::{normalised_black, normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations}};
+///
+/// let beta = normalised_black(0.1, 0.3, OptionType::Put);
+/// let s = normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(beta, 0.1, OptionType::Put, 2);
+/// assert!((s - 0.3).abs() < 1e-14);
+/// ```
+pub fn normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
     beta: f64,
     x: f64,
     option_type: OptionType,
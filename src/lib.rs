@@ -16,27 +16,74 @@
 //!
 //! See the [Github Repo](https://github.com/hayden4r4/blackscholes-rust/tree/master) for full source code.  Other implementations such as a [npm WASM package](https://www.npmjs.com/package/@haydenr4/blackscholes_wasm) and a [python module](https://pypi.org/project/blackscholes/) are also available.
 
+pub use american::{AmericanInputs, AmericanIv, DEFAULT_TREE_STEPS};
+pub use bates::{Bates, BatesCalibration, Heston};
+pub use cev::CevInputs;
+pub use characteristic_function::{
+    BsmModel, Cgmy, CharacteristicFunction, NormalInverseGaussian, VarianceGamma,
+};
+pub use compound::{ChooserInputs, CompoundInputs};
+pub use forward_start::{CliquetInputs, ForwardStartInputs};
+pub use fourier::{CarrMadan, CosMethod};
 pub use greeks::Greeks;
-pub use implied_volatility::ImpliedVolatility;
+pub use implied_correlation::{CorrelationLevel, DispersionInputs, ImpliedCorrelation};
+pub use implied_parameter::{ImpliedParameter, ImpliedParameterResult, ImpliedParameters};
+pub use implied_volatility::{ImpliedVolatility, IvErrorKind};
 pub use inputs::{Inputs, OptionType};
+pub use iv_batch::{calc_rational_iv_batch, OptionChain};
+pub use iv_estimators::{IvEstimate, IvEstimator, IvEstimatorDomain};
+pub use iv_solver::{IvMethod, IvOptions, IvResult, IvStatus, IvTolerance};
 use lets_be_rational::normal_distribution::{standard_normal_cdf, standard_normal_pdf};
+pub use merton::{MertonCalibration, MertonInputs, MertonPricing};
+pub use num_complex::Complex64;
+pub use numerical_greeks::{Bump, NumericalGreeks};
+pub use parity::{ParityFit, ParityInputs};
+pub use perpetual::PerpetualInputs;
+pub use power::{PowerInputs, PowerPayoff, PowerPricing};
 pub use pricing::Pricing;
-
+pub use quanto::{QuantoInputs, QuantoPricing, QuantoStyle};
+pub use quote_iv::{QuoteInputs, QuoteIv};
+pub use rainbow::{RainbowPayoff, RainbowPricing};
+pub use touch::{PayoutCurrency, Settlement, Touch, TouchInputs};
+pub use two_asset::{SpreadModel, SpreadPricing, TwoAssetInputs};
+pub use vanna_volga::VannaVolgaSmile;
+pub use variance_swap::{VarianceReplication, VarianceSwapInputs};
+pub use warrant::{WarrantInputs, WarrantPricing};
+
+mod american;
+mod bates;
+mod cev;
+mod characteristic_function;
+mod compound;
+mod forward_start;
+mod fourier;
 mod greeks;
+mod implied_correlation;
+mod implied_parameter;
 mod implied_volatility;
 mod inputs;
+mod iv_batch;
+mod iv_estimators;
+mod iv_solver;
 pub mod lets_be_rational;
+mod merton;
+mod numerical_greeks;
+mod numerics;
+mod parity;
+mod perpetual;
+mod power;
 mod pricing;
+mod quanto;
+mod quote_iv;
+mod rainbow;
+mod touch;
+mod two_asset;
+mod vanna_volga;
+mod variance_swap;
+mod warrant;
 
 pub(crate) const DAYS_PER_YEAR: f64 = 365.25;
 
-pub(crate) const A: f64 = 4.626_275_3e-1;
-pub(crate) const B: f64 = -1.168_519_2e-2;
-pub(crate) const C: f64 = 9.635_418_5e-4;
-pub(crate) const D: f64 = 7.535_022_5e-5;
-pub(crate) const _E: f64 = 1.424_516_45e-5;
-pub(crate) const F: f64 = -2.102_376_9e-5;
-
 /// Calculates the d1 and d2 values for the option.
 /// # Requires
 /// s, k, r, q, t, sigma.
@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+
+use crate::{
+    numerics::nelder_mead, Greeks, ImpliedVolatility, Inputs, OptionType, Pricing, DAYS_PER_YEAR,
+};
+
+/// Stop summing once the Poisson weights not yet included fall below this.
+const SERIES_TOLERANCE: f64 = 1e-13;
+/// Upper bound on the number of jump terms in the series.
+const MAX_JUMP_TERMS: usize = 1_000;
+/// Convergence tolerance on the mean squared implied volatility error during calibration.
+const CALIBRATION_TOLERANCE: f64 = 1e-14;
+const CALIBRATION_ITERATIONS: usize = 2_000;
+
+/// An option under the Merton (1976) jump-diffusion model, where the log price jumps at Poisson times
+/// by normally distributed amounts on top of the Black-Scholes-Merton diffusion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MertonInputs {
+    /// The option type, strike and market parameters; `sigma` is the diffusion volatility
+    pub underlying: Inputs,
+    /// Jump intensity, the expected number of jumps per year
+    pub lambda: f64,
+    /// Mean of the log jump size
+    pub jump_mean: f64,
+    /// Standard deviation of the log jump size
+    pub jump_vol: f64,
+}
+
+/// The result of fitting the diffusion and jump parameters of a [`MertonInputs`] to a volatility smile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MertonCalibration {
+    /// The fitted model
+    pub inputs: MertonInputs,
+    /// Root mean squared difference between model and market implied volatilities
+    pub rmse: f64,
+    /// Number of optimiser iterations used
+    pub iterations: usize,
+    /// Whether the optimiser met its tolerance within the iteration limit; if not, the best fit found is returned
+    pub converged: bool,
+}
+
+/// One term of the Poisson series: the Black-Scholes-Merton option conditional on `n` jumps.
+struct JumpTerm {
+    n: f64,
+    weight: f64,
+    inputs: Inputs,
+}
+
+impl MertonInputs {
+    /// Creates instance of the `MertonInputs` struct.
+    /// # Arguments
+    /// * `underlying` - The option type, strike and market parameters, with `sigma` the diffusion volatility.
+    /// * `lambda` - The jump intensity per year.
+    /// * `jump_mean` - The mean of the log jump size.
+    /// * `jump_vol` - The standard deviation of the log jump size.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, MertonInputs, OptionType};
+    /// let underlying = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 0.25, Some(0.2));
+    /// let inputs = MertonInputs::new(underlying, 1.0, -0.1, 0.15);
+    /// ```
+    /// # Returns
+    /// An instance of the `MertonInputs` struct.
+    pub fn new(underlying: Inputs, lambda: f64, jump_mean: f64, jump_vol: f64) -> Self {
+        Self {
+            underlying,
+            lambda,
+            jump_mean,
+            jump_vol,
+        }
+    }
+
+    /// Expected relative jump size `E[e^J] - 1`.
+    fn kappa(&self) -> f64 {
+        (self.jump_mean + 0.5 * self.jump_vol * self.jump_vol).exp() - 1.0
+    }
+
+    /// The Poisson-weighted Black-Scholes-Merton terms, truncated once the remaining weight is negligible.
+    /// Conditional on `n` jumps the price is lognormal with variance `sigma^2 T + n jump_vol^2`,
+    /// which is a Black-Scholes-Merton option on a shifted spot with volatility `sigma_n`.
+    fn terms(&self) -> Result<Vec<JumpTerm>, String> {
+        let sigma = self
+            .underlying
+            .sigma
+            .ok_or("Expected Some(f64) for self.sigma, received None")?;
+        if self.lambda < 0.0 || self.jump_vol < 0.0 {
+            return Err("Jump intensity and jump volatility must not be negative".to_string());
+        }
+        let t = self.underlying.t;
+        if t <= 0.0 {
+            return Err("Time to maturity must be positive".to_string());
+        }
+        let mean_jumps = self.lambda * t;
+        let mut weight = (-mean_jumps).exp();
+        if weight == 0.0 {
+            return Err("Expected number of jumps is too large".to_string());
+        }
+        let compensator = self.lambda * self.kappa() * t;
+        let mut terms = Vec::new();
+        let mut included = 0.0;
+        for n in 0..MAX_JUMP_TERMS {
+            let n = n as f64;
+            terms.push(JumpTerm {
+                n,
+                weight,
+                inputs: Inputs {
+                    s: self.underlying.s
+                        * (n * (self.jump_mean + 0.5 * self.jump_vol * self.jump_vol)
+                            - compensator)
+                            .exp(),
+                    sigma: Some((sigma * sigma + n * self.jump_vol * self.jump_vol / t).sqrt()),
+                    ..self.underlying.clone()
+                },
+            });
+            included += weight;
+            if n >= mean_jumps && 1.0 - included < SERIES_TOLERANCE {
+                return Ok(terms);
+            }
+            weight *= mean_jumps / (n + 1.0);
+        }
+        Err("Jump series failed to converge".to_string())
+    }
+
+    /// Fits the diffusion volatility, jump intensity, jump mean and jump volatility to a volatility smile.
+    /// Minimises the squared differences between model and market implied volatilities,
+    /// with model volatilities backed out of out-of-the-money prices using `calc_rational_iv`.
+    /// # Arguments
+    /// * `underlying` - The market parameters s, r, q and t; the option type, strike and volatility are ignored.
+    /// * `strikes` - The strikes of the smile.
+    /// * `vols` - The market implied volatility at each strike.
+    /// # Returns
+    /// MertonCalibration with the fitted model, the implied volatility fit error, the iterations used and whether the fit converged.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, MertonInputs, OptionType};
+    /// let market = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.02, 0.0, 0.1, None);
+    /// let strikes = [90.0, 95.0, 100.0, 105.0, 110.0];
+    /// let vols = [0.32, 0.24, 0.19, 0.17, 0.17];
+    /// let calibration = MertonInputs::calibrate(&market, &strikes, &vols).unwrap();
+    /// assert!(calibration.inputs.jump_mean < 0.0);
+    /// ```
+    pub fn calibrate(
+        underlying: &Inputs,
+        strikes: &[f64],
+        vols: &[f64],
+    ) -> Result<MertonCalibration, String> {
+        if strikes.is_empty() || strikes.len() != vols.len() {
+            return Err("Each strike requires exactly one volatility".to_string());
+        }
+        if vols.iter().any(|&vol| vol <= 0.0) {
+            return Err("Market volatilities must be positive".to_string());
+        }
+        let forward = underlying.s * ((underlying.r - underlying.q) * underlying.t).exp();
+        let option_type = |k: f64| {
+            if k < forward {
+                OptionType::Put
+            } else {
+                OptionType::Call
+            }
+        };
+        // Positive parameters are optimised on a log scale.
+        let model = |x: &[f64]| {
+            MertonInputs::new(
+                Inputs {
+                    sigma: Some(x[0].exp()),
+                    ..underlying.clone()
+                },
+                x[1].exp(),
+                x[2],
+                x[3].exp(),
+            )
+        };
+        let objective = |x: &[f64]| -> Result<f64, String> {
+            let merton = model(x);
+            let mut sum = 0.0;
+            for (&k, &vol) in strikes.iter().zip(vols) {
+                let mut priced = MertonInputs {
+                    underlying: Inputs {
+                        option_type: option_type(k),
+                        k,
+                        ..merton.underlying.clone()
+                    },
+                    ..merton.clone()
+                };
+                // Parameters the series cannot price, such as too many expected jumps, and prices too far out of
+                // the money to invert are penalised rather than aborting the fit.
+                let error = match priced.calc_merton_price() {
+                    Ok(price) => {
+                        priced.underlying.p = Some(price);
+                        priced.underlying.sigma = None;
+                        match priced.underlying.calc_rational_iv() {
+                            Ok(model_vol) if model_vol.is_finite() => model_vol - vol,
+                            _ => 1.0,
+                        }
+                    }
+                    Err(_) => 1.0,
+                };
+                sum += error * error;
+            }
+            Ok(sum / strikes.len() as f64)
+        };
+        // Jumps only raise the smile above the diffusion volatility, so start the diffusion at its lowest point.
+        let lowest_vol = vols.iter().copied().fold(f64::INFINITY, f64::min);
+        let start = [lowest_vol.ln(), 0.0, -0.1, 0.1_f64.ln()];
+        let minimum = nelder_mead(
+            objective,
+            &start,
+            &[0.1, 0.5, 0.1, 0.5],
+            CALIBRATION_TOLERANCE,
+            CALIBRATION_ITERATIONS,
+        )?;
+        Ok(MertonCalibration {
+            inputs: model(&minimum.point),
+            rmse: minimum.value.sqrt(),
+            iterations: minimum.iterations,
+            converged: minimum.converged,
+        })
+    }
+}
+
+pub trait MertonPricing {
+    fn calc_merton_price(&self) -> Result<f64, String>;
+    fn calc_merton_delta(&self) -> Result<f64, String>;
+    fn calc_merton_gamma(&self) -> Result<f64, String>;
+    fn calc_merton_vega(&self) -> Result<f64, String>;
+    fn calc_merton_theta(&self) -> Result<f64, String>;
+    fn calc_merton_rho(&self) -> Result<f64, String>;
+    fn calc_merton_epsilon(&self) -> Result<f64, String>;
+    fn calc_all_merton_greeks(&self) -> Result<HashMap<String, f64>, String>;
+}
+
+impl MertonPricing for MertonInputs {
+    /// Calculates the price of the option as a Poisson-weighted sum of Black-Scholes-Merton prices.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, lambda, jump_mean, jump_vol.
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, MertonInputs, MertonPricing, OptionType};
+    /// let underlying = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 0.25, Some(0.2));
+    /// let inputs = MertonInputs::new(underlying, 1.0, -0.1, 0.15);
+    /// let price = inputs.calc_merton_price().unwrap();
+    /// ```
+    fn calc_merton_price(&self) -> Result<f64, String> {
+        self.terms()?
+            .iter()
+            .map(|term| Ok(term.weight * term.inputs.calc_price()?))
+            .sum()
+    }
+
+    /// Calculates the delta of the option as a Poisson-weighted sum of Black-Scholes-Merton deltas.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, lambda, jump_mean, jump_vol.
+    /// # Returns
+    /// f64 of the delta of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, MertonInputs, MertonPricing, OptionType};
+    /// let underlying = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 0.25, Some(0.2));
+    /// let inputs = MertonInputs::new(underlying, 1.0, -0.1, 0.15);
+    /// let delta = inputs.calc_merton_delta().unwrap();
+    /// ```
+    fn calc_merton_delta(&self) -> Result<f64, String> {
+        let s = self.underlying.s;
+        self.terms()?
+            .iter()
+            .map(|term| Ok(term.weight * term.inputs.calc_delta()? * term.inputs.s / s))
+            .sum()
+    }
+
+    /// Calculates the gamma of the option as a Poisson-weighted sum of Black-Scholes-Merton gammas.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, lambda, jump_mean, jump_vol.
+    /// # Returns
+    /// f64 of the gamma of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, MertonInputs, MertonPricing, OptionType};
+    /// let underlying = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 0.25, Some(0.2));
+    /// let inputs = MertonInputs::new(underlying, 1.0, -0.1, 0.15);
+    /// let gamma = inputs.calc_merton_gamma().unwrap();
+    /// ```
+    fn calc_merton_gamma(&self) -> Result<f64, String> {
+        let s = self.underlying.s;
+        self.terms()?
+            .iter()
+            .map(|term| Ok(term.weight * term.inputs.calc_gamma()? * (term.inputs.s / s).powi(2)))
+            .sum()
+    }
+
+    /// Calculates the vega of the option with respect to the diffusion volatility.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, lambda, jump_mean, jump_vol.
+    /// # Returns
+    /// f64 of the change in price for a 1% change in the diffusion volatility.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, MertonInputs, MertonPricing, OptionType};
+    /// let underlying = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 0.25, Some(0.2));
+    /// let inputs = MertonInputs::new(underlying, 1.0, -0.1, 0.15);
+    /// let vega = inputs.calc_merton_vega().unwrap();
+    /// ```
+    fn calc_merton_vega(&self) -> Result<f64, String> {
+        let sigma = self
+            .underlying
+            .sigma
+            .ok_or("Expected Some(f64) for self.sigma, received None")?;
+        self.terms()?
+            .iter()
+            .map(|term| {
+                let sigma_n = term.inputs.sigma.unwrap_or(sigma);
+                Ok(term.weight * term.inputs.calc_vega()? * sigma / sigma_n)
+            })
+            .sum()
+    }
+
+    /// Calculates the theta of the option.
+    /// Time enters through the Poisson weights, the jump compensator and the jump variance per year,
+    /// as well as through each Black-Scholes-Merton term.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, lambda, jump_mean, jump_vol.
+    /// # Returns
+    /// f64 of theta per day (not per year).
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, MertonInputs, MertonPricing, OptionType};
+    /// let underlying = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 0.25, Some(0.2));
+    /// let inputs = MertonInputs::new(underlying, 1.0, -0.1, 0.15);
+    /// let theta = inputs.calc_merton_theta().unwrap();
+    /// ```
+    fn calc_merton_theta(&self) -> Result<f64, String> {
+        let t = self.underlying.t;
+        let compensator_rate = self.lambda * self.kappa();
+        let jump_variance = self.jump_vol * self.jump_vol;
+        self.terms()?
+            .iter()
+            .map(|term| {
+                let inputs = &term.inputs;
+                let sigma_n = inputs.sigma.unwrap_or_default();
+                // d(price)/dT from everything other than the Black-Scholes-Merton maturity itself
+                let d_weight = (term.n / t - self.lambda) * inputs.calc_price()?;
+                let d_spot = -compensator_rate * inputs.s * inputs.calc_delta()?;
+                let d_sigma =
+                    -100.0 * inputs.calc_vega()? * term.n * jump_variance / (2.0 * t * t * sigma_n);
+                Ok(term.weight
+                    * (inputs.calc_theta()? - (d_weight + d_spot + d_sigma) / DAYS_PER_YEAR))
+            })
+            .sum()
+    }
+
+    /// Calculates the rho of the option as a Poisson-weighted sum of Black-Scholes-Merton rhos.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, lambda, jump_mean, jump_vol.
+    /// # Returns
+    /// f64 of the change in price for a 1% change in the risk-free rate.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, MertonInputs, MertonPricing, OptionType};
+    /// let underlying = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 0.25, Some(0.2));
+    /// let inputs = MertonInputs::new(underlying, 1.0, -0.1, 0.15);
+    /// let rho = inputs.calc_merton_rho().unwrap();
+    /// ```
+    fn calc_merton_rho(&self) -> Result<f64, String> {
+        self.terms()?
+            .iter()
+            .map(|term| Ok(term.weight * term.inputs.calc_rho()?))
+            .sum()
+    }
+
+    /// Calculates the epsilon of the option as a Poisson-weighted sum of Black-Scholes-Merton epsilons.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, lambda, jump_mean, jump_vol.
+    /// # Returns
+    /// f64 of the change in price for a unit change in the dividend yield.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, MertonInputs, MertonPricing, OptionType};
+    /// let underlying = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 0.25, Some(0.2));
+    /// let inputs = MertonInputs::new(underlying, 1.0, -0.1, 0.15);
+    /// let epsilon = inputs.calc_merton_epsilon().unwrap();
+    /// ```
+    fn calc_merton_epsilon(&self) -> Result<f64, String> {
+        self.terms()?
+            .iter()
+            .map(|term| Ok(term.weight * term.inputs.calc_epsilon()?))
+            .sum()
+    }
+
+    /// Calculates the price and all greeks of the option.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, lambda, jump_mean, jump_vol.
+    /// # Returns
+    /// HashMap of type <String, f64> with the price, delta, gamma, vega, theta, rho and epsilon.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, MertonInputs, MertonPricing, OptionType};
+    /// let underlying = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 0.25, Some(0.2));
+    /// let inputs = MertonInputs::new(underlying, 1.0, -0.1, 0.15);
+    /// let greeks = inputs.calc_all_merton_greeks().unwrap();
+    /// ```
+    fn calc_all_merton_greeks(&self) -> Result<HashMap<String, f64>, String> {
+        let mut greeks: HashMap<String, f64> = HashMap::with_capacity(7);
+        greeks.insert("price".into(), self.calc_merton_price()?);
+        greeks.insert("delta".into(), self.calc_merton_delta()?);
+        greeks.insert("gamma".into(), self.calc_merton_gamma()?);
+        greeks.insert("vega".into(), self.calc_merton_vega()?);
+        greeks.insert("theta".into(), self.calc_merton_theta()?);
+        greeks.insert("rho".into(), self.calc_merton_rho()?);
+        greeks.insert("epsilon".into(), self.calc_merton_epsilon()?);
+        Ok(greeks)
+    }
+}
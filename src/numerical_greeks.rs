@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use crate::{
+    numerics::{central_difference, second_difference, ABSOLUTE_BUMP, RELATIVE_BUMP},
+    Inputs, DAYS_PER_YEAR,
+};
+
+/// A market input that can be shifted when computing finite-difference greeks.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Bump {
+    /// Price of the underlying asset
+    Spot,
+    /// Volatility of the underlying asset
+    Volatility,
+    /// Risk-free rate
+    Rate,
+    /// Dividend yield
+    Dividend,
+    /// Passage of time; every time to maturity in the instrument shortens by the bump size
+    Time,
+}
+
+/// Greeks computed by bumping and repricing, for instruments without closed-form greeks.
+///
+/// Implementors provide the price and how to reprice under a bumped market;
+/// the greeks follow the units of [`Greeks`](crate::Greeks): vega and rho per 1% change,
+/// theta per day and epsilon per unit change in the dividend yield.
+pub trait NumericalGreeks {
+    /// Calculates the price of the instrument.
+    fn calc_price(&self) -> Result<f64, String>;
+
+    /// Calculates the price of the instrument with one market input shifted by `size`.
+    fn calc_bumped_price(&self, bump: Bump, size: f64) -> Result<f64, String>;
+
+    /// Current price of the underlying, used to scale spot bumps.
+    fn spot(&self) -> f64;
+
+    /// Calculates the delta of the instrument by central differences.
+    fn calc_delta(&self) -> Result<f64, String> {
+        central_difference(
+            |ds| self.calc_bumped_price(Bump::Spot, ds),
+            0.0,
+            self.spot() * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the gamma of the instrument by central differences.
+    fn calc_gamma(&self) -> Result<f64, String> {
+        second_difference(
+            |ds| self.calc_bumped_price(Bump::Spot, ds),
+            0.0,
+            self.spot() * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the vega of the instrument per 1% change in volatility.
+    fn calc_vega(&self) -> Result<f64, String> {
+        let vega = central_difference(
+            |dsigma| self.calc_bumped_price(Bump::Volatility, dsigma),
+            0.0,
+            ABSOLUTE_BUMP,
+        )?;
+        Ok(0.01 * vega)
+    }
+
+    /// Calculates the theta of the instrument per day.
+    fn calc_theta(&self) -> Result<f64, String> {
+        // Step forward in time only, so instruments close to expiry stay valid.
+        let dt = ABSOLUTE_BUMP;
+        let theta = (self.calc_bumped_price(Bump::Time, dt)? - self.calc_price()?) / dt;
+        Ok(theta / DAYS_PER_YEAR)
+    }
+
+    /// Calculates the rho of the instrument per 1% change in the risk-free rate.
+    fn calc_rho(&self) -> Result<f64, String> {
+        let rho = central_difference(
+            |dr| self.calc_bumped_price(Bump::Rate, dr),
+            0.0,
+            ABSOLUTE_BUMP,
+        )?;
+        Ok(rho / 100.0)
+    }
+
+    /// Calculates the epsilon (dividend sensitivity) of the instrument.
+    fn calc_epsilon(&self) -> Result<f64, String> {
+        central_difference(
+            |dq| self.calc_bumped_price(Bump::Dividend, dq),
+            0.0,
+            ABSOLUTE_BUMP,
+        )
+    }
+
+    /// Calculates the price and all numerical greeks of the instrument.
+    /// # Returns
+    /// HashMap of type <String, f64> with the price, delta, gamma, vega, theta, rho and epsilon.
+    fn calc_all_greeks(&self) -> Result<HashMap<String, f64>, String> {
+        let mut greeks: HashMap<String, f64> = HashMap::with_capacity(7);
+        greeks.insert("price".into(), self.calc_price()?);
+        greeks.insert("delta".into(), self.calc_delta()?);
+        greeks.insert("gamma".into(), self.calc_gamma()?);
+        greeks.insert("vega".into(), self.calc_vega()?);
+        greeks.insert("theta".into(), self.calc_theta()?);
+        greeks.insert("rho".into(), self.calc_rho()?);
+        greeks.insert("epsilon".into(), self.calc_epsilon()?);
+        Ok(greeks)
+    }
+}
+
+/// Returns a copy of `inputs` with one market input shifted by `size`.
+pub(crate) fn bump_inputs(inputs: &Inputs, bump: Bump, size: f64) -> Inputs {
+    let mut bumped = inputs.clone();
+    match bump {
+        Bump::Spot => bumped.s += size,
+        Bump::Volatility => bumped.sigma = inputs.sigma.map(|sigma| sigma + size),
+        Bump::Rate => bumped.r += size,
+        Bump::Dividend => bumped.q += size,
+        Bump::Time => bumped.t -= size,
+    }
+    bumped
+}
@@ -0,0 +1,445 @@
+//! Small numerical helpers shared by the pricing modules that have no closed-form greeks.
+
+use std::f64::consts::PI;
+
+use crate::lets_be_rational::normal_distribution::standard_normal_cdf;
+
+/// Relative bump used for finite-difference greeks on price-like inputs.
+pub(crate) const RELATIVE_BUMP: f64 = 1e-4;
+
+/// Absolute bump used for finite-difference greeks on rate-, vol- and correlation-like inputs.
+pub(crate) const ABSOLUTE_BUMP: f64 = 1e-5;
+
+/// Stop the double-barrier survival series once the decay of a term falls below this.
+const SERIES_TOLERANCE: f64 = 1e-16;
+const MAX_SERIES_TERMS: usize = 10_000;
+
+/// Central first derivative of `f` at `x` with step `h`.
+pub(crate) fn central_difference<F>(f: F, x: f64, h: f64) -> Result<f64, String>
+where
+    F: Fn(f64) -> Result<f64, String>,
+{
+    Ok((f(x + h)? - f(x - h)?) / (2.0 * h))
+}
+
+/// Central second derivative of `f` at `x` with step `h`.
+pub(crate) fn second_difference<F>(f: F, x: f64, h: f64) -> Result<f64, String>
+where
+    F: Fn(f64) -> Result<f64, String>,
+{
+    Ok((f(x + h)? - 2.0 * f(x)? + f(x - h)?) / (h * h))
+}
+
+/// Central mixed second derivative of `f` at `(x, y)` with steps `hx` and `hy`.
+pub(crate) fn mixed_difference<F>(f: F, x: f64, y: f64, hx: f64, hy: f64) -> Result<f64, String>
+where
+    F: Fn(f64, f64) -> Result<f64, String>,
+{
+    Ok(
+        (f(x + hx, y + hy)? - f(x + hx, y - hy)? - f(x - hx, y + hy)? + f(x - hx, y - hy)?)
+            / (4.0 * hx * hy),
+    )
+}
+
+/// First derivative of `f` at `x` that stays inside `[lower, upper]`,
+/// switching to a one-sided difference when a central bump would leave the interval.
+pub(crate) fn bounded_difference<F>(
+    f: F,
+    x: f64,
+    h: f64,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, String>
+where
+    F: Fn(f64) -> Result<f64, String>,
+{
+    if x - h < lower {
+        Ok((f(x + h)? - f(x)?) / h)
+    } else if x + h > upper {
+        Ok((f(x)? - f(x - h)?) / h)
+    } else {
+        central_difference(f, x, h)
+    }
+}
+
+/// Probability that the spot stays strictly between the barriers until `t` when its logarithm is a Brownian motion
+/// with drift `drift` and volatility `sigma`; zero if the spot is already outside them.
+pub(crate) fn survival_probability(
+    s: f64,
+    lower: Option<f64>,
+    upper: Option<f64>,
+    drift: f64,
+    sigma: f64,
+    t: f64,
+) -> f64 {
+    let width = sigma * t.sqrt();
+    // Probability that a Brownian motion with this drift stays below `h > 0`.
+    let below = |h: f64, drift: f64| {
+        standard_normal_cdf((h - drift * t) / width)
+            - (2.0 * drift * h / (sigma * sigma)).exp()
+                * standard_normal_cdf((-h - drift * t) / width)
+    };
+    match (lower, upper) {
+        (None, None) => 1.0,
+        (Some(lower), _) if lower >= s => 0.0,
+        (_, Some(upper)) if upper <= s => 0.0,
+        (None, Some(upper)) => below((upper / s).ln(), drift).clamp(0.0, 1.0),
+        (Some(lower), None) => below((s / lower).ln(), -drift).clamp(0.0, 1.0),
+        (Some(lower), Some(upper)) => {
+            // Eigenfunction expansion of the killed Brownian motion on [0, L] started at x0,
+            // with the drift removed by a change of measure.
+            let length = (upper / lower).ln();
+            let x0 = (s / lower).ln();
+            let c = drift / (sigma * sigma);
+            let tilt = (-c * x0 - 0.5 * drift * drift / (sigma * sigma) * t).exp();
+            let mut sum = 0.0;
+            for n in 1..=MAX_SERIES_TERMS {
+                let m = n as f64 * PI / length;
+                let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+                let integral = m * (1.0 - sign * (c * length).exp()) / (c * c + m * m);
+                let decay = (-0.5 * m * m * sigma * sigma * t).exp();
+                sum += (m * x0).sin() * integral * decay;
+                if decay < SERIES_TOLERANCE {
+                    break;
+                }
+            }
+            (2.0 / length * tilt * sum).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// Finds a root of `f` in `[lower, upper]` with Brent's method.
+/// `f(lower)` and `f(upper)` must have opposite signs.
+pub(crate) fn brent<F>(
+    f: F,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<f64, String>
+where
+    F: Fn(f64) -> Result<f64, String>,
+{
+    brent_with(f, lower, upper, tolerance, 0.0, max_iterations).map(|(root, _)| root)
+}
+
+/// Brent's method stopping once the bracket is narrower than `tolerance` or `|f|` is at most `f_tolerance`.
+/// # Returns
+/// The root and the number of iterations used.
+pub(crate) fn brent_with<F>(
+    f: F,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    f_tolerance: f64,
+    max_iterations: usize,
+) -> Result<(f64, usize), String>
+where
+    F: Fn(f64) -> Result<f64, String>,
+{
+    let (mut a, mut b) = (lower, upper);
+    let (mut fa, mut fb) = (f(a)?, f(b)?);
+    if fa.abs() <= f_tolerance {
+        return Ok((a, 0));
+    }
+    if fb.abs() <= f_tolerance {
+        return Ok((b, 0));
+    }
+    if fa * fb > 0.0 {
+        return Err("Root is not bracketed".to_string());
+    }
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+
+    for iteration in 0..max_iterations {
+        if fb * fc > 0.0 {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tol = 2.0 * f64::EPSILON * b.abs() + 0.5 * tolerance;
+        let m = 0.5 * (c - b);
+        if m.abs() <= tol || fb.abs() <= f_tolerance {
+            return Ok((b, iteration));
+        }
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+            // Attempt inverse quadratic interpolation, or the secant method when only two points differ.
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * m * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * m * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            } else {
+                p = -p;
+            }
+            if 2.0 * p < (3.0 * m * q - (tol * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = m;
+                e = m;
+            }
+        } else {
+            d = m;
+            e = m;
+        }
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol { d } else { tol.copysign(m) };
+        fb = f(b)?;
+    }
+    Err("Root finding failed to converge".to_string())
+}
+
+/// Newton's method safeguarded by the bracket `[lower, upper]`, which must contain a root of `f`.
+/// `step(x, f(x))` is the Newton step, or a higher-order one, to subtract from `x`; `increasing` is whether
+/// `f` rises across the bracket. Each evaluation narrows the bracket, and once a step leaves it or fails to
+/// halve the previous step the remaining iterations are spent on Brent's method.
+/// Stops when a step is at most `tolerance` or `|f|` is at most `f_tolerance`.
+/// # Returns
+/// The root and the number of iterations used.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn newton_bracketed<F, S>(
+    f: F,
+    step: S,
+    lower: f64,
+    upper: f64,
+    start: f64,
+    increasing: bool,
+    tolerance: f64,
+    f_tolerance: f64,
+    max_iterations: usize,
+) -> Result<(f64, usize), String>
+where
+    F: Fn(f64) -> Result<f64, String>,
+    S: Fn(f64, f64) -> Result<f64, String>,
+{
+    let (mut lo, mut hi) = (lower, upper);
+    let mut x = start;
+    let mut previous_step = hi - lo;
+    for iteration in 1..=max_iterations {
+        let fx = f(x)?;
+        if fx.abs() <= f_tolerance {
+            return Ok((x, iteration));
+        }
+        if (fx < 0.0) == increasing {
+            lo = x;
+        } else {
+            hi = x;
+        }
+        let dx = step(x, fx)?;
+        let next = x - dx;
+        if !next.is_finite() || next <= lo || next >= hi || 2.0 * dx.abs() > previous_step {
+            let (root, used) = brent_with(
+                &f,
+                lo,
+                hi,
+                tolerance,
+                f_tolerance,
+                max_iterations - iteration,
+            )?;
+            return Ok((root, iteration + used));
+        }
+        if dx.abs() <= tolerance {
+            return Ok((next, iteration));
+        }
+        previous_step = dx.abs();
+        x = next;
+    }
+    Err("Root finding failed to converge".to_string())
+}
+
+/// Widens `[lower, upper]` geometrically until `f` changes sign across it,
+/// keeping `lower` above `floor`.
+pub(crate) fn expand_bracket<F>(
+    f: F,
+    mut lower: f64,
+    mut upper: f64,
+    floor: f64,
+) -> Result<(f64, f64), String>
+where
+    F: Fn(f64) -> Result<f64, String>,
+{
+    let (mut f_lower, mut f_upper) = (f(lower)?, f(upper)?);
+    for _ in 0..100 {
+        if f_lower * f_upper <= 0.0 {
+            return Ok((lower, upper));
+        }
+        if f_lower.abs() < f_upper.abs() && lower > floor {
+            lower = floor + 0.5 * (lower - floor);
+            f_lower = f(lower)?;
+        } else {
+            upper *= 2.0;
+            f_upper = f(upper)?;
+        }
+    }
+    Err("Failed to bracket the root".to_string())
+}
+
+/// The best point found by a minimiser.
+pub(crate) struct Minimum {
+    /// The best point
+    pub(crate) point: Vec<f64>,
+    /// The value of the function at the best point
+    pub(crate) value: f64,
+    /// Number of iterations used
+    pub(crate) iterations: usize,
+    /// Whether the stopping criterion was met within the iteration limit
+    pub(crate) converged: bool,
+}
+
+/// Minimises `f` with the Nelder-Mead simplex method, starting from `start` with an initial simplex of `steps`.
+/// Stops when the spread of the simplex values falls below `tolerance`, or after `max_iterations`
+/// with the best vertex found so far.
+pub(crate) fn nelder_mead<F>(
+    f: F,
+    start: &[f64],
+    steps: &[f64],
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<Minimum, String>
+where
+    F: Fn(&[f64]) -> Result<f64, String>,
+{
+    let n = start.len();
+    if n == 0 || steps.len() != n {
+        return Err("Start point and steps must have the same non-zero length".to_string());
+    }
+    let mut simplex: Vec<(Vec<f64>, f64)> = Vec::with_capacity(n + 1);
+    simplex.push((start.to_vec(), f(start)?));
+    for i in 0..n {
+        let mut vertex = start.to_vec();
+        vertex[i] += steps[i];
+        let value = f(&vertex)?;
+        simplex.push((vertex, value));
+    }
+    let combine = |a: &[f64], b: &[f64], weight: f64| -> Vec<f64> {
+        a.iter().zip(b).map(|(a, b)| a + weight * (b - a)).collect()
+    };
+
+    let mut iteration = 0;
+    let converged = loop {
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        if (simplex[n].1 - simplex[0].1).abs() <= tolerance {
+            break true;
+        }
+        if iteration == max_iterations {
+            break false;
+        }
+        iteration += 1;
+        let centroid: Vec<f64> = (0..n)
+            .map(|j| simplex[..n].iter().map(|(x, _)| x[j]).sum::<f64>() / n as f64)
+            .collect();
+        let worst = simplex[n].0.clone();
+
+        let reflected = combine(&centroid, &worst, -1.0);
+        let f_reflected = f(&reflected)?;
+        if f_reflected < simplex[0].1 {
+            let expanded = combine(&centroid, &worst, -2.0);
+            let f_expanded = f(&expanded)?;
+            simplex[n] = if f_expanded < f_reflected {
+                (expanded, f_expanded)
+            } else {
+                (reflected, f_reflected)
+            };
+        } else if f_reflected < simplex[n - 1].1 {
+            simplex[n] = (reflected, f_reflected);
+        } else {
+            let contracted = if f_reflected < simplex[n].1 {
+                combine(&centroid, &reflected, 0.5)
+            } else {
+                combine(&centroid, &worst, 0.5)
+            };
+            let f_contracted = f(&contracted)?;
+            if f_contracted < simplex[n].1.min(f_reflected) {
+                simplex[n] = (contracted, f_contracted);
+            } else {
+                // Shrink every vertex towards the best one.
+                let best = simplex[0].0.clone();
+                for vertex in simplex.iter_mut().skip(1) {
+                    let shrunk = combine(&best, &vertex.0, 0.5);
+                    *vertex = (shrunk.clone(), f(&shrunk)?);
+                }
+            }
+        }
+    };
+    let (point, value) = simplex.swap_remove(0);
+    Ok(Minimum {
+        point,
+        value,
+        iterations: iteration,
+        converged,
+    })
+}
+
+/// Composite Simpson integral of `f` over `[lower, upper]` using `intervals` (rounded up to even) subintervals.
+pub(crate) fn simpson<F>(f: F, lower: f64, upper: f64, intervals: usize) -> Result<f64, String>
+where
+    F: Fn(f64) -> Result<f64, String>,
+{
+    let n = (intervals.max(2) + 1) & !1;
+    let h = (upper - lower) / n as f64;
+    let mut sum = f(lower)? + f(upper)?;
+    for i in 1..n {
+        let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+        sum += weight * f(lower + i as f64 * h)?;
+    }
+    Ok(sum * h / 3.0)
+}
+
+/// Small deterministic generator of standard normal draws for the Monte Carlo pricers.
+/// Uses SplitMix64 for uniforms and the Box-Muller transform for normals.
+pub(crate) struct NormalRng {
+    state: u64,
+    spare: Option<f64>,
+}
+
+impl NormalRng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            spare: None,
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform draw on the open interval (0, 1).
+    pub(crate) fn next_uniform(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    pub(crate) fn next_normal(&mut self) -> f64 {
+        if let Some(spare) = self.spare.take() {
+            return spare;
+        }
+        let radius = (-2.0 * self.next_uniform().ln()).sqrt();
+        let angle = 2.0 * std::f64::consts::PI * self.next_uniform();
+        self.spare = Some(radius * angle.sin());
+        radius * angle.cos()
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    numerical_greeks::{bump_inputs, Bump, NumericalGreeks},
+    Inputs, OptionType,
+};
+
+/// A perpetual American option (Merton, 1973), which never expires and may be exercised at any time.
+///
+/// The value depends only on the spot, strike, carry and volatility of `underlying`; its time to maturity is ignored,
+/// so the theta is zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerpetualInputs {
+    /// The option type, strike and market parameters; `t` is ignored
+    pub underlying: Inputs,
+}
+
+impl PerpetualInputs {
+    /// Creates instance of the `PerpetualInputs` struct.
+    /// # Arguments
+    /// * `underlying` - The option type, strike and market parameters.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PerpetualInputs};
+    /// let underlying = Inputs::new(OptionType::Put, 100.0, 90.0, None, 0.05, 0.02, 0.0, Some(0.25));
+    /// let inputs = PerpetualInputs::new(underlying);
+    /// ```
+    /// # Returns
+    /// An instance of the `PerpetualInputs` struct.
+    pub fn new(underlying: Inputs) -> Self {
+        Self { underlying }
+    }
+
+    /// Root of the characteristic equation `sigma^2 y (y - 1) / 2 + b y - r = 0` for this option type:
+    /// the root above one for calls and the negative root for puts.
+    fn exponent(&self) -> Result<f64, String> {
+        let Inputs {
+            option_type, r, q, ..
+        } = self.underlying;
+        let sigma = self
+            .underlying
+            .sigma
+            .ok_or("Expected Some(f64) for self.sigma, received None")?;
+        if sigma <= 0.0 {
+            return Err("Volatility must be positive".to_string());
+        }
+        let variance = sigma * sigma;
+        let centre = 0.5 - (r - q) / variance;
+        let radius = (centre * centre + 2.0 * r / variance).sqrt();
+        match option_type {
+            OptionType::Call if q <= 0.0 => Err(
+                "A perpetual call is never exercised without a positive dividend yield".to_string(),
+            ),
+            OptionType::Put if r <= 0.0 => Err(
+                "A perpetual put is never exercised without a positive interest rate".to_string(),
+            ),
+            OptionType::Call => Ok(centre + radius),
+            OptionType::Put => Ok(centre - radius),
+        }
+    }
+
+    /// Calculates the optimal exercise boundary: the spot at or above which a call, or at or below which a put,
+    /// should be exercised immediately.
+    /// # Requires
+    /// k, r, q, sigma of the underlying; a positive q for calls and a positive r for puts.
+    /// # Returns
+    /// f64 of the exercise boundary.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PerpetualInputs};
+    /// let underlying = Inputs::new(OptionType::Put, 100.0, 90.0, None, 0.05, 0.02, 0.0, Some(0.25));
+    /// let boundary = PerpetualInputs::new(underlying).calc_exercise_boundary().unwrap();
+    /// assert!(boundary < 90.0);
+    /// ```
+    pub fn calc_exercise_boundary(&self) -> Result<f64, String> {
+        let y = self.exponent()?;
+        Ok(self.underlying.k * y / (y - 1.0))
+    }
+}
+
+impl NumericalGreeks for PerpetualInputs {
+    /// Calculates the price of the perpetual option,
+    /// `(k - boundary) (s / boundary)^y` in absolute value before exercise and the intrinsic value after.
+    /// # Requires
+    /// s, k, r, q, sigma of the underlying; a positive q for calls and a positive r for puts.
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, NumericalGreeks, OptionType, PerpetualInputs};
+    /// let underlying = Inputs::new(OptionType::Put, 100.0, 90.0, None, 0.05, 0.02, 0.0, Some(0.25));
+    /// let price = PerpetualInputs::new(underlying).calc_price().unwrap();
+    /// ```
+    fn calc_price(&self) -> Result<f64, String> {
+        let Inputs {
+            option_type, s, k, ..
+        } = self.underlying;
+        if s <= 0.0 || k <= 0.0 {
+            return Err("Spot and strike must be positive".to_string());
+        }
+        let y = self.exponent()?;
+        let boundary = k * y / (y - 1.0);
+        let exercised = match option_type {
+            OptionType::Call => s >= boundary,
+            OptionType::Put => s <= boundary,
+        };
+        if exercised {
+            return Ok(option_type * (s - k));
+        }
+        Ok(option_type * (boundary - k) * (s / boundary).powf(y))
+    }
+
+    fn calc_bumped_price(&self, bump: Bump, size: f64) -> Result<f64, String> {
+        PerpetualInputs::new(bump_inputs(&self.underlying, bump, size)).calc_price()
+    }
+
+    fn spot(&self) -> f64 {
+        self.underlying.s
+    }
+}
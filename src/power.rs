@@ -0,0 +1,399 @@
+use std::collections::HashMap;
+
+use num_traits::Float;
+
+use crate::{
+    calc_d1d2,
+    lets_be_rational::normal_distribution::{standard_normal_cdf, standard_normal_pdf},
+    Inputs, OptionType, DAYS_PER_YEAR,
+};
+
+/// The payoff of a power option on the underlying price `S` at maturity, with `K` the strike of the underlying [`Inputs`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PowerPayoff {
+    /// Symmetric power option paying `max(S - K, 0)^exponent` for a call and `max(K - S, 0)^exponent` for a put.
+    Symmetric { exponent: u32 },
+    /// Asymmetric power option paying `max(S^exponent - K, 0)` for a call and `max(K - S^exponent, 0)` for a put.
+    Asymmetric { exponent: f64 },
+    /// Asymmetric power option whose payoff is capped at `cap`.
+    Capped { exponent: f64, cap: f64 },
+    /// Log contract paying `ln(S / K)`, the payoff replicated by a variance swap; the option type is ignored.
+    LogContract,
+}
+
+/// The inputs to a power option on an underlying described by the market parameters of `underlying`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerInputs {
+    /// The payoff of the option
+    pub payoff: PowerPayoff,
+    /// The option type, strike and market parameters
+    pub underlying: Inputs,
+}
+
+/// `coefficient * E[S^power * 1{omega * S > omega * strike}]`, discounted; power payoffs are sums of these.
+struct PowerTerm {
+    coefficient: f64,
+    power: f64,
+    strike: f64,
+    omega: f64,
+}
+
+/// Value of a discounted power term and its partial derivatives.
+#[derive(Default)]
+struct TermSensitivities {
+    value: f64,
+    ds: f64,
+    ds2: f64,
+    dsigma: f64,
+    dr: f64,
+    dq: f64,
+    dt: f64,
+}
+
+impl PowerInputs {
+    /// Creates instance of the `PowerInputs` struct.
+    /// # Arguments
+    /// * `payoff` - The payoff of the option.
+    /// * `underlying` - The option type, strike and market parameters; `sigma` must be set.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PowerInputs, PowerPayoff};
+    /// let underlying = Inputs::new(OptionType::Call, 10.0, 100.0, None, 0.08, 0.02, 0.5, Some(0.1));
+    /// let inputs = PowerInputs::new(PowerPayoff::Asymmetric { exponent: 2.0 }, underlying);
+    /// ```
+    /// # Returns
+    /// An instance of the `PowerInputs` struct.
+    pub fn new(payoff: PowerPayoff, underlying: Inputs) -> Self {
+        Self { payoff, underlying }
+    }
+
+    /// Decomposes the payoff into discounted power terms.
+    fn terms(&self) -> Result<Vec<PowerTerm>, String> {
+        let k = self.underlying.k;
+        let option_type = self.underlying.option_type;
+        match self.payoff {
+            PowerPayoff::Symmetric { exponent } => {
+                if exponent == 0 {
+                    return Err("Exponent must be at least 1".to_string());
+                }
+                // Binomial expansion of (S - K)^i, or of (K - S)^i for a put.
+                let i = exponent as i32;
+                let mut binomial = 1.0;
+                let mut terms = Vec::with_capacity(exponent as usize + 1);
+                for j in 0..=i {
+                    let (coefficient, power, omega) = match option_type {
+                        OptionType::Call => (binomial * (-k).powi(j), i - j, 1.0),
+                        OptionType::Put => (binomial * k.powi(i - j) * (-1.0).powi(j), j, -1.0),
+                    };
+                    terms.push(PowerTerm {
+                        coefficient,
+                        power: power as f64,
+                        strike: k,
+                        omega,
+                    });
+                    binomial *= (i - j) as f64 / (j + 1) as f64;
+                }
+                Ok(terms)
+            }
+            PowerPayoff::Asymmetric { exponent } => asymmetric_terms(option_type, exponent, k),
+            PowerPayoff::Capped { exponent, cap } => {
+                if cap <= 0.0 {
+                    return Err("Cap must be positive".to_string());
+                }
+                // A capped power option is a power spread between the strike and the strike shifted by the cap.
+                let mut terms = asymmetric_terms(option_type, exponent, k)?;
+                let capped_k = k + f64::from(option_type) * cap;
+                if capped_k > 0.0 {
+                    terms.extend(
+                        asymmetric_terms(option_type, exponent, capped_k)?
+                            .into_iter()
+                            .map(|term| PowerTerm {
+                                coefficient: -term.coefficient,
+                                ..term
+                            }),
+                    );
+                }
+                Ok(terms)
+            }
+            PowerPayoff::LogContract => Err("Log contract has no power terms".to_string()),
+        }
+    }
+
+    /// Value and sensitivities of `e^(-rT) E[S^m 1{omega * S > omega * strike}]`, where `S^m` is lognormal
+    /// with volatility `m * sigma` and the exercise probability uses `d_m = d2 + m * sigma * sqrt(T)`.
+    fn term_sensitivities(&self, term: &PowerTerm) -> Result<TermSensitivities, String> {
+        let inputs = Inputs {
+            k: term.strike,
+            ..self.underlying.clone()
+        };
+        let (_, d2) = calc_d1d2(&inputs)?;
+        let sigma = inputs
+            .sigma
+            .ok_or("Expected Some(f64) for self.sigma, received None")?;
+        let Inputs { s, r, q, t, .. } = inputs;
+        let m = term.power;
+        let omega = term.omega;
+        let sqrt_t = t.sqrt();
+        let b = r - q;
+        let d = d2 + m * sigma * sqrt_t;
+        let discounted_moment = (-r * t
+            + m * s.ln()
+            + m * (b - 0.5 * sigma * sigma) * t
+            + 0.5 * m * m * sigma * sigma * t)
+            .exp();
+        let nd = standard_normal_cdf(omega * d);
+        let pdf = standard_normal_pdf(d);
+        let c = term.coefficient;
+        let value = discounted_moment * nd;
+        // d(term)/dx = term * dln(moment)/dx + moment * omega * n(d) * dd/dx
+        let partial = |dln_moment: f64, dd: f64| {
+            c * (value * dln_moment + discounted_moment * omega * pdf * dd)
+        };
+        let big_d = 1.0 / (sigma * sqrt_t);
+        Ok(TermSensitivities {
+            value: c * value,
+            ds: partial(m / s, big_d / s),
+            ds2: c * discounted_moment / (s * s)
+                * ((m - 1.0) * (m * nd + omega * pdf * big_d) + m * omega * pdf * big_d
+                    - omega * d * pdf * big_d * big_d),
+            dsigma: partial(
+                m * (m - 1.0) * sigma * t,
+                -d / sigma + (2.0 * m - 1.0) * sqrt_t,
+            ),
+            dr: partial((m - 1.0) * t, sqrt_t / sigma),
+            dq: partial(-m * t, -sqrt_t / sigma),
+            dt: partial(
+                -r + m * (b - 0.5 * sigma * sigma) + 0.5 * m * m * sigma * sigma,
+                (b + (m - 0.5) * sigma * sigma) * big_d - d / (2.0 * t),
+            ),
+        })
+    }
+
+    /// Sums the sensitivities of all terms of the payoff.
+    fn sensitivities(&self) -> Result<TermSensitivities, String> {
+        if let PowerPayoff::LogContract = self.payoff {
+            return self.log_contract_sensitivities();
+        }
+        let mut total = TermSensitivities::default();
+        for term in self.terms()? {
+            let part = self.term_sensitivities(&term)?;
+            total.value += part.value;
+            total.ds += part.ds;
+            total.ds2 += part.ds2;
+            total.dsigma += part.dsigma;
+            total.dr += part.dr;
+            total.dq += part.dq;
+            total.dt += part.dt;
+        }
+        Ok(total)
+    }
+
+    /// The log contract is worth `e^(-rT) (ln(S / K) + (r - q - sigma^2 / 2) T)`.
+    fn log_contract_sensitivities(&self) -> Result<TermSensitivities, String> {
+        let sigma = self
+            .underlying
+            .sigma
+            .ok_or("Expected Some(f64) for self.sigma, received None")?;
+        let Inputs { s, k, r, q, t, .. } = self.underlying;
+        if s <= 0.0 || k <= 0.0 {
+            return Err("Log contract requires positive spot and strike".to_string());
+        }
+        let df = (-r * t).exp();
+        let drift = r - q - 0.5 * sigma * sigma;
+        let value = df * ((s / k).ln() + drift * t);
+        Ok(TermSensitivities {
+            value,
+            ds: df / s,
+            ds2: -df / (s * s),
+            dsigma: -df * sigma * t,
+            dr: -t * value + df * t,
+            dq: -df * t,
+            dt: -r * value + df * drift,
+        })
+    }
+}
+
+/// Terms of an asymmetric power payoff, exercised when `S^exponent` is beyond `k`.
+fn asymmetric_terms(
+    option_type: OptionType,
+    exponent: f64,
+    k: f64,
+) -> Result<Vec<PowerTerm>, String> {
+    if exponent == 0.0 {
+        return Err("Exponent must not be zero".to_string());
+    }
+    if k <= 0.0 {
+        return Err("Strike must be positive".to_string());
+    }
+    // S^p > K exactly when S > K^(1/p) for a positive exponent, and S < K^(1/p) for a negative one.
+    let strike = k.powf(1.0 / exponent);
+    let omega = f64::from(option_type) * exponent.signum();
+    let sign = f64::from(option_type);
+    Ok(vec![
+        PowerTerm {
+            coefficient: sign,
+            power: exponent,
+            strike,
+            omega,
+        },
+        PowerTerm {
+            coefficient: -sign * k,
+            power: 0.0,
+            strike,
+            omega,
+        },
+    ])
+}
+
+pub trait PowerPricing<T>
+where
+    T: Float,
+{
+    fn calc_power_price(&self) -> Result<T, String>;
+    fn calc_power_delta(&self) -> Result<T, String>;
+    fn calc_power_gamma(&self) -> Result<T, String>;
+    fn calc_power_vega(&self) -> Result<T, String>;
+    fn calc_power_theta(&self) -> Result<T, String>;
+    fn calc_power_rho(&self) -> Result<T, String>;
+    fn calc_power_epsilon(&self) -> Result<T, String>;
+    fn calc_all_power_greeks(&self) -> Result<HashMap<String, T>, String>;
+}
+
+impl PowerPricing<f64> for PowerInputs {
+    /// Calculates the price of the power option.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying.
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PowerInputs, PowerPayoff, PowerPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 10.0, 100.0, None, 0.08, 0.02, 0.5, Some(0.1));
+    /// let inputs = PowerInputs::new(PowerPayoff::Asymmetric { exponent: 2.0 }, underlying);
+    /// let price = inputs.calc_power_price().unwrap();
+    /// ```
+    fn calc_power_price(&self) -> Result<f64, String> {
+        Ok(self.sensitivities()?.value)
+    }
+
+    /// Calculates the delta of the power option.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying.
+    /// # Returns
+    /// f64 of the delta of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PowerInputs, PowerPayoff, PowerPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 10.0, 100.0, None, 0.08, 0.02, 0.5, Some(0.1));
+    /// let inputs = PowerInputs::new(PowerPayoff::Asymmetric { exponent: 2.0 }, underlying);
+    /// let delta = inputs.calc_power_delta().unwrap();
+    /// ```
+    fn calc_power_delta(&self) -> Result<f64, String> {
+        Ok(self.sensitivities()?.ds)
+    }
+
+    /// Calculates the gamma of the power option.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying.
+    /// # Returns
+    /// f64 of the gamma of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PowerInputs, PowerPayoff, PowerPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 10.0, 100.0, None, 0.08, 0.02, 0.5, Some(0.1));
+    /// let inputs = PowerInputs::new(PowerPayoff::Asymmetric { exponent: 2.0 }, underlying);
+    /// let gamma = inputs.calc_power_gamma().unwrap();
+    /// ```
+    fn calc_power_gamma(&self) -> Result<f64, String> {
+        Ok(self.sensitivities()?.ds2)
+    }
+
+    /// Calculates the vega of the power option.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying.
+    /// # Returns
+    /// f64 of the change in price for a 1% change in volatility.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PowerInputs, PowerPayoff, PowerPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 10.0, 100.0, None, 0.08, 0.02, 0.5, Some(0.1));
+    /// let inputs = PowerInputs::new(PowerPayoff::Asymmetric { exponent: 2.0 }, underlying);
+    /// let vega = inputs.calc_power_vega().unwrap();
+    /// ```
+    fn calc_power_vega(&self) -> Result<f64, String> {
+        Ok(self.sensitivities()?.dsigma / 100.0)
+    }
+
+    /// Calculates the theta of the power option.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying.
+    /// # Returns
+    /// f64 of theta per day (not per year).
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PowerInputs, PowerPayoff, PowerPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 10.0, 100.0, None, 0.08, 0.02, 0.5, Some(0.1));
+    /// let inputs = PowerInputs::new(PowerPayoff::Asymmetric { exponent: 2.0 }, underlying);
+    /// let theta = inputs.calc_power_theta().unwrap();
+    /// ```
+    fn calc_power_theta(&self) -> Result<f64, String> {
+        Ok(-self.sensitivities()?.dt / DAYS_PER_YEAR)
+    }
+
+    /// Calculates the rho of the power option.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying.
+    /// # Returns
+    /// f64 of the change in price for a 1% change in the risk-free rate.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PowerInputs, PowerPayoff, PowerPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 10.0, 100.0, None, 0.08, 0.02, 0.5, Some(0.1));
+    /// let inputs = PowerInputs::new(PowerPayoff::Asymmetric { exponent: 2.0 }, underlying);
+    /// let rho = inputs.calc_power_rho().unwrap();
+    /// ```
+    fn calc_power_rho(&self) -> Result<f64, String> {
+        Ok(self.sensitivities()?.dr / 100.0)
+    }
+
+    /// Calculates the epsilon of the power option.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying.
+    /// # Returns
+    /// f64 of the change in price for a unit change in the dividend yield.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PowerInputs, PowerPayoff, PowerPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 10.0, 100.0, None, 0.08, 0.02, 0.5, Some(0.1));
+    /// let inputs = PowerInputs::new(PowerPayoff::Asymmetric { exponent: 2.0 }, underlying);
+    /// let epsilon = inputs.calc_power_epsilon().unwrap();
+    /// ```
+    fn calc_power_epsilon(&self) -> Result<f64, String> {
+        Ok(self.sensitivities()?.dq)
+    }
+
+    /// Calculates the price and all greeks of the power option.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying.
+    /// # Returns
+    /// HashMap of type <String, f64> with the price, delta, gamma, vega, theta, rho and epsilon.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PowerInputs, PowerPayoff, PowerPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 1.0, Some(0.2));
+    /// let inputs = PowerInputs::new(PowerPayoff::LogContract, underlying);
+    /// let greeks = inputs.calc_all_power_greeks().unwrap();
+    /// ```
+    fn calc_all_power_greeks(&self) -> Result<HashMap<String, f64>, String> {
+        let sensitivities = self.sensitivities()?;
+        let mut greeks: HashMap<String, f64> = HashMap::with_capacity(7);
+        greeks.insert("price".into(), sensitivities.value);
+        greeks.insert("delta".into(), sensitivities.ds);
+        greeks.insert("gamma".into(), sensitivities.ds2);
+        greeks.insert("vega".into(), sensitivities.dsigma / 100.0);
+        greeks.insert("theta".into(), -sensitivities.dt / DAYS_PER_YEAR);
+        greeks.insert("rho".into(), sensitivities.dr / 100.0);
+        greeks.insert("epsilon".into(), sensitivities.dq);
+        Ok(greeks)
+    }
+}
@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+
+use num_traits::Float;
+
+use crate::{Greeks, Inputs, OptionType, Pricing, DAYS_PER_YEAR};
+
+/// How a foreign-asset option is settled in the domestic currency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuantoStyle {
+    /// Fixed-rate quanto paying `fixed_fx * max(S - K, 0)`, with `K` in foreign currency.
+    FixedRate {
+        /// Exchange rate fixed at inception, in domestic currency per unit of foreign currency
+        fixed_fx: f64,
+    },
+    /// Composite option paying `max(S * X - K, 0)`, with `K` in domestic currency.
+    Composite,
+    /// Equity-linked foreign-exchange option paying `S * max(X - K, 0)`, with `K` an exchange rate.
+    EquityLinkedFx,
+}
+
+/// The inputs to options on a foreign asset settled in the domestic currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantoInputs {
+    /// The type of the option (call or put)
+    pub option_type: OptionType,
+    /// Price of the foreign asset in foreign currency
+    pub s: f64,
+    /// Strike price; its unit depends on the [`QuantoStyle`]
+    pub k: f64,
+    /// Spot exchange rate, in domestic currency per unit of foreign currency
+    pub fx: f64,
+    /// Domestic risk-free rate
+    pub r: f64,
+    /// Foreign risk-free rate
+    pub r_foreign: f64,
+    /// Dividend yield of the foreign asset
+    pub q: f64,
+    /// Time to maturity in years
+    pub t: f64,
+    /// Volatility of the foreign asset
+    pub sigma: f64,
+    /// Volatility of the exchange rate
+    pub sigma_fx: f64,
+    /// Correlation between the foreign asset and the exchange rate
+    pub rho: f64,
+}
+
+impl QuantoInputs {
+    /// Creates instance of the `QuantoInputs` struct.
+    /// # Arguments
+    /// * `option_type` - The type of option to be priced.
+    /// * `s` - The price of the foreign asset in foreign currency.
+    /// * `k` - The strike price.
+    /// * `fx` - The spot exchange rate (domestic per foreign).
+    /// * `r` - The domestic risk-free rate.
+    /// * `r_foreign` - The foreign risk-free rate.
+    /// * `q` - The dividend yield of the foreign asset.
+    /// * `t` - The time to maturity of the option in years.
+    /// * `sigma` - The volatility of the foreign asset.
+    /// * `sigma_fx` - The volatility of the exchange rate.
+    /// * `rho` - The correlation between the foreign asset and the exchange rate.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, QuantoInputs};
+    /// let inputs = QuantoInputs::new(OptionType::Call, 100.0, 105.0, 1.5, 0.08, 0.05, 0.04, 0.5, 0.2, 0.1, 0.3);
+    /// ```
+    /// # Returns
+    /// An instance of the `QuantoInputs` struct.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        option_type: OptionType,
+        s: f64,
+        k: f64,
+        fx: f64,
+        r: f64,
+        r_foreign: f64,
+        q: f64,
+        t: f64,
+        sigma: f64,
+        sigma_fx: f64,
+        rho: f64,
+    ) -> Self {
+        Self {
+            option_type,
+            s,
+            k,
+            fx,
+            r,
+            r_foreign,
+            q,
+            t,
+            sigma,
+            sigma_fx,
+            rho,
+        }
+    }
+
+    /// Covariance between the asset and exchange-rate returns.
+    fn covariance(&self) -> f64 {
+        self.rho * self.sigma * self.sigma_fx
+    }
+
+    /// Volatility of the domestic-currency asset price `S * X`.
+    fn composite_sigma(&self) -> f64 {
+        (self.sigma.powi(2) + self.sigma_fx.powi(2) + 2.0 * self.covariance()).sqrt()
+    }
+
+    /// The single-asset Black-Scholes-Merton problem equivalent to this option, and the multiplier applied to its price.
+    fn equivalent(&self, style: QuantoStyle) -> Result<(Inputs, f64), String> {
+        if !(-1.0..=1.0).contains(&self.rho) {
+            return Err("Correlation must be between -1 and 1".to_string());
+        }
+        match style {
+            // The quanto drift adjustment enters as an extra yield.
+            QuantoStyle::FixedRate { fixed_fx } => Ok((
+                Inputs::new(
+                    self.option_type,
+                    self.s,
+                    self.k,
+                    None,
+                    self.r,
+                    self.q + self.r - self.r_foreign + self.covariance(),
+                    self.t,
+                    Some(self.sigma),
+                ),
+                fixed_fx,
+            )),
+            QuantoStyle::Composite => Ok((
+                Inputs::new(
+                    self.option_type,
+                    self.s * self.fx,
+                    self.k,
+                    None,
+                    self.r,
+                    self.q,
+                    self.t,
+                    Some(self.composite_sigma()),
+                ),
+                1.0,
+            )),
+            // An option on the exchange rate measured in units of the foreign asset.
+            QuantoStyle::EquityLinkedFx => {
+                let q_fx = self.r_foreign - self.covariance();
+                Ok((
+                    Inputs::new(
+                        self.option_type,
+                        self.fx,
+                        self.k,
+                        None,
+                        self.r,
+                        q_fx,
+                        self.t,
+                        Some(self.sigma_fx),
+                    ),
+                    self.s * ((q_fx - self.q) * self.t).exp(),
+                ))
+            }
+        }
+    }
+}
+
+pub trait QuantoPricing<T>
+where
+    T: Float,
+{
+    fn calc_quanto_price(&self, style: QuantoStyle) -> Result<T, String>;
+    fn calc_quanto_delta(&self, style: QuantoStyle) -> Result<T, String>;
+    fn calc_quanto_gamma(&self, style: QuantoStyle) -> Result<T, String>;
+    fn calc_quanto_vega(&self, style: QuantoStyle) -> Result<T, String>;
+    fn calc_quanto_fx_vega(&self, style: QuantoStyle) -> Result<T, String>;
+    fn calc_quanto_corr_sensitivity(&self, style: QuantoStyle) -> Result<T, String>;
+    fn calc_quanto_rho(&self, style: QuantoStyle) -> Result<T, String>;
+    fn calc_quanto_theta(&self, style: QuantoStyle) -> Result<T, String>;
+    fn calc_all_quanto_greeks(&self, style: QuantoStyle) -> Result<HashMap<String, T>, String>;
+}
+
+impl QuantoPricing<f64> for QuantoInputs {
+    /// Calculates the domestic-currency price of the option.
+    /// # Requires
+    /// s, k, fx, r, r_foreign, q, t, sigma, sigma_fx, rho.
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, QuantoInputs, QuantoPricing, QuantoStyle};
+    /// let inputs = QuantoInputs::new(OptionType::Call, 100.0, 105.0, 1.5, 0.08, 0.05, 0.04, 0.5, 0.2, 0.1, 0.3);
+    /// let price = inputs.calc_quanto_price(QuantoStyle::FixedRate { fixed_fx: 1.5 }).unwrap();
+    /// ```
+    fn calc_quanto_price(&self, style: QuantoStyle) -> Result<f64, String> {
+        let (inputs, multiplier) = self.equivalent(style)?;
+        Ok(multiplier * inputs.calc_price()?)
+    }
+
+    /// Calculates the delta of the option with respect to the foreign asset price, in domestic currency.
+    /// # Requires
+    /// s, k, fx, r, r_foreign, q, t, sigma, sigma_fx, rho.
+    /// # Returns
+    /// f64 of the delta of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, QuantoInputs, QuantoPricing, QuantoStyle};
+    /// let inputs = QuantoInputs::new(OptionType::Call, 100.0, 105.0, 1.5, 0.08, 0.05, 0.04, 0.5, 0.2, 0.1, 0.3);
+    /// let delta = inputs.calc_quanto_delta(QuantoStyle::Composite).unwrap();
+    /// ```
+    fn calc_quanto_delta(&self, style: QuantoStyle) -> Result<f64, String> {
+        let (inputs, multiplier) = self.equivalent(style)?;
+        match style {
+            QuantoStyle::FixedRate { .. } => Ok(multiplier * inputs.calc_delta()?),
+            QuantoStyle::Composite => Ok(self.fx * inputs.calc_delta()?),
+            // The price is linear in the asset price.
+            QuantoStyle::EquityLinkedFx => Ok(multiplier / self.s * inputs.calc_price()?),
+        }
+    }
+
+    /// Calculates the gamma of the option with respect to the foreign asset price.
+    /// # Requires
+    /// s, k, fx, r, r_foreign, q, t, sigma, sigma_fx, rho.
+    /// # Returns
+    /// f64 of the gamma of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, QuantoInputs, QuantoPricing, QuantoStyle};
+    /// let inputs = QuantoInputs::new(OptionType::Call, 100.0, 105.0, 1.5, 0.08, 0.05, 0.04, 0.5, 0.2, 0.1, 0.3);
+    /// let gamma = inputs.calc_quanto_gamma(QuantoStyle::Composite).unwrap();
+    /// ```
+    fn calc_quanto_gamma(&self, style: QuantoStyle) -> Result<f64, String> {
+        let (inputs, multiplier) = self.equivalent(style)?;
+        match style {
+            QuantoStyle::FixedRate { .. } => Ok(multiplier * inputs.calc_gamma()?),
+            QuantoStyle::Composite => Ok(self.fx * self.fx * inputs.calc_gamma()?),
+            QuantoStyle::EquityLinkedFx => Ok(0.0),
+        }
+    }
+
+    /// Calculates the vega of the option with respect to the foreign asset volatility.
+    /// # Requires
+    /// s, k, fx, r, r_foreign, q, t, sigma, sigma_fx, rho.
+    /// # Returns
+    /// f64 of the change in price for a 1% change in `sigma`.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, QuantoInputs, QuantoPricing, QuantoStyle};
+    /// let inputs = QuantoInputs::new(OptionType::Call, 100.0, 105.0, 1.5, 0.08, 0.05, 0.04, 0.5, 0.2, 0.1, 0.3);
+    /// let vega = inputs.calc_quanto_vega(QuantoStyle::Composite).unwrap();
+    /// ```
+    fn calc_quanto_vega(&self, style: QuantoStyle) -> Result<f64, String> {
+        let (inputs, multiplier) = self.equivalent(style)?;
+        let d_covariance = self.rho * self.sigma_fx;
+        match style {
+            QuantoStyle::FixedRate { .. } => {
+                Ok(multiplier
+                    * (inputs.calc_vega()? + 0.01 * d_covariance * inputs.calc_epsilon()?))
+            }
+            QuantoStyle::Composite => {
+                Ok(inputs.calc_vega()? * (self.sigma + d_covariance) / self.composite_sigma())
+            }
+            QuantoStyle::EquityLinkedFx => Ok(-0.01
+                * multiplier
+                * d_covariance
+                * (self.t * inputs.calc_price()? + inputs.calc_epsilon()?)),
+        }
+    }
+
+    /// Calculates the vega of the option with respect to the exchange-rate volatility.
+    /// # Requires
+    /// s, k, fx, r, r_foreign, q, t, sigma, sigma_fx, rho.
+    /// # Returns
+    /// f64 of the change in price for a 1% change in `sigma_fx`.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, QuantoInputs, QuantoPricing, QuantoStyle};
+    /// let inputs = QuantoInputs::new(OptionType::Call, 100.0, 105.0, 1.5, 0.08, 0.05, 0.04, 0.5, 0.2, 0.1, 0.3);
+    /// let fx_vega = inputs.calc_quanto_fx_vega(QuantoStyle::FixedRate { fixed_fx: 1.5 }).unwrap();
+    /// ```
+    fn calc_quanto_fx_vega(&self, style: QuantoStyle) -> Result<f64, String> {
+        let (inputs, multiplier) = self.equivalent(style)?;
+        let d_covariance = self.rho * self.sigma;
+        match style {
+            QuantoStyle::FixedRate { .. } => {
+                Ok(0.01 * multiplier * d_covariance * inputs.calc_epsilon()?)
+            }
+            QuantoStyle::Composite => {
+                Ok(inputs.calc_vega()? * (self.sigma_fx + d_covariance) / self.composite_sigma())
+            }
+            QuantoStyle::EquityLinkedFx => Ok(multiplier
+                * (inputs.calc_vega()?
+                    - 0.01
+                        * d_covariance
+                        * (self.t * inputs.calc_price()? + inputs.calc_epsilon()?))),
+        }
+    }
+
+    /// Calculates the sensitivity of the option to the correlation between the asset and the exchange rate.
+    /// # Requires
+    /// s, k, fx, r, r_foreign, q, t, sigma, sigma_fx, rho.
+    /// # Returns
+    /// f64 of the change in price for a unit change in correlation.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, QuantoInputs, QuantoPricing, QuantoStyle};
+    /// let inputs = QuantoInputs::new(OptionType::Call, 100.0, 105.0, 1.5, 0.08, 0.05, 0.04, 0.5, 0.2, 0.1, 0.3);
+    /// let corr_sensitivity = inputs.calc_quanto_corr_sensitivity(QuantoStyle::Composite).unwrap();
+    /// ```
+    fn calc_quanto_corr_sensitivity(&self, style: QuantoStyle) -> Result<f64, String> {
+        let (inputs, multiplier) = self.equivalent(style)?;
+        let d_covariance = self.sigma * self.sigma_fx;
+        match style {
+            QuantoStyle::FixedRate { .. } => {
+                Ok(multiplier * d_covariance * inputs.calc_epsilon()?)
+            }
+            QuantoStyle::Composite => {
+                Ok(100.0 * inputs.calc_vega()? * d_covariance / self.composite_sigma())
+            }
+            QuantoStyle::EquityLinkedFx => Ok(-multiplier
+                * d_covariance
+                * (self.t * inputs.calc_price()? + inputs.calc_epsilon()?)),
+        }
+    }
+
+    /// Calculates the rho of the option with respect to the domestic rate.
+    /// # Requires
+    /// s, k, fx, r, r_foreign, q, t, sigma, sigma_fx, rho.
+    /// # Returns
+    /// f64 of the change in price for a 1% change in `r`.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, QuantoInputs, QuantoPricing, QuantoStyle};
+    /// let inputs = QuantoInputs::new(OptionType::Call, 100.0, 105.0, 1.5, 0.08, 0.05, 0.04, 0.5, 0.2, 0.1, 0.3);
+    /// let rho = inputs.calc_quanto_rho(QuantoStyle::Composite).unwrap();
+    /// ```
+    fn calc_quanto_rho(&self, style: QuantoStyle) -> Result<f64, String> {
+        let (inputs, multiplier) = self.equivalent(style)?;
+        match style {
+            // The domestic rate also enters the adjusted yield.
+            QuantoStyle::FixedRate { .. } => {
+                Ok(multiplier * (inputs.calc_rho()? + inputs.calc_epsilon()? / 100.0))
+            }
+            QuantoStyle::Composite | QuantoStyle::EquityLinkedFx => {
+                Ok(multiplier * inputs.calc_rho()?)
+            }
+        }
+    }
+
+    /// Calculates the theta of the option.
+    /// # Requires
+    /// s, k, fx, r, r_foreign, q, t, sigma, sigma_fx, rho.
+    /// # Returns
+    /// f64 of theta per day (not per year).
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, QuantoInputs, QuantoPricing, QuantoStyle};
+    /// let inputs = QuantoInputs::new(OptionType::Call, 100.0, 105.0, 1.5, 0.08, 0.05, 0.04, 0.5, 0.2, 0.1, 0.3);
+    /// let theta = inputs.calc_quanto_theta(QuantoStyle::Composite).unwrap();
+    /// ```
+    fn calc_quanto_theta(&self, style: QuantoStyle) -> Result<f64, String> {
+        let (inputs, multiplier) = self.equivalent(style)?;
+        match style {
+            QuantoStyle::FixedRate { .. } | QuantoStyle::Composite => {
+                Ok(multiplier * inputs.calc_theta()?)
+            }
+            // The multiplier itself depends on the time to maturity.
+            QuantoStyle::EquityLinkedFx => Ok(multiplier
+                * (inputs.calc_theta()?
+                    - (inputs.q - self.q) * inputs.calc_price()? / DAYS_PER_YEAR)),
+        }
+    }
+
+    /// Calculates the price and all greeks of the option.
+    /// # Requires
+    /// s, k, fx, r, r_foreign, q, t, sigma, sigma_fx, rho.
+    /// # Returns
+    /// HashMap of type <String, f64> with the price, delta, gamma, vega, fx vega, correlation sensitivity, rho and theta.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, QuantoInputs, QuantoPricing, QuantoStyle};
+    /// let inputs = QuantoInputs::new(OptionType::Call, 100.0, 105.0, 1.5, 0.08, 0.05, 0.04, 0.5, 0.2, 0.1, 0.3);
+    /// let greeks = inputs.calc_all_quanto_greeks(QuantoStyle::EquityLinkedFx).unwrap();
+    /// ```
+    fn calc_all_quanto_greeks(&self, style: QuantoStyle) -> Result<HashMap<String, f64>, String> {
+        let mut greeks: HashMap<String, f64> = HashMap::with_capacity(8);
+        greeks.insert("price".into(), self.calc_quanto_price(style)?);
+        greeks.insert("delta".into(), self.calc_quanto_delta(style)?);
+        greeks.insert("gamma".into(), self.calc_quanto_gamma(style)?);
+        greeks.insert("vega".into(), self.calc_quanto_vega(style)?);
+        greeks.insert("fx_vega".into(), self.calc_quanto_fx_vega(style)?);
+        greeks.insert(
+            "corr_sensitivity".into(),
+            self.calc_quanto_corr_sensitivity(style)?,
+        );
+        greeks.insert("rho".into(), self.calc_quanto_rho(style)?);
+        greeks.insert("theta".into(), self.calc_quanto_theta(style)?);
+        Ok(greeks)
+    }
+}
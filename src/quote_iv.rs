@@ -0,0 +1,108 @@
+use crate::{implied_volatility::rational_iv, Greeks, Inputs, IvErrorKind};
+
+/// A two-sided market quote on an option.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuoteInputs {
+    /// The option type, strike and market parameters; `p` and `sigma` are ignored
+    pub underlying: Inputs,
+    /// Bid price, zero when there is no bid
+    pub bid: f64,
+    /// Ask price
+    pub ask: f64,
+}
+
+/// Implied volatilities of a two-sided quote. A side is `None` when no volatility reprices it:
+/// a zero bid, a price below intrinsic value or a price at or above the maximum value of the option.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuoteIv {
+    /// Implied volatility of the bid
+    pub bid: Option<f64>,
+    /// Implied volatility of the ask
+    pub ask: Option<f64>,
+    /// Implied volatility of the mid price
+    pub mid: Option<f64>,
+    /// Average of the bid and ask volatilities weighted by the vega at each
+    pub fair: Option<f64>,
+    /// Half the spread converted into volatility with the vega at the mid volatility
+    pub uncertainty: Option<f64>,
+}
+
+impl QuoteInputs {
+    /// Creates instance of the `QuoteInputs` struct.
+    /// # Arguments
+    /// * `underlying` - The option type, strike and market parameters.
+    /// * `bid` - The bid price.
+    /// * `ask` - The ask price.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, QuoteInputs};
+    /// let underlying = Inputs::new(OptionType::Call, 100.0, 105.0, None, 0.05, 0.01, 0.25, None);
+    /// let quote = QuoteInputs::new(underlying, 2.10, 2.30);
+    /// ```
+    /// # Returns
+    /// An instance of the `QuoteInputs` struct.
+    pub fn new(underlying: Inputs, bid: f64, ask: f64) -> Self {
+        Self {
+            underlying,
+            bid,
+            ask,
+        }
+    }
+
+    /// Implied volatility and vega of the option at price `p`, or `None` if no volatility reprices it.
+    fn side(&self, p: f64) -> Result<Option<(f64, f64)>, String> {
+        if p <= 0.0 {
+            return Ok(None);
+        }
+        let mut inputs = Inputs {
+            p: Some(p),
+            ..self.underlying.clone()
+        };
+        match rational_iv(&inputs) {
+            Ok(sigma) => {
+                inputs.sigma = Some(sigma);
+                Ok(Some((sigma, inputs.calc_vega()?)))
+            }
+            Err(IvErrorKind::BelowIntrinsic | IvErrorKind::AboveMaximum) => Ok(None),
+            Err(kind) => Err(kind.to_string()),
+        }
+    }
+
+    /// Calculates the bid, ask, mid and vega-weighted fair implied volatilities of the quote with the
+    /// "Let's be rational" method, and the volatility uncertainty implied by the spread.
+    /// # Requires
+    /// s, k, r, q, t of the underlying, bid, ask.
+    /// # Returns
+    /// QuoteIv of the implied volatilities, with `None` for any price no volatility reprices.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, QuoteInputs};
+    /// let underlying = Inputs::new(OptionType::Put, 100.0, 80.0, None, 0.05, 0.01, 0.25, None);
+    /// let quote = QuoteInputs::new(underlying, 0.0, 0.15).calc_quote_iv().unwrap();
+    /// assert!(quote.bid.is_none());
+    /// assert!(quote.ask.is_some() && quote.mid.is_some());
+    /// ```
+    pub fn calc_quote_iv(&self) -> Result<QuoteIv, String> {
+        if self.bid < 0.0 || self.ask <= 0.0 || self.bid > self.ask {
+            return Err("Quote requires 0 <= bid <= ask and a positive ask".to_string());
+        }
+        let bid = self.side(self.bid)?;
+        let ask = self.side(self.ask)?;
+        let mid = self.side(0.5 * (self.bid + self.ask))?;
+        let fair = match (bid, ask) {
+            (Some((bid_sigma, bid_vega)), Some((ask_sigma, ask_vega))) => {
+                Some((bid_vega * bid_sigma + ask_vega * ask_sigma) / (bid_vega + ask_vega))
+            }
+            _ => None,
+        };
+        // Vega is quoted per 1% change in volatility.
+        let uncertainty = mid.map(|(_, vega)| 0.5 * (self.ask - self.bid) / (100.0 * vega));
+        Ok(QuoteIv {
+            bid: bid.map(|(sigma, _)| sigma),
+            ask: ask.map(|(sigma, _)| sigma),
+            mid: mid.map(|(sigma, _)| sigma),
+            fair,
+            uncertainty,
+        })
+    }
+}
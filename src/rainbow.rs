@@ -0,0 +1,392 @@
+use std::collections::HashMap;
+
+use num_traits::Float;
+
+use crate::{
+    lets_be_rational::normal_distribution::bivariate_normal_cdf,
+    numerics::{
+        bounded_difference, central_difference, mixed_difference, second_difference, ABSOLUTE_BUMP,
+        RELATIVE_BUMP,
+    },
+    two_asset::{margrabe, with_spots},
+    OptionType, TwoAssetInputs,
+};
+
+/// The payoff of a two-asset rainbow option.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RainbowPayoff {
+    /// Option on the maximum of the two assets, `max(max(S1, S2) - K, 0)` for a call.
+    BestOf,
+    /// Option on the minimum of the two assets, `max(min(S1, S2) - K, 0)` for a call.
+    WorstOf,
+    /// Two-asset correlation option paying `S2 - K` if `S1 > trigger_strike` and `S2 > K` for a call,
+    /// and `K - S2` if `S1 < trigger_strike` and `S2 < K` for a put.
+    Correlation {
+        /// Strike applied to the first asset
+        trigger_strike: f64,
+    },
+}
+
+pub trait RainbowPricing<T>
+where
+    T: Float,
+{
+    fn calc_rainbow_price(&self, payoff: RainbowPayoff) -> Result<T, String>;
+    fn calc_rainbow_delta1(&self, payoff: RainbowPayoff) -> Result<T, String>;
+    fn calc_rainbow_delta2(&self, payoff: RainbowPayoff) -> Result<T, String>;
+    fn calc_rainbow_gamma1(&self, payoff: RainbowPayoff) -> Result<T, String>;
+    fn calc_rainbow_gamma2(&self, payoff: RainbowPayoff) -> Result<T, String>;
+    fn calc_rainbow_cross_gamma(&self, payoff: RainbowPayoff) -> Result<T, String>;
+    fn calc_rainbow_vega1(&self, payoff: RainbowPayoff) -> Result<T, String>;
+    fn calc_rainbow_vega2(&self, payoff: RainbowPayoff) -> Result<T, String>;
+    fn calc_rainbow_corr_sensitivity(&self, payoff: RainbowPayoff) -> Result<T, String>;
+    fn calc_all_rainbow_greeks(&self, payoff: RainbowPayoff) -> Result<HashMap<String, T>, String>;
+}
+
+impl RainbowPricing<f64> for TwoAssetInputs {
+    /// Calculates the price of the rainbow option.
+    /// Best-of and worst-of options use Stulz (1982), correlation options use Zhang (1995).
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, RainbowPayoff, RainbowPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 100.0, 105.0, 98.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let price = inputs.calc_rainbow_price(RainbowPayoff::BestOf).unwrap();
+    /// ```
+    fn calc_rainbow_price(&self, payoff: RainbowPayoff) -> Result<f64, String> {
+        self.validate()?;
+        if self.k <= 0.0 {
+            return Err("Strike price must be positive".to_string());
+        }
+        match payoff {
+            RainbowPayoff::BestOf => best_of(self),
+            RainbowPayoff::WorstOf => worst_of(self),
+            RainbowPayoff::Correlation { trigger_strike } => correlation(self, trigger_strike),
+        }
+    }
+
+    /// Calculates the delta of the rainbow option with respect to the first asset.
+    /// Greeks of rainbow options are computed by central finite differences.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the first-asset delta of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, RainbowPayoff, RainbowPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 100.0, 105.0, 98.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let delta1 = inputs.calc_rainbow_delta1(RainbowPayoff::BestOf).unwrap();
+    /// ```
+    fn calc_rainbow_delta1(&self, payoff: RainbowPayoff) -> Result<f64, String> {
+        central_difference(
+            |s1| with_spots(self, s1, self.s2).calc_rainbow_price(payoff),
+            self.s1,
+            self.s1 * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the delta of the rainbow option with respect to the second asset.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the second-asset delta of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, RainbowPayoff, RainbowPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 100.0, 105.0, 98.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let delta2 = inputs.calc_rainbow_delta2(RainbowPayoff::BestOf).unwrap();
+    /// ```
+    fn calc_rainbow_delta2(&self, payoff: RainbowPayoff) -> Result<f64, String> {
+        central_difference(
+            |s2| with_spots(self, self.s1, s2).calc_rainbow_price(payoff),
+            self.s2,
+            self.s2 * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the gamma of the rainbow option with respect to the first asset.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the first-asset gamma of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, RainbowPayoff, RainbowPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 100.0, 105.0, 98.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let gamma1 = inputs.calc_rainbow_gamma1(RainbowPayoff::BestOf).unwrap();
+    /// ```
+    fn calc_rainbow_gamma1(&self, payoff: RainbowPayoff) -> Result<f64, String> {
+        second_difference(
+            |s1| with_spots(self, s1, self.s2).calc_rainbow_price(payoff),
+            self.s1,
+            self.s1 * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the gamma of the rainbow option with respect to the second asset.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the second-asset gamma of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, RainbowPayoff, RainbowPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 100.0, 105.0, 98.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let gamma2 = inputs.calc_rainbow_gamma2(RainbowPayoff::BestOf).unwrap();
+    /// ```
+    fn calc_rainbow_gamma2(&self, payoff: RainbowPayoff) -> Result<f64, String> {
+        second_difference(
+            |s2| with_spots(self, self.s1, s2).calc_rainbow_price(payoff),
+            self.s2,
+            self.s2 * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the cross gamma (d²V/dS1dS2) of the rainbow option.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the cross gamma of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, RainbowPayoff, RainbowPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 100.0, 105.0, 98.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let cross_gamma = inputs.calc_rainbow_cross_gamma(RainbowPayoff::BestOf).unwrap();
+    /// ```
+    fn calc_rainbow_cross_gamma(&self, payoff: RainbowPayoff) -> Result<f64, String> {
+        mixed_difference(
+            |s1, s2| with_spots(self, s1, s2).calc_rainbow_price(payoff),
+            self.s1,
+            self.s2,
+            self.s1 * RELATIVE_BUMP,
+            self.s2 * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the vega of the rainbow option with respect to the first asset's volatility.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the change in price for a 1% change in `sigma1`.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, RainbowPayoff, RainbowPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 100.0, 105.0, 98.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let vega1 = inputs.calc_rainbow_vega1(RainbowPayoff::BestOf).unwrap();
+    /// ```
+    fn calc_rainbow_vega1(&self, payoff: RainbowPayoff) -> Result<f64, String> {
+        let vega = central_difference(
+            |sigma1| {
+                TwoAssetInputs {
+                    sigma1,
+                    ..self.clone()
+                }
+                .calc_rainbow_price(payoff)
+            },
+            self.sigma1,
+            ABSOLUTE_BUMP,
+        )?;
+        Ok(0.01 * vega)
+    }
+
+    /// Calculates the vega of the rainbow option with respect to the second asset's volatility.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the change in price for a 1% change in `sigma2`.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, RainbowPayoff, RainbowPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 100.0, 105.0, 98.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let vega2 = inputs.calc_rainbow_vega2(RainbowPayoff::BestOf).unwrap();
+    /// ```
+    fn calc_rainbow_vega2(&self, payoff: RainbowPayoff) -> Result<f64, String> {
+        let vega = central_difference(
+            |sigma2| {
+                TwoAssetInputs {
+                    sigma2,
+                    ..self.clone()
+                }
+                .calc_rainbow_price(payoff)
+            },
+            self.sigma2,
+            ABSOLUTE_BUMP,
+        )?;
+        Ok(0.01 * vega)
+    }
+
+    /// Calculates the sensitivity of the rainbow option to the correlation between the assets.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the change in price for a unit change in correlation.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, RainbowPayoff, RainbowPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 100.0, 105.0, 98.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let corr_sensitivity = inputs.calc_rainbow_corr_sensitivity(RainbowPayoff::BestOf).unwrap();
+    /// ```
+    fn calc_rainbow_corr_sensitivity(&self, payoff: RainbowPayoff) -> Result<f64, String> {
+        bounded_difference(
+            |rho| {
+                TwoAssetInputs {
+                    rho,
+                    ..self.clone()
+                }
+                .calc_rainbow_price(payoff)
+            },
+            self.rho,
+            ABSOLUTE_BUMP,
+            -1.0,
+            1.0,
+        )
+    }
+
+    /// Calculates the price and all greeks of the rainbow option.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// HashMap of type <String, f64> with the price, per-asset deltas, gammas and vegas, the cross gamma and the correlation sensitivity.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, RainbowPayoff, RainbowPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 100.0, 105.0, 98.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let greeks = inputs.calc_all_rainbow_greeks(RainbowPayoff::WorstOf).unwrap();
+    /// ```
+    fn calc_all_rainbow_greeks(
+        &self,
+        payoff: RainbowPayoff,
+    ) -> Result<HashMap<String, f64>, String> {
+        let mut greeks: HashMap<String, f64> = HashMap::with_capacity(10);
+        greeks.insert("price".into(), self.calc_rainbow_price(payoff)?);
+        greeks.insert("delta1".into(), self.calc_rainbow_delta1(payoff)?);
+        greeks.insert("delta2".into(), self.calc_rainbow_delta2(payoff)?);
+        greeks.insert("gamma1".into(), self.calc_rainbow_gamma1(payoff)?);
+        greeks.insert("gamma2".into(), self.calc_rainbow_gamma2(payoff)?);
+        greeks.insert("cross_gamma".into(), self.calc_rainbow_cross_gamma(payoff)?);
+        greeks.insert("vega1".into(), self.calc_rainbow_vega1(payoff)?);
+        greeks.insert("vega2".into(), self.calc_rainbow_vega2(payoff)?);
+        greeks.insert(
+            "corr_sensitivity".into(),
+            self.calc_rainbow_corr_sensitivity(payoff)?,
+        );
+        Ok(greeks)
+    }
+}
+
+/// Quantities shared by the Stulz formulas.
+struct StulzTerms {
+    sigma_sqrt_t: f64,
+    rho1: f64,
+    rho2: f64,
+    d: f64,
+    y1: f64,
+    y2: f64,
+}
+
+fn stulz_terms(inputs: &TwoAssetInputs) -> Result<StulzTerms, String> {
+    let (sigma1, sigma2, rho) = (inputs.sigma1, inputs.sigma2, inputs.rho);
+    let sigma = (sigma1 * sigma1 + sigma2 * sigma2 - 2.0 * rho * sigma1 * sigma2).sqrt();
+    if sigma <= 0.0 || sigma1 <= 0.0 || sigma2 <= 0.0 {
+        return Err("Stulz formulas require non-degenerate volatilities".to_string());
+    }
+    let sqrt_t = inputs.t.sqrt();
+    let b1 = inputs.r - inputs.q1;
+    let b2 = inputs.r - inputs.q2;
+    Ok(StulzTerms {
+        sigma_sqrt_t: sigma * sqrt_t,
+        rho1: (sigma1 - rho * sigma2) / sigma,
+        rho2: (sigma2 - rho * sigma1) / sigma,
+        d: ((inputs.s1 / inputs.s2).ln() + (b1 - b2 + 0.5 * sigma * sigma) * inputs.t)
+            / (sigma * sqrt_t),
+        y1: ((inputs.s1 / inputs.k).ln() + (b1 + 0.5 * sigma1 * sigma1) * inputs.t)
+            / (sigma1 * sqrt_t),
+        y2: ((inputs.s2 / inputs.k).ln() + (b2 + 0.5 * sigma2 * sigma2) * inputs.t)
+            / (sigma2 * sqrt_t),
+    })
+}
+
+fn best_of(inputs: &TwoAssetInputs) -> Result<f64, String> {
+    let terms = stulz_terms(inputs)?;
+    let sqrt_t = inputs.t.sqrt();
+    let s1 = inputs.s1 * (-inputs.q1 * inputs.t).exp();
+    let s2 = inputs.s2 * (-inputs.q2 * inputs.t).exp();
+    let k = inputs.k * (-inputs.r * inputs.t).exp();
+    let call = s1 * bivariate_normal_cdf(terms.y1, terms.d, terms.rho1)
+        + s2 * bivariate_normal_cdf(terms.y2, -terms.d + terms.sigma_sqrt_t, terms.rho2)
+        - k * (1.0
+            - bivariate_normal_cdf(
+                -terms.y1 + inputs.sigma1 * sqrt_t,
+                -terms.y2 + inputs.sigma2 * sqrt_t,
+                inputs.rho,
+            ));
+    let price = match inputs.option_type {
+        OptionType::Call => call,
+        // Put-call parity against the zero-strike best-of, S2 + exchange(S1 over S2).
+        OptionType::Put => k - (s2 + exchange(inputs)?) + call,
+    };
+    Ok(price.max(0.0))
+}
+
+fn worst_of(inputs: &TwoAssetInputs) -> Result<f64, String> {
+    let terms = stulz_terms(inputs)?;
+    let sqrt_t = inputs.t.sqrt();
+    let s1 = inputs.s1 * (-inputs.q1 * inputs.t).exp();
+    let s2 = inputs.s2 * (-inputs.q2 * inputs.t).exp();
+    let k = inputs.k * (-inputs.r * inputs.t).exp();
+    let call = s1 * bivariate_normal_cdf(terms.y1, -terms.d, -terms.rho1)
+        + s2 * bivariate_normal_cdf(terms.y2, terms.d - terms.sigma_sqrt_t, -terms.rho2)
+        - k * bivariate_normal_cdf(
+            terms.y1 - inputs.sigma1 * sqrt_t,
+            terms.y2 - inputs.sigma2 * sqrt_t,
+            inputs.rho,
+        );
+    let price = match inputs.option_type {
+        OptionType::Call => call,
+        // Put-call parity against the zero-strike worst-of, S1 - exchange(S1 over S2).
+        OptionType::Put => k - (s1 - exchange(inputs)?) + call,
+    };
+    Ok(price.max(0.0))
+}
+
+/// Value of the option to receive asset 1 in exchange for asset 2.
+fn exchange(inputs: &TwoAssetInputs) -> Result<f64, String> {
+    margrabe(&TwoAssetInputs {
+        option_type: OptionType::Call,
+        ..inputs.clone()
+    })
+}
+
+fn correlation(inputs: &TwoAssetInputs, trigger_strike: f64) -> Result<f64, String> {
+    if trigger_strike <= 0.0 {
+        return Err("Trigger strike must be positive".to_string());
+    }
+    if inputs.sigma1 <= 0.0 || inputs.sigma2 <= 0.0 {
+        return Err("Correlation options require positive volatilities".to_string());
+    }
+    let sqrt_t = inputs.t.sqrt();
+    let y1 = ((inputs.s1 / trigger_strike).ln()
+        + (inputs.r - inputs.q1 - 0.5 * inputs.sigma1.powi(2)) * inputs.t)
+        / (inputs.sigma1 * sqrt_t);
+    let y2 = ((inputs.s2 / inputs.k).ln()
+        + (inputs.r - inputs.q2 - 0.5 * inputs.sigma2.powi(2)) * inputs.t)
+        / (inputs.sigma2 * sqrt_t);
+    let s2 = inputs.s2 * (-inputs.q2 * inputs.t).exp();
+    let k = inputs.k * (-inputs.r * inputs.t).exp();
+    let sigma2_sqrt_t = inputs.sigma2 * sqrt_t;
+    let rho = inputs.rho;
+    let price = match inputs.option_type {
+        OptionType::Call => {
+            s2 * bivariate_normal_cdf(y2 + sigma2_sqrt_t, y1 + rho * sigma2_sqrt_t, rho)
+                - k * bivariate_normal_cdf(y2, y1, rho)
+        }
+        OptionType::Put => {
+            k * bivariate_normal_cdf(-y2, -y1, rho)
+                - s2 * bivariate_normal_cdf(-y2 - sigma2_sqrt_t, -y1 - rho * sigma2_sqrt_t, rho)
+        }
+    };
+    Ok(price.max(0.0))
+}
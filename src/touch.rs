@@ -0,0 +1,205 @@
+use crate::{
+    lets_be_rational::normal_distribution::standard_normal_cdf,
+    numerical_greeks::{bump_inputs, Bump, NumericalGreeks},
+    numerics::survival_probability,
+    Inputs,
+};
+
+/// When a one-touch pays out.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Settlement {
+    /// As soon as the barrier is touched
+    AtHit,
+    /// At expiry, if the barrier was touched
+    AtExpiry,
+}
+
+/// The currency of a touch option's payout, for an FX rate quoted as domestic per unit of foreign.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PayoutCurrency {
+    /// The payout is an amount of domestic currency (cash-or-nothing)
+    Domestic,
+    /// The payout is an amount of foreign currency (asset-or-nothing), worth the spot rate per unit
+    Foreign,
+}
+
+/// The barrier event an American digital pays on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Touch {
+    /// Pays if the spot touches `barrier` before expiry
+    OneTouch {
+        barrier: f64,
+        settlement: Settlement,
+    },
+    /// Pays at expiry if the spot never touches `barrier`
+    NoTouch { barrier: f64 },
+    /// Pays at expiry if the spot stays strictly between `lower` and `upper`
+    DoubleNoTouch { lower: f64, upper: f64 },
+}
+
+/// A one-touch, no-touch or double-no-touch option under Black-Scholes-Merton dynamics,
+/// with barriers monitored continuously.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TouchInputs {
+    /// The barrier event and, for one-touches, when it pays
+    pub touch: Touch,
+    /// The amount paid, in units of `currency`
+    pub payout: f64,
+    /// The currency of the payout
+    pub currency: PayoutCurrency,
+    /// The market parameters s, r, q, t and sigma; for FX `r` is the domestic and `q` the foreign rate.
+    /// The option type and strike are ignored
+    pub underlying: Inputs,
+}
+
+impl TouchInputs {
+    /// Creates instance of the `TouchInputs` struct.
+    /// # Arguments
+    /// * `touch` - The barrier event.
+    /// * `payout` - The amount paid.
+    /// * `currency` - The currency of the payout.
+    /// * `underlying` - The market parameters.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, PayoutCurrency, Settlement, Touch, TouchInputs};
+    /// let market = Inputs::new(OptionType::Call, 1.35, 1.35, None, 0.03, 0.02, 0.5, Some(0.1));
+    /// let touch = Touch::OneTouch { barrier: 1.45, settlement: Settlement::AtExpiry };
+    /// let inputs = TouchInputs::new(touch, 1_000_000.0, PayoutCurrency::Domestic, market);
+    /// ```
+    /// # Returns
+    /// An instance of the `TouchInputs` struct.
+    pub fn new(touch: Touch, payout: f64, currency: PayoutCurrency, underlying: Inputs) -> Self {
+        Self {
+            touch,
+            payout,
+            currency,
+            underlying,
+        }
+    }
+
+    /// Value today of one unit of the payout currency received at expiry if the spot stays between the barriers.
+    fn survival_value(&self, lower: Option<f64>, upper: Option<f64>, sigma: f64) -> f64 {
+        let Inputs { s, r, q, t, .. } = self.underlying;
+        // The log drift under the domestic measure, or under the foreign measure for a foreign payout.
+        match self.currency {
+            PayoutCurrency::Domestic => {
+                (-r * t).exp()
+                    * survival_probability(s, lower, upper, r - q - 0.5 * sigma * sigma, sigma, t)
+            }
+            PayoutCurrency::Foreign => {
+                s * (-q * t).exp()
+                    * survival_probability(s, lower, upper, r - q + 0.5 * sigma * sigma, sigma, t)
+            }
+        }
+    }
+
+    /// Value today of one unit of the payout currency received when the spot first touches `barrier`.
+    fn hit_value(&self, barrier: f64, sigma: f64) -> f64 {
+        let Inputs { s, r, q, t, .. } = self.underlying;
+        // Reiner & Rubinstein (1991) cash-at-hit, with eta = 1 for a barrier below the spot.
+        let variance = sigma * sigma;
+        let mu = (r - q - 0.5 * variance) / variance;
+        let lambda = (mu * mu + 2.0 * r / variance).sqrt();
+        let eta = if barrier < s { 1.0 } else { -1.0 };
+        let width = sigma * t.sqrt();
+        let z = (barrier / s).ln() / width + lambda * width;
+        let ratio = barrier / s;
+        let cash = ratio.powf(mu + lambda) * standard_normal_cdf(eta * z)
+            + ratio.powf(mu - lambda) * standard_normal_cdf(eta * z - 2.0 * eta * lambda * width);
+        // A foreign unit received at the hit is worth `barrier` domestic units at that moment.
+        self.currency_value(barrier) * cash
+    }
+
+    /// Domestic value of one unit of the payout currency when the spot is at `spot`.
+    fn currency_value(&self, spot: f64) -> f64 {
+        match self.currency {
+            PayoutCurrency::Domestic => 1.0,
+            PayoutCurrency::Foreign => spot,
+        }
+    }
+}
+
+impl NumericalGreeks for TouchInputs {
+    /// Calculates the price of the touch option in domestic currency.
+    /// A single barrier above the spot is an up barrier and one below is a down barrier;
+    /// a barrier at the spot counts as touched today.
+    /// # Requires
+    /// s, r, q, t, sigma of the underlying, touch, payout, currency.
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, NumericalGreeks, OptionType, PayoutCurrency, Touch, TouchInputs};
+    /// let market = Inputs::new(OptionType::Call, 1.35, 1.35, None, 0.03, 0.02, 0.5, Some(0.1));
+    /// let touch = Touch::DoubleNoTouch { lower: 1.25, upper: 1.45 };
+    /// let price = TouchInputs::new(touch, 1.0, PayoutCurrency::Domestic, market).calc_price().unwrap();
+    /// assert!(price > 0.0 && price < 1.0);
+    /// ```
+    fn calc_price(&self) -> Result<f64, String> {
+        let Inputs { s, r, t, .. } = self.underlying;
+        let sigma = self
+            .underlying
+            .sigma
+            .ok_or("Expected Some(f64) for self.sigma, received None")?;
+        if s <= 0.0 || t <= 0.0 || sigma <= 0.0 {
+            return Err("Spot, time and volatility must be positive".to_string());
+        }
+        let one_sided = |barrier: f64| {
+            if barrier > s {
+                (None, Some(barrier))
+            } else {
+                (Some(barrier), None)
+            }
+        };
+        let value = match self.touch {
+            Touch::OneTouch { barrier, .. } | Touch::NoTouch { barrier } if barrier <= 0.0 => {
+                return Err("Barrier must be positive".to_string());
+            }
+            Touch::OneTouch {
+                barrier,
+                settlement: Settlement::AtHit,
+            } => {
+                if barrier == s {
+                    self.currency_value(s)
+                } else {
+                    self.hit_value(barrier, sigma)
+                }
+            }
+            Touch::OneTouch {
+                barrier,
+                settlement: Settlement::AtExpiry,
+            } => {
+                // Touched or not, the holder of a one-touch and a no-touch receives the payout at expiry.
+                let (lower, upper) = one_sided(barrier);
+                let paid = match self.currency {
+                    PayoutCurrency::Domestic => (-r * t).exp(),
+                    PayoutCurrency::Foreign => s * (-self.underlying.q * t).exp(),
+                };
+                paid - self.survival_value(lower, upper, sigma)
+            }
+            Touch::NoTouch { barrier } => {
+                let (lower, upper) = one_sided(barrier);
+                self.survival_value(lower, upper, sigma)
+            }
+            Touch::DoubleNoTouch { lower, upper } => {
+                if lower <= 0.0 || upper <= lower {
+                    return Err("Double no-touch requires 0 < lower < upper".to_string());
+                }
+                self.survival_value(Some(lower), Some(upper), sigma)
+            }
+        };
+        Ok(self.payout * value)
+    }
+
+    fn calc_bumped_price(&self, bump: Bump, size: f64) -> Result<f64, String> {
+        TouchInputs {
+            underlying: bump_inputs(&self.underlying, bump, size),
+            ..self.clone()
+        }
+        .calc_price()
+    }
+
+    fn spot(&self) -> f64 {
+        self.underlying.s
+    }
+}
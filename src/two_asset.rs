@@ -0,0 +1,421 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as fmtResult},
+};
+
+use num_traits::Float;
+
+use crate::{
+    lets_be_rational::normal_distribution::standard_normal_cdf,
+    numerics::{
+        bounded_difference, central_difference, mixed_difference, second_difference, ABSOLUTE_BUMP,
+        RELATIVE_BUMP,
+    },
+    OptionType,
+};
+
+/// The inputs to two-asset option models (exchange, spread and rainbow options).
+///
+/// Carry is expressed as a continuous yield per asset, so forwards and futures can be
+/// priced by setting `q1 = r` and/or `q2 = r` and passing the forward/futures price as the spot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwoAssetInputs {
+    /// The type of the option (call or put)
+    pub option_type: OptionType,
+    /// Price of the first asset
+    pub s1: f64,
+    /// Price of the second asset
+    pub s2: f64,
+    /// Strike price
+    pub k: f64,
+    /// Risk-free rate
+    pub r: f64,
+    /// Dividend yield of the first asset
+    pub q1: f64,
+    /// Dividend yield of the second asset
+    pub q2: f64,
+    /// Time to maturity in years
+    pub t: f64,
+    /// Volatility of the first asset
+    pub sigma1: f64,
+    /// Volatility of the second asset
+    pub sigma2: f64,
+    /// Correlation between the returns of the two assets
+    pub rho: f64,
+}
+
+impl TwoAssetInputs {
+    /// Creates instance of the `TwoAssetInputs` struct.
+    /// # Arguments
+    /// * `option_type` - The type of option to be priced.
+    /// * `s1` - The current price of the first asset.
+    /// * `s2` - The current price of the second asset.
+    /// * `k` - The strike price of the option.
+    /// * `r` - The risk-free interest rate.
+    /// * `q1` - The dividend yield of the first asset.
+    /// * `q2` - The dividend yield of the second asset.
+    /// * `t` - The time to maturity of the option in years.
+    /// * `sigma1` - The volatility of the first asset.
+    /// * `sigma2` - The volatility of the second asset.
+    /// * `rho` - The correlation between the two assets.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 110.0, 100.0, 5.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// ```
+    /// # Returns
+    /// An instance of the `TwoAssetInputs` struct.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        option_type: OptionType,
+        s1: f64,
+        s2: f64,
+        k: f64,
+        r: f64,
+        q1: f64,
+        q2: f64,
+        t: f64,
+        sigma1: f64,
+        sigma2: f64,
+        rho: f64,
+    ) -> Self {
+        Self {
+            option_type,
+            s1,
+            s2,
+            k,
+            r,
+            q1,
+            q2,
+            t,
+            sigma1,
+            sigma2,
+            rho,
+        }
+    }
+
+    /// Forward prices of the two assets.
+    pub(crate) fn forwards(&self) -> (f64, f64) {
+        (
+            self.s1 * ((self.r - self.q1) * self.t).exp(),
+            self.s2 * ((self.r - self.q2) * self.t).exp(),
+        )
+    }
+
+    /// Checks the inputs shared by every two-asset model.
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if self.t <= 0.0 {
+            return Err("Time to maturity is 0".to_string());
+        }
+        if self.s1 <= 0.0 || self.s2 <= 0.0 {
+            return Err("Asset prices must be positive".to_string());
+        }
+        if !(-1.0..=1.0).contains(&self.rho) {
+            return Err("Correlation must be between -1 and 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl Display for TwoAssetInputs {
+    fn fmt(&self, f: &mut Formatter) -> fmtResult {
+        writeln!(f, "Option type: {}", self.option_type)?;
+        writeln!(f, "Asset 1 price: {:.2}", self.s1)?;
+        writeln!(f, "Asset 2 price: {:.2}", self.s2)?;
+        writeln!(f, "Strike price: {:.2}", self.k)?;
+        writeln!(f, "Risk-free rate: {:.4}", self.r)?;
+        writeln!(f, "Asset 1 dividend yield: {:.4}", self.q1)?;
+        writeln!(f, "Asset 2 dividend yield: {:.4}", self.q2)?;
+        writeln!(f, "Time to maturity: {:.4}", self.t)?;
+        writeln!(f, "Asset 1 volatility: {:.4}", self.sigma1)?;
+        writeln!(f, "Asset 2 volatility: {:.4}", self.sigma2)?;
+        writeln!(f, "Correlation: {:.4}", self.rho)?;
+        Ok(())
+    }
+}
+
+/// The model used to price a spread option paying `max(S1 - S2 - K, 0)` (call) or `max(K + S2 - S1, 0)` (put).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SpreadModel {
+    /// Margrabe (1978) exchange option, exact for `K = 0`. The strike is ignored.
+    Margrabe,
+    /// Kirk (1995) approximation.
+    Kirk,
+    /// Bjerksund and Stensland (2011) refinement of Kirk's approximation.
+    BjerksundStensland,
+}
+
+pub trait SpreadPricing<T>
+where
+    T: Float,
+{
+    fn calc_spread_price(&self, model: SpreadModel) -> Result<T, String>;
+    fn calc_spread_delta1(&self, model: SpreadModel) -> Result<T, String>;
+    fn calc_spread_delta2(&self, model: SpreadModel) -> Result<T, String>;
+    fn calc_spread_gamma1(&self, model: SpreadModel) -> Result<T, String>;
+    fn calc_spread_gamma2(&self, model: SpreadModel) -> Result<T, String>;
+    fn calc_spread_cross_gamma(&self, model: SpreadModel) -> Result<T, String>;
+    fn calc_spread_corr_sensitivity(&self, model: SpreadModel) -> Result<T, String>;
+    fn calc_all_spread_greeks(&self, model: SpreadModel) -> Result<HashMap<String, T>, String>;
+}
+
+impl SpreadPricing<f64> for TwoAssetInputs {
+    /// Calculates the price of the spread option.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, SpreadModel, SpreadPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 110.0, 100.0, 5.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let price = inputs.calc_spread_price(SpreadModel::Kirk).unwrap();
+    /// ```
+    fn calc_spread_price(&self, model: SpreadModel) -> Result<f64, String> {
+        self.validate()?;
+        match model {
+            SpreadModel::Margrabe => margrabe(self),
+            SpreadModel::Kirk => kirk(self),
+            SpreadModel::BjerksundStensland => bjerksund_stensland(self),
+        }
+    }
+
+    /// Calculates the delta of the spread option with respect to the first asset.
+    /// Greeks of the spread models are computed by central finite differences.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the first-asset delta of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, SpreadModel, SpreadPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 110.0, 100.0, 5.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let delta1 = inputs.calc_spread_delta1(SpreadModel::Kirk).unwrap();
+    /// ```
+    fn calc_spread_delta1(&self, model: SpreadModel) -> Result<f64, String> {
+        central_difference(
+            |s1| with_spots(self, s1, self.s2).calc_spread_price(model),
+            self.s1,
+            self.s1 * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the delta of the spread option with respect to the second asset.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the second-asset delta of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, SpreadModel, SpreadPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 110.0, 100.0, 5.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let delta2 = inputs.calc_spread_delta2(SpreadModel::Kirk).unwrap();
+    /// ```
+    fn calc_spread_delta2(&self, model: SpreadModel) -> Result<f64, String> {
+        central_difference(
+            |s2| with_spots(self, self.s1, s2).calc_spread_price(model),
+            self.s2,
+            self.s2 * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the gamma of the spread option with respect to the first asset.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the first-asset gamma of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, SpreadModel, SpreadPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 110.0, 100.0, 5.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let gamma1 = inputs.calc_spread_gamma1(SpreadModel::Kirk).unwrap();
+    /// ```
+    fn calc_spread_gamma1(&self, model: SpreadModel) -> Result<f64, String> {
+        second_difference(
+            |s1| with_spots(self, s1, self.s2).calc_spread_price(model),
+            self.s1,
+            self.s1 * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the gamma of the spread option with respect to the second asset.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the second-asset gamma of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, SpreadModel, SpreadPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 110.0, 100.0, 5.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let gamma2 = inputs.calc_spread_gamma2(SpreadModel::Kirk).unwrap();
+    /// ```
+    fn calc_spread_gamma2(&self, model: SpreadModel) -> Result<f64, String> {
+        second_difference(
+            |s2| with_spots(self, self.s1, s2).calc_spread_price(model),
+            self.s2,
+            self.s2 * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the cross gamma (d²V/dS1dS2) of the spread option.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the cross gamma of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, SpreadModel, SpreadPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 110.0, 100.0, 5.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let cross_gamma = inputs.calc_spread_cross_gamma(SpreadModel::Kirk).unwrap();
+    /// ```
+    fn calc_spread_cross_gamma(&self, model: SpreadModel) -> Result<f64, String> {
+        mixed_difference(
+            |s1, s2| with_spots(self, s1, s2).calc_spread_price(model),
+            self.s1,
+            self.s2,
+            self.s1 * RELATIVE_BUMP,
+            self.s2 * RELATIVE_BUMP,
+        )
+    }
+
+    /// Calculates the sensitivity of the spread option to the correlation between the assets.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// f64 of the change in price for a unit change in correlation.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, SpreadModel, SpreadPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 110.0, 100.0, 5.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let corr_sensitivity = inputs.calc_spread_corr_sensitivity(SpreadModel::Kirk).unwrap();
+    /// ```
+    fn calc_spread_corr_sensitivity(&self, model: SpreadModel) -> Result<f64, String> {
+        bounded_difference(
+            |rho| {
+                TwoAssetInputs {
+                    rho,
+                    ..self.clone()
+                }
+                .calc_spread_price(model)
+            },
+            self.rho,
+            ABSOLUTE_BUMP,
+            -1.0,
+            1.0,
+        )
+    }
+
+    /// Calculates the price and all greeks of the spread option.
+    /// # Requires
+    /// s1, s2, k, r, q1, q2, t, sigma1, sigma2, rho.
+    /// # Returns
+    /// HashMap of type <String, f64> with the price, deltas, gammas, cross gamma and correlation sensitivity.
+    /// # Example
+    /// ```
+    /// use blackscholes::{OptionType, SpreadModel, SpreadPricing, TwoAssetInputs};
+    /// let inputs = TwoAssetInputs::new(OptionType::Call, 110.0, 100.0, 5.0, 0.05, 0.0, 0.0, 0.5, 0.3, 0.2, 0.5);
+    /// let greeks = inputs.calc_all_spread_greeks(SpreadModel::BjerksundStensland).unwrap();
+    /// ```
+    fn calc_all_spread_greeks(&self, model: SpreadModel) -> Result<HashMap<String, f64>, String> {
+        let mut greeks: HashMap<String, f64> = HashMap::with_capacity(7);
+        greeks.insert("price".into(), self.calc_spread_price(model)?);
+        greeks.insert("delta1".into(), self.calc_spread_delta1(model)?);
+        greeks.insert("delta2".into(), self.calc_spread_delta2(model)?);
+        greeks.insert("gamma1".into(), self.calc_spread_gamma1(model)?);
+        greeks.insert("gamma2".into(), self.calc_spread_gamma2(model)?);
+        greeks.insert("cross_gamma".into(), self.calc_spread_cross_gamma(model)?);
+        greeks.insert(
+            "corr_sensitivity".into(),
+            self.calc_spread_corr_sensitivity(model)?,
+        );
+        Ok(greeks)
+    }
+}
+
+pub(crate) fn with_spots(inputs: &TwoAssetInputs, s1: f64, s2: f64) -> TwoAssetInputs {
+    TwoAssetInputs {
+        s1,
+        s2,
+        ..inputs.clone()
+    }
+}
+
+/// Margrabe (1978) price of the option to exchange asset 2 for asset 1 (call) or asset 1 for asset 2 (put).
+pub(crate) fn margrabe(inputs: &TwoAssetInputs) -> Result<f64, String> {
+    let sigma = (inputs.sigma1.powi(2) + inputs.sigma2.powi(2)
+        - 2.0 * inputs.rho * inputs.sigma1 * inputs.sigma2)
+        .sqrt();
+    let (f1, f2) = inputs.forwards();
+    let df = (-inputs.r * inputs.t).exp();
+    let intrinsic = (inputs.option_type * (f1 - f2)).max(0.0);
+    if sigma <= 0.0 {
+        return Ok(df * intrinsic);
+    }
+    let sigma_sqrt_t = sigma * inputs.t.sqrt();
+    let d1 = (f1 / f2).ln() / sigma_sqrt_t + 0.5 * sigma_sqrt_t;
+    let d2 = d1 - sigma_sqrt_t;
+    let price = match inputs.option_type {
+        OptionType::Call => f1 * standard_normal_cdf(d1) - f2 * standard_normal_cdf(d2),
+        OptionType::Put => f2 * standard_normal_cdf(-d2) - f1 * standard_normal_cdf(-d1),
+    };
+    Ok(df * price.max(0.0))
+}
+
+/// Kirk (1995) approximation of the spread option price.
+fn kirk(inputs: &TwoAssetInputs) -> Result<f64, String> {
+    let (f1, f2) = inputs.forwards();
+    let a = f2 + inputs.k;
+    if a <= 0.0 {
+        return Err("Kirk's approximation requires F2 + K > 0".to_string());
+    }
+    let b = f2 / a;
+    let sigma = (inputs.sigma1.powi(2) + (b * inputs.sigma2).powi(2)
+        - 2.0 * inputs.rho * inputs.sigma1 * inputs.sigma2 * b)
+        .sqrt();
+    let df = (-inputs.r * inputs.t).exp();
+    if sigma <= 0.0 {
+        return Ok(df * (inputs.option_type * (f1 - a)).max(0.0));
+    }
+    let sigma_sqrt_t = sigma * inputs.t.sqrt();
+    let ratio = f1 / a;
+    let d1 = ratio.ln() / sigma_sqrt_t + 0.5 * sigma_sqrt_t;
+    let d2 = d1 - sigma_sqrt_t;
+    let price = match inputs.option_type {
+        OptionType::Call => ratio * standard_normal_cdf(d1) - standard_normal_cdf(d2),
+        OptionType::Put => standard_normal_cdf(-d2) - ratio * standard_normal_cdf(-d1),
+    };
+    Ok(df * a * price.max(0.0))
+}
+
+/// Bjerksund and Stensland (2011) closed-form spread option price.
+fn bjerksund_stensland(inputs: &TwoAssetInputs) -> Result<f64, String> {
+    let (f1, f2) = inputs.forwards();
+    let a = f2 + inputs.k;
+    if a <= 0.0 {
+        return Err("Bjerksund-Stensland requires F2 + K > 0".to_string());
+    }
+    let b = f2 / a;
+    let (s1, s2, rho) = (inputs.sigma1, inputs.sigma2, inputs.rho);
+    let sigma = (s1 * s1 - 2.0 * b * rho * s1 * s2 + b * b * s2 * s2).sqrt();
+    let df = (-inputs.r * inputs.t).exp();
+    let forward_value = f1 - f2 - inputs.k;
+    if sigma <= 0.0 {
+        return Ok(df * (inputs.option_type * forward_value).max(0.0));
+    }
+    let sigma_sqrt_t = sigma * inputs.t.sqrt();
+    let ln = (f1 / a).ln();
+    let t = inputs.t;
+    let d1 = (ln + (0.5 * s1 * s1 - b * rho * s1 * s2 + 0.5 * b * b * s2 * s2) * t) / sigma_sqrt_t;
+    let d2 =
+        (ln + (-0.5 * s1 * s1 + rho * s1 * s2 + (0.5 * b * b - b) * s2 * s2) * t) / sigma_sqrt_t;
+    let d3 = (ln + (-0.5 * s1 * s1 + 0.5 * b * b * s2 * s2) * t) / sigma_sqrt_t;
+    let call = f1 * standard_normal_cdf(d1)
+        - f2 * standard_normal_cdf(d2)
+        - inputs.k * standard_normal_cdf(d3);
+    // Puts follow from put-call parity on the forward spread.
+    let price = match inputs.option_type {
+        OptionType::Call => call,
+        OptionType::Put => call - forward_value,
+    };
+    Ok(df * price.max(0.0))
+}
@@ -0,0 +1,286 @@
+use crate::{
+    lets_be_rational::normal_distribution::inverse_normal_cdf, numerics::survival_probability,
+    Greeks, Inputs, OptionType, Pricing,
+};
+
+/// Delta of the risk-reversal and butterfly pivot strikes.
+const PIVOT_DELTA: f64 = 0.25;
+
+/// An FX volatility smile quoted as at-the-money volatility, 25-delta risk reversal and 25-delta butterfly,
+/// used to price first-generation exotics by vanna-volga adjustment of their Black-Scholes-Merton price.
+///
+/// The at-the-money strike is the delta-neutral straddle strike and the 25-delta strikes use
+/// premium-unadjusted spot deltas. `r` is the domestic and `q` the foreign interest rate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VannaVolgaSmile {
+    /// The market parameters s, r, q and t, with `sigma` the at-the-money volatility; the option type and strike are ignored
+    pub underlying: Inputs,
+    /// 25-delta risk reversal, the call volatility minus the put volatility
+    pub risk_reversal: f64,
+    /// 25-delta butterfly, the average of the call and put volatilities minus the at-the-money volatility
+    pub butterfly: f64,
+}
+
+impl VannaVolgaSmile {
+    /// Creates instance of the `VannaVolgaSmile` struct.
+    /// # Arguments
+    /// * `underlying` - The market parameters, with `sigma` the at-the-money volatility.
+    /// * `risk_reversal` - The 25-delta risk reversal.
+    /// * `butterfly` - The 25-delta butterfly.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, VannaVolgaSmile};
+    /// let market = Inputs::new(OptionType::Call, 1.35, 1.35, None, 0.03, 0.02, 0.5, Some(0.1));
+    /// let smile = VannaVolgaSmile::new(market, -0.012, 0.004);
+    /// ```
+    /// # Returns
+    /// An instance of the `VannaVolgaSmile` struct.
+    pub fn new(underlying: Inputs, risk_reversal: f64, butterfly: f64) -> Self {
+        Self {
+            underlying,
+            risk_reversal,
+            butterfly,
+        }
+    }
+
+    fn atm_vol(&self) -> Result<f64, String> {
+        let sigma = self
+            .underlying
+            .sigma
+            .ok_or("Expected Some(f64) for self.sigma, received None")?;
+        if sigma <= 0.0 || self.underlying.t <= 0.0 || self.underlying.s <= 0.0 {
+            return Err("Spot, time and at-the-money volatility must be positive".to_string());
+        }
+        Ok(sigma)
+    }
+
+    /// Black-Scholes-Merton call at strike `k` and volatility `sigma` in this market.
+    fn vanilla(&self, k: f64, sigma: f64) -> Inputs {
+        Inputs {
+            option_type: OptionType::Call,
+            k,
+            p: None,
+            sigma: Some(sigma),
+            ..self.underlying.clone()
+        }
+    }
+
+    /// Calculates the three pivot strikes and their volatilities, at which the vanna-volga smile is pinned
+    /// to the market: the 25-delta put, the at-the-money delta-neutral straddle and the 25-delta call.
+    /// # Requires
+    /// s, r, q, t, sigma of the underlying, risk_reversal, butterfly.
+    /// # Returns
+    /// [(f64, f64); 3] of (strike, volatility) pairs in increasing strike order.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, VannaVolgaSmile};
+    /// let market = Inputs::new(OptionType::Call, 1.35, 1.35, None, 0.03, 0.02, 0.5, Some(0.1));
+    /// let pivots = VannaVolgaSmile::new(market, -0.012, 0.004).calc_pivots().unwrap();
+    /// assert!(pivots[0].1 > pivots[2].1);
+    /// ```
+    pub fn calc_pivots(&self) -> Result<[(f64, f64); 3], String> {
+        let atm = self.atm_vol()?;
+        let Inputs { s, r, q, t, .. } = self.underlying;
+        let forward = s * ((r - q) * t).exp();
+        let call_vol = atm + self.butterfly + 0.5 * self.risk_reversal;
+        let put_vol = atm + self.butterfly - 0.5 * self.risk_reversal;
+        if call_vol <= 0.0 || put_vol <= 0.0 {
+            return Err("Quotes imply a non-positive wing volatility".to_string());
+        }
+        let scaled_delta = PIVOT_DELTA * (q * t).exp();
+        if scaled_delta >= 1.0 {
+            return Err(
+                "25-delta strikes do not exist for this foreign rate and maturity".to_string(),
+            );
+        }
+        // Strike at which N(d1) = scaled_delta (call) or N(-d1) = scaled_delta (put).
+        let d1 = inverse_normal_cdf(scaled_delta);
+        let strike = |sigma: f64, d1: f64| {
+            forward * (-d1 * sigma * t.sqrt() + 0.5 * sigma * sigma * t).exp()
+        };
+        Ok([
+            (strike(put_vol, -d1), put_vol),
+            (forward * (0.5 * atm * atm * t).exp(), atm),
+            (strike(call_vol, d1), call_vol),
+        ])
+    }
+
+    /// Calculates the smile-consistent volatility at strike `k` with the second-order approximation of
+    /// Castagna & Mercurio (2007), "The vanna-volga method for implied volatilities", which reproduces the
+    /// market volatility at each pivot strike.
+    /// # Arguments
+    /// * `k` - The strike.
+    /// # Returns
+    /// f64 of the implied volatility at `k`.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, VannaVolgaSmile};
+    /// let market = Inputs::new(OptionType::Call, 1.35, 1.35, None, 0.03, 0.02, 0.5, Some(0.1));
+    /// let vol = VannaVolgaSmile::new(market, -0.012, 0.004).calc_smile_vol(1.25).unwrap();
+    /// assert!(vol > 0.1);
+    /// ```
+    pub fn calc_smile_vol(&self, k: f64) -> Result<f64, String> {
+        if k <= 0.0 {
+            return Err("Strike must be positive".to_string());
+        }
+        let [(k1, s1), (k2, s2), (k3, s3)] = self.calc_pivots()?;
+        let Inputs { s, r, q, t, .. } = self.underlying;
+        let d1d2 = |x: f64| {
+            let d1 = ((s / x).ln() + (r - q + 0.5 * s2 * s2) * t) / (s2 * t.sqrt());
+            d1 * (d1 - s2 * t.sqrt())
+        };
+        let (y1, y2, y3) = log_weights(k, k1, k2, k3);
+        let first = y1 * s1 + y2 * s2 + y3 * s3 - s2;
+        let second = y1 * d1d2(k1) * (s1 - s2).powi(2) + y3 * d1d2(k3) * (s3 - s2).powi(2);
+        let d = d1d2(k);
+        if d.abs() < 1e-12 {
+            // The expansion degenerates to its first-order term where d1 d2 vanishes.
+            return Ok(s2 + first);
+        }
+        let discriminant = s2 * s2 + d * (2.0 * s2 * first + second);
+        if discriminant < 0.0 {
+            return Err(format!("Vanna-volga smile is undefined at strike {k}"));
+        }
+        Ok(s2 + (discriminant.sqrt() - s2) / d)
+    }
+
+    /// Calculates the vanna-volga price of a European option: the at-the-money Black-Scholes-Merton price
+    /// plus the smile cost of the pivot portfolio that matches its vega, vanna and volga.
+    /// # Arguments
+    /// * `option_type` - Call or put.
+    /// * `k` - The strike.
+    /// # Returns
+    /// f64 of the price of the option.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, VannaVolgaSmile};
+    /// let market = Inputs::new(OptionType::Call, 1.35, 1.35, None, 0.03, 0.02, 0.5, Some(0.1));
+    /// let smile = VannaVolgaSmile::new(market, -0.012, 0.004);
+    /// let price = smile.calc_vanilla_price(OptionType::Put, 1.3).unwrap();
+    /// ```
+    pub fn calc_vanilla_price(&self, option_type: OptionType, k: f64) -> Result<f64, String> {
+        let atm = self.atm_vol()?;
+        let inputs = Inputs {
+            option_type,
+            ..self.vanilla(k, atm)
+        };
+        self.calc_exotic_price(
+            inputs.calc_price()?,
+            inputs.calc_vega()?,
+            inputs.calc_vanna()?,
+            inputs.calc_vomma()?,
+            1.0,
+        )
+    }
+
+    /// Calculates the vanna-volga price of an exotic from its Black-Scholes-Merton price and volatility greeks
+    /// at the at-the-money volatility. The smile cost of the replicating pivot portfolio is scaled by `survival`,
+    /// typically the probability that a barrier product is still alive at expiry
+    /// (see [`calc_survival_probability`](Self::calc_survival_probability)); use 1 for the unadjusted method.
+    /// # Arguments
+    /// * `price` - The Black-Scholes-Merton price of the exotic.
+    /// * `vega` - Its vega, in the units of `calc_vega`.
+    /// * `vanna` - Its vanna, in the units of `calc_vanna`.
+    /// * `vomma` - Its vomma, in the units of `calc_vomma`.
+    /// * `survival` - Weight applied to the smile cost, between 0 and 1.
+    /// # Returns
+    /// f64 of the vanna-volga price of the exotic.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, VannaVolgaSmile};
+    /// let market = Inputs::new(OptionType::Call, 1.35, 1.35, None, 0.03, 0.02, 0.5, Some(0.1));
+    /// let smile = VannaVolgaSmile::new(market, -0.012, 0.004);
+    /// let survival = smile.calc_survival_probability(Some(1.25), Some(1.45)).unwrap();
+    /// let price = smile.calc_exotic_price(0.4, 0.1, -0.5, 2.0, survival).unwrap();
+    /// ```
+    pub fn calc_exotic_price(
+        &self,
+        price: f64,
+        vega: f64,
+        vanna: f64,
+        vomma: f64,
+        survival: f64,
+    ) -> Result<f64, String> {
+        if !(0.0..=1.0).contains(&survival) {
+            return Err("Survival probability must lie between 0 and 1".to_string());
+        }
+        let atm = self.atm_vol()?;
+        let pivots = self.calc_pivots()?;
+        let mut matrix = [[0.0; 3]; 3];
+        let mut costs = [0.0; 3];
+        for (j, &(k, vol)) in pivots.iter().enumerate() {
+            let flat = self.vanilla(k, atm);
+            matrix[0][j] = flat.calc_vega()?;
+            matrix[1][j] = flat.calc_vanna()?;
+            matrix[2][j] = flat.calc_vomma()?;
+            costs[j] = self.vanilla(k, vol).calc_price()? - flat.calc_price()?;
+        }
+        let weights = solve(matrix, [vega, vanna, vomma])?;
+        let smile_cost: f64 = weights.iter().zip(costs).map(|(w, c)| w * c).sum();
+        Ok(price + survival * smile_cost)
+    }
+
+    /// Calculates the risk-neutral probability that the spot stays strictly between the barriers until expiry
+    /// under Black-Scholes-Merton dynamics at the at-the-money volatility.
+    /// # Arguments
+    /// * `lower` - The lower barrier, if any.
+    /// * `upper` - The upper barrier, if any.
+    /// # Returns
+    /// f64 of the survival probability, zero if the spot is already outside the barriers.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, VannaVolgaSmile};
+    /// let market = Inputs::new(OptionType::Call, 1.35, 1.35, None, 0.03, 0.02, 0.5, Some(0.1));
+    /// let smile = VannaVolgaSmile::new(market, -0.012, 0.004);
+    /// let survival = smile.calc_survival_probability(None, Some(1.45)).unwrap();
+    /// assert!(survival > 0.0 && survival < 1.0);
+    /// ```
+    pub fn calc_survival_probability(
+        &self,
+        lower: Option<f64>,
+        upper: Option<f64>,
+    ) -> Result<f64, String> {
+        let sigma = self.atm_vol()?;
+        let Inputs { s, r, q, t, .. } = self.underlying;
+        Ok(survival_probability(
+            s,
+            lower,
+            upper,
+            r - q - 0.5 * sigma * sigma,
+            sigma,
+            t,
+        ))
+    }
+}
+
+/// Lagrange-style weights in log-strike that equal one at their own pivot and zero at the other two.
+fn log_weights(k: f64, k1: f64, k2: f64, k3: f64) -> (f64, f64, f64) {
+    let ln = |a: f64, b: f64| (a / b).ln();
+    (
+        ln(k2, k) * ln(k3, k) / (ln(k2, k1) * ln(k3, k1)),
+        ln(k, k1) * ln(k3, k) / (ln(k2, k1) * ln(k3, k2)),
+        ln(k, k1) * ln(k, k2) / (ln(k3, k1) * ln(k3, k2)),
+    )
+}
+
+/// Solves the 3x3 linear system `matrix x = rhs` by Cramer's rule.
+fn solve(matrix: [[f64; 3]; 3], rhs: [f64; 3]) -> Result<[f64; 3], String> {
+    let det = |m: [[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let denominator = det(matrix);
+    if denominator.abs() < f64::MIN_POSITIVE || !denominator.is_finite() {
+        return Err("Pivot options do not span vega, vanna and volga".to_string());
+    }
+    let mut solution = [0.0; 3];
+    for (j, value) in solution.iter_mut().enumerate() {
+        let mut replaced = matrix;
+        for (row, &b) in replaced.iter_mut().zip(&rhs) {
+            row[j] = b;
+        }
+        *value = det(replaced) / denominator;
+    }
+    Ok(solution)
+}
@@ -0,0 +1,350 @@
+use crate::{numerics::simpson, ImpliedVolatility, Inputs, OptionType, Pricing};
+
+/// Width of the extrapolated wings, in standard deviations of the log price.
+const WING_WIDTH: f64 = 12.0;
+/// Number of Simpson intervals used to integrate each extrapolated wing.
+const WING_INTERVALS: usize = 200;
+
+/// A strip of out-of-the-money options used to replicate a variance swap (Demeterfi, Derman, Kamal & Zou, 1999).
+/// Strikes below the forward carry put prices and strikes at or above it carry call prices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VarianceSwapInputs {
+    /// Stock price
+    pub s: f64,
+    /// Risk-free rate
+    pub r: f64,
+    /// Dividend yield
+    pub q: f64,
+    /// Time to maturity in years
+    pub t: f64,
+    /// Strictly increasing strikes of the strip
+    pub strikes: Vec<f64>,
+    /// Out-of-the-money option price at each strike
+    pub otm_prices: Vec<f64>,
+    /// Lower and upper barriers of a corridor variance swap, which only accrues variance while the
+    /// forward is inside `[lower, upper]`; `None` is a standard variance swap
+    pub corridor: Option<(f64, f64)>,
+}
+
+/// The fair variance implied by a strip, with the estimated corrections for the finite strike range and spacing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VarianceReplication {
+    /// Annualised variance replicated by the quoted strikes alone
+    pub strip_variance: f64,
+    /// Variance missing beyond the outermost strikes, estimated by extrapolating the wing implied volatilities flat
+    pub truncation_correction: f64,
+    /// Overstatement of the strip caused by the strike spacing, estimated by Richardson extrapolation
+    /// against a strip with every other strike
+    pub discretisation_error: f64,
+}
+
+impl VarianceSwapInputs {
+    /// Creates a variance swap strip from market quotes.
+    /// # Arguments
+    /// * `s` - The current price of the underlying asset.
+    /// * `r` - The risk-free interest rate.
+    /// * `q` - The dividend yield of the underlying asset.
+    /// * `t` - The time to maturity in years.
+    /// * `strikes` - The strictly increasing strikes of the strip.
+    /// * `otm_prices` - The out-of-the-money option price at each strike.
+    /// # Example
+    /// ```
+    /// use blackscholes::VarianceSwapInputs;
+    /// let strikes = vec![80.0, 90.0, 100.0, 110.0, 120.0];
+    /// let prices = vec![0.9, 2.6, 6.3, 4.1, 1.8];
+    /// let inputs = VarianceSwapInputs::new(100.0, 0.0, 0.0, 0.5, strikes, prices);
+    /// ```
+    /// # Returns
+    /// An instance of the `VarianceSwapInputs` struct.
+    pub fn new(s: f64, r: f64, q: f64, t: f64, strikes: Vec<f64>, otm_prices: Vec<f64>) -> Self {
+        Self {
+            s,
+            r,
+            q,
+            t,
+            strikes,
+            otm_prices,
+            corridor: None,
+        }
+    }
+
+    /// Creates a variance swap strip by pricing each out-of-the-money option with `calc_price` off a volatility smile.
+    /// # Arguments
+    /// * `s` - The current price of the underlying asset.
+    /// * `r` - The risk-free interest rate.
+    /// * `q` - The dividend yield of the underlying asset.
+    /// * `t` - The time to maturity in years.
+    /// * `strikes` - The strictly increasing strikes of the strip.
+    /// * `smile` - The implied volatility as a function of the strike.
+    /// # Example
+    /// ```
+    /// use blackscholes::VarianceSwapInputs;
+    /// let strikes: Vec<f64> = (50..=200).map(f64::from).collect();
+    /// let inputs = VarianceSwapInputs::from_smile(100.0, 0.05, 0.0, 1.0, strikes, |k| 0.2 - 0.1 * (k / 100.0).ln()).unwrap();
+    /// let variance = inputs.calc_fair_variance().unwrap().fair_variance();
+    /// ```
+    /// # Returns
+    /// An instance of the `VarianceSwapInputs` struct, or an error if an option cannot be priced.
+    pub fn from_smile<F>(
+        s: f64,
+        r: f64,
+        q: f64,
+        t: f64,
+        strikes: Vec<f64>,
+        smile: F,
+    ) -> Result<Self, String>
+    where
+        F: Fn(f64) -> f64,
+    {
+        let forward = s * ((r - q) * t).exp();
+        let otm_prices = strikes
+            .iter()
+            .map(|&k| {
+                let option_type = if k < forward {
+                    OptionType::Put
+                } else {
+                    OptionType::Call
+                };
+                Inputs::new(option_type, s, k, None, r, q, t, Some(smile(k))).calc_price()
+            })
+            .collect::<Result<Vec<f64>, String>>()?;
+        Ok(Self::new(s, r, q, t, strikes, otm_prices))
+    }
+
+    /// Forward price of the underlying at maturity.
+    pub fn forward(&self) -> f64 {
+        self.s * ((self.r - self.q) * self.t).exp()
+    }
+
+    /// Calculates the fair variance strike by replicating the log contract with the strip.
+    /// Between strikes the replicating payoff is the linear interpolation of the log payoff,
+    /// and beyond the outermost strikes it follows the tangent of the log payoff.
+    /// # Requires
+    /// s, r, q, t, strikes, otm_prices; at least three strikes.
+    /// # Returns
+    /// VarianceReplication with the strip variance and its estimated truncation and discretisation corrections.
+    /// # Example
+    /// ```
+    /// use blackscholes::VarianceSwapInputs;
+    /// let strikes: Vec<f64> = (10..=40).map(|k| 5.0 * k as f64).collect();
+    /// let inputs = VarianceSwapInputs::from_smile(100.0, 0.05, 0.0, 1.0, strikes, |_| 0.2).unwrap();
+    /// let replication = inputs.calc_fair_variance().unwrap();
+    /// assert!((replication.fair_volatility() - 0.2).abs() < 1e-3);
+    /// ```
+    pub fn calc_fair_variance(&self) -> Result<VarianceReplication, String> {
+        self.validate()?;
+        let all: Vec<usize> = (0..self.strikes.len()).collect();
+        let mut every_other: Vec<usize> = all.iter().copied().step_by(2).collect();
+        if every_other.last() != all.last() {
+            every_other.extend(all.last());
+        }
+        let strip_variance = self.strip_variance(&all);
+        // Interpolation error is quadratic in the spacing, so doubling it quadruples the error.
+        let discretisation_error = (self.strip_variance(&every_other) - strip_variance) / 3.0;
+        Ok(VarianceReplication {
+            strip_variance,
+            truncation_correction: self.truncation_correction()?,
+            discretisation_error,
+        })
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.t <= 0.0 {
+            return Err("Time to maturity must be positive".to_string());
+        }
+        if self.strikes.len() < 3 {
+            return Err("Replication requires at least three strikes".to_string());
+        }
+        if self.strikes.len() != self.otm_prices.len() {
+            return Err("Each strike requires exactly one option price".to_string());
+        }
+        if self.strikes[0] <= 0.0 || self.strikes.windows(2).any(|w| w[1] <= w[0]) {
+            return Err("Strikes must be positive and strictly increasing".to_string());
+        }
+        if self.otm_prices.iter().any(|&price| price < 0.0) {
+            return Err("Option prices must not be negative".to_string());
+        }
+        if let Some((lower, upper)) = self.corridor {
+            if lower < 0.0 || upper <= lower {
+                return Err("Corridor barriers must satisfy 0 <= lower < upper".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Corridor barriers, with a standard variance swap as the unbounded corridor.
+    fn barriers(&self) -> (f64, f64) {
+        self.corridor.unwrap_or((0.0, f64::INFINITY))
+    }
+
+    /// Payoff whose second derivative is `2 / K^2` inside the corridor, zero at the forward and flat to first order there.
+    fn payoff(&self, spot: f64) -> f64 {
+        let forward = self.forward();
+        let (lower, upper) = self.barriers();
+        if spot >= forward {
+            let (a, b) = (lower.max(forward), upper.min(spot));
+            if b > a {
+                return 2.0 * (spot * (1.0 / a - 1.0 / b) - (b / a).ln());
+            }
+        } else {
+            let (a, b) = (lower.max(spot), upper.min(forward));
+            if b > a {
+                return 2.0 * ((b / a).ln() - spot * (1.0 / a - 1.0 / b));
+            }
+        }
+        0.0
+    }
+
+    /// First derivative of [`Self::payoff`].
+    fn payoff_slope(&self, spot: f64) -> f64 {
+        let forward = self.forward();
+        let (lower, upper) = self.barriers();
+        if spot >= forward {
+            let (a, b) = (lower.max(forward), upper.min(spot));
+            if b > a {
+                return 2.0 * (1.0 / a - 1.0 / b);
+            }
+        } else {
+            let (a, b) = (lower.max(spot), upper.min(forward));
+            if b > a {
+                return -2.0 * (1.0 / a - 1.0 / b);
+            }
+        }
+        0.0
+    }
+
+    /// Out-of-the-money price at strike `i` converted to a put or call by put-call parity.
+    fn option_price(&self, i: usize, option_type: OptionType) -> f64 {
+        let k = self.strikes[i];
+        let forward = self.forward();
+        let quoted = if k < forward {
+            OptionType::Put
+        } else {
+            OptionType::Call
+        };
+        let price = self.otm_prices[i];
+        if quoted == option_type {
+            price
+        } else {
+            price + f64::from(option_type) * (-self.r * self.t).exp() * (forward - k)
+        }
+    }
+
+    /// Variance replicated by the payoff interpolating linearly through the strikes at `nodes`
+    /// and continuing along its tangents beyond the outermost ones.
+    fn strip_variance(&self, nodes: &[usize]) -> f64 {
+        let forward = self.forward();
+        let k: Vec<f64> = nodes.iter().map(|&i| self.strikes[i]).collect();
+        let g: Vec<f64> = k.iter().map(|&strike| self.payoff(strike)).collect();
+        let last = k.len() - 1;
+        // slopes[i] is the slope to the left of strike i and slopes[i + 1] the slope to its right.
+        let mut slopes = Vec::with_capacity(k.len() + 1);
+        slopes.push(self.payoff_slope(k[0]));
+        slopes.extend((0..last).map(|i| (g[i + 1] - g[i]) / (k[i + 1] - k[i])));
+        slopes.push(self.payoff_slope(k[last]));
+        // Expand around the last strike at or below the forward: puts up to it, calls above it.
+        let split = k.iter().rposition(|&strike| strike <= forward).unwrap_or(0);
+        let options: f64 = (0..k.len())
+            .map(|i| {
+                let option_type = if i <= split {
+                    OptionType::Put
+                } else {
+                    OptionType::Call
+                };
+                (slopes[i + 1] - slopes[i]) * self.option_price(nodes[i], option_type)
+            })
+            .sum();
+        (g[split] + slopes[split + 1] * (forward - k[split]) + (self.r * self.t).exp() * options)
+            / self.t
+    }
+
+    /// Variance contributed by strikes beyond the strip, pricing the wings at the outermost implied volatilities.
+    fn truncation_correction(&self) -> Result<f64, String> {
+        let (lower, upper) = self.barriers();
+        let last = self.strikes.len() - 1;
+        let mut correction = 0.0;
+        for (i, option_type) in [(0, OptionType::Put), (last, OptionType::Call)] {
+            let price = self.option_price(i, option_type);
+            if price <= 0.0 {
+                continue;
+            }
+            let k = self.strikes[i];
+            let mut inputs = Inputs::new(
+                option_type,
+                self.s,
+                k,
+                Some(price),
+                self.r,
+                self.q,
+                self.t,
+                None,
+            );
+            let Ok(sigma) = inputs.calc_rational_iv() else {
+                continue;
+            };
+            if !sigma.is_finite() || sigma <= 0.0 {
+                continue;
+            }
+            inputs.sigma = Some(sigma);
+            let width = WING_WIDTH * sigma * self.t.sqrt();
+            let (from, to) = match option_type {
+                OptionType::Put => (k.ln() - width, k.ln()),
+                OptionType::Call => (k.ln(), k.ln() + width),
+            };
+            let (from, to) = (from.max(lower.ln()), to.min(upper.ln()));
+            if to <= from {
+                continue;
+            }
+            // Integrate 2 / K^2 times the option price over log-strike, where dK = K dx.
+            correction += simpson(
+                |x| {
+                    let wing = Inputs {
+                        k: x.exp(),
+                        ..inputs.clone()
+                    };
+                    Ok(2.0 * wing.calc_price()? / wing.k)
+                },
+                from,
+                to,
+                WING_INTERVALS,
+            )?;
+        }
+        Ok((self.r * self.t).exp() * correction / self.t)
+    }
+}
+
+impl VarianceReplication {
+    /// Fair variance strike after adding the truncation correction and removing the discretisation error.
+    pub fn fair_variance(&self) -> f64 {
+        self.strip_variance + self.truncation_correction - self.discretisation_error
+    }
+
+    /// Square root of the fair variance strike.
+    pub fn fair_volatility(&self) -> f64 {
+        self.fair_variance().sqrt()
+    }
+
+    /// Convexity adjustment between the volatility and variance swap strikes (Brockhaus & Long, 2000).
+    /// # Arguments
+    /// * `variance_of_variance` - The variance of the realised variance over the life of the swap.
+    /// # Returns
+    /// f64 of the amount by which the volatility swap strike lies below the square root of the variance strike.
+    pub fn convexity_adjustment(&self, variance_of_variance: f64) -> f64 {
+        variance_of_variance / (8.0 * self.fair_variance().powf(1.5))
+    }
+
+    /// Fair volatility swap strike, approximated as the square root of the variance strike less the convexity adjustment.
+    /// # Arguments
+    /// * `variance_of_variance` - The variance of the realised variance over the life of the swap.
+    /// # Example
+    /// ```
+    /// use blackscholes::VarianceSwapInputs;
+    /// let strikes: Vec<f64> = (10..=40).map(|k| 5.0 * k as f64).collect();
+    /// let inputs = VarianceSwapInputs::from_smile(100.0, 0.05, 0.0, 1.0, strikes, |_| 0.2).unwrap();
+    /// let replication = inputs.calc_fair_variance().unwrap();
+    /// let volatility_strike = replication.volatility_swap_strike(0.0004);
+    /// ```
+    pub fn volatility_swap_strike(&self, variance_of_variance: f64) -> f64 {
+        self.fair_volatility() - self.convexity_adjustment(variance_of_variance)
+    }
+}
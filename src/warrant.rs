@@ -0,0 +1,312 @@
+use std::collections::HashMap;
+
+use num_traits::Float;
+
+use crate::{
+    numerics::{brent, expand_bracket},
+    Greeks, Inputs, OptionType, Pricing,
+};
+
+const FIXED_POINT_TOLERANCE: f64 = 1e-13;
+const IMPLIED_VOLATILITY_TOLERANCE: f64 = 1e-12;
+const MAX_ITERATIONS: usize = 200;
+
+/// A company-issued warrant, priced with the dilution adjustment of Galai & Schneller (1978).
+///
+/// Exercising the warrants issues new shares, so each warrant is worth `N / (N + M)` calls on the
+/// per-share firm value `s + (M / N) W`, where `W` is the warrant price itself. `sigma` is the volatility of
+/// the firm value, equity plus warrants.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WarrantInputs {
+    /// The share price, strike and market parameters; must be a call
+    pub underlying: Inputs,
+    /// Shares outstanding, N
+    pub shares: f64,
+    /// Warrants issued, M, each converting into one share
+    pub warrants: f64,
+}
+
+/// The solved fixed point and the sensitivity multiplier of the dilution adjustment.
+struct Dilution {
+    price: f64,
+    /// The call on the per-share firm value
+    call: Inputs,
+    /// N / (N + M)
+    dilution: f64,
+    /// 1 / (1 - M / (N + M) * call delta), converting call sensitivities into warrant sensitivities
+    multiplier: f64,
+}
+
+impl WarrantInputs {
+    /// Creates instance of the `WarrantInputs` struct.
+    /// # Arguments
+    /// * `underlying` - The share price, strike and market parameters of the warrant, as a call.
+    /// * `shares` - The number of shares outstanding.
+    /// * `warrants` - The number of warrants issued.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, WarrantInputs};
+    /// let underlying = Inputs::new(OptionType::Call, 50.0, 55.0, None, 0.04, 0.0, 3.0, Some(0.3));
+    /// let inputs = WarrantInputs::new(underlying, 1_000_000.0, 200_000.0);
+    /// ```
+    /// # Returns
+    /// An instance of the `WarrantInputs` struct.
+    pub fn new(underlying: Inputs, shares: f64, warrants: f64) -> Self {
+        Self {
+            underlying,
+            shares,
+            warrants,
+        }
+    }
+
+    fn solve(&self) -> Result<Dilution, String> {
+        if self.underlying.option_type != OptionType::Call {
+            return Err("Warrants must be calls".to_string());
+        }
+        if self.shares <= 0.0 || self.warrants < 0.0 {
+            return Err(
+                "Shares outstanding must be positive and warrants non-negative".to_string(),
+            );
+        }
+        let s = self.underlying.s;
+        if s <= 0.0 {
+            return Err("Spot price must be positive".to_string());
+        }
+        let dilution = self.shares / (self.shares + self.warrants);
+        let ratio = self.warrants / self.shares;
+        let call_at = |w: f64| Inputs {
+            s: s + ratio * w,
+            ..self.underlying.clone()
+        };
+        let error = |w: f64| Ok(dilution * call_at(w).calc_price()? - w);
+        // A diluted call is worth at most its share of the firm, which a negative dividend yield can grow
+        // beyond the spot, so widen [0, s] until it holds the warrant.
+        let (lower, upper) = expand_bracket(error, 0.0, s, 0.0)?;
+        let price = brent(error, lower, upper, FIXED_POINT_TOLERANCE, MAX_ITERATIONS)?;
+        let call = call_at(price);
+        let multiplier = 1.0 / (1.0 - dilution * ratio * call.calc_delta()?);
+        Ok(Dilution {
+            price,
+            call,
+            dilution,
+            multiplier,
+        })
+    }
+
+    /// Calculates the volatility at which the warrant is worth the observed price `underlying.p`.
+    /// # Requires
+    /// s, k, r, q, t, p of the underlying, shares, warrants.
+    /// # Returns
+    /// f64 of the firm value volatility implied by the warrant price.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, WarrantInputs};
+    /// let underlying = Inputs::new(OptionType::Call, 50.0, 55.0, Some(12.0), 0.04, 0.0, 3.0, None);
+    /// let iv = WarrantInputs::new(underlying, 1_000_000.0, 200_000.0).calc_warrant_iv().unwrap();
+    /// ```
+    pub fn calc_warrant_iv(&self) -> Result<f64, String> {
+        let p = self
+            .underlying
+            .p
+            .ok_or("inputs.p must contain Some(f64), found None".to_string())?;
+        let error = |sigma: f64| -> Result<f64, String> {
+            let inputs = WarrantInputs {
+                underlying: Inputs {
+                    sigma: Some(sigma),
+                    ..self.underlying.clone()
+                },
+                ..self.clone()
+            };
+            Ok(inputs.solve()?.price - p)
+        };
+        let (lower, upper) = expand_bracket(error, 0.1, 0.5, 1e-8)?;
+        brent(
+            error,
+            lower,
+            upper,
+            IMPLIED_VOLATILITY_TOLERANCE,
+            MAX_ITERATIONS,
+        )
+    }
+}
+
+pub trait WarrantPricing<T>
+where
+    T: Float,
+{
+    fn calc_warrant_price(&self) -> Result<T, String>;
+    fn calc_warrant_delta(&self) -> Result<T, String>;
+    fn calc_warrant_gamma(&self) -> Result<T, String>;
+    fn calc_warrant_vega(&self) -> Result<T, String>;
+    fn calc_warrant_theta(&self) -> Result<T, String>;
+    fn calc_warrant_rho(&self) -> Result<T, String>;
+    fn calc_warrant_epsilon(&self) -> Result<T, String>;
+    fn calc_all_warrant_greeks(&self) -> Result<HashMap<String, T>, String>;
+}
+
+impl WarrantPricing<f64> for WarrantInputs {
+    /// Calculates the dilution-adjusted price of the warrant by solving `W = N / (N + M) C(s + (M / N) W)`.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, shares, warrants.
+    /// # Returns
+    /// f64 of the price of the warrant.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, Pricing, WarrantInputs, WarrantPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 50.0, 55.0, None, 0.04, 0.0, 3.0, Some(0.3));
+    /// let inputs = WarrantInputs::new(underlying.clone(), 1_000_000.0, 200_000.0);
+    /// assert!(inputs.calc_warrant_price().unwrap() < underlying.calc_price().unwrap());
+    /// ```
+    fn calc_warrant_price(&self) -> Result<f64, String> {
+        Ok(self.solve()?.price)
+    }
+
+    /// Calculates the delta of the warrant with respect to the share price, including the feedback of the
+    /// warrant value on the firm value.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, shares, warrants.
+    /// # Returns
+    /// f64 of the delta of the warrant.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, WarrantInputs, WarrantPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 50.0, 55.0, None, 0.04, 0.0, 3.0, Some(0.3));
+    /// let delta = WarrantInputs::new(underlying, 1_000_000.0, 200_000.0)
+    ///     .calc_warrant_delta()
+    ///     .unwrap();
+    /// ```
+    fn calc_warrant_delta(&self) -> Result<f64, String> {
+        let solved = self.solve()?;
+        Ok(solved.dilution * solved.call.calc_delta()? * solved.multiplier)
+    }
+
+    /// Calculates the gamma of the warrant with respect to the share price.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, shares, warrants.
+    /// # Returns
+    /// f64 of the gamma of the warrant.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, WarrantInputs, WarrantPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 50.0, 55.0, None, 0.04, 0.0, 3.0, Some(0.3));
+    /// let gamma = WarrantInputs::new(underlying, 1_000_000.0, 200_000.0)
+    ///     .calc_warrant_gamma()
+    ///     .unwrap();
+    /// ```
+    fn calc_warrant_gamma(&self) -> Result<f64, String> {
+        let solved = self.solve()?;
+        let ratio = self.warrants / self.shares;
+        let delta = solved.dilution * solved.call.calc_delta()? * solved.multiplier;
+        let firm_delta = 1.0 + ratio * delta;
+        Ok(solved.dilution
+            * solved.call.calc_gamma()?
+            * firm_delta
+            * firm_delta
+            * solved.multiplier)
+    }
+
+    /// Calculates the vega of the warrant.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, shares, warrants.
+    /// # Returns
+    /// f64 of the change in price for a 1% change in volatility.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, WarrantInputs, WarrantPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 50.0, 55.0, None, 0.04, 0.0, 3.0, Some(0.3));
+    /// let vega = WarrantInputs::new(underlying, 1_000_000.0, 200_000.0)
+    ///     .calc_warrant_vega()
+    ///     .unwrap();
+    /// ```
+    fn calc_warrant_vega(&self) -> Result<f64, String> {
+        let solved = self.solve()?;
+        Ok(solved.dilution * solved.call.calc_vega()? * solved.multiplier)
+    }
+
+    /// Calculates the theta of the warrant.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, shares, warrants.
+    /// # Returns
+    /// f64 of the change in price per day.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, WarrantInputs, WarrantPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 50.0, 55.0, None, 0.04, 0.0, 3.0, Some(0.3));
+    /// let theta = WarrantInputs::new(underlying, 1_000_000.0, 200_000.0)
+    ///     .calc_warrant_theta()
+    ///     .unwrap();
+    /// ```
+    fn calc_warrant_theta(&self) -> Result<f64, String> {
+        let solved = self.solve()?;
+        Ok(solved.dilution * solved.call.calc_theta()? * solved.multiplier)
+    }
+
+    /// Calculates the rho of the warrant.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, shares, warrants.
+    /// # Returns
+    /// f64 of the change in price for a 1% change in the risk-free rate.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, WarrantInputs, WarrantPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 50.0, 55.0, None, 0.04, 0.0, 3.0, Some(0.3));
+    /// let rho = WarrantInputs::new(underlying, 1_000_000.0, 200_000.0)
+    ///     .calc_warrant_rho()
+    ///     .unwrap();
+    /// ```
+    fn calc_warrant_rho(&self) -> Result<f64, String> {
+        let solved = self.solve()?;
+        Ok(solved.dilution * solved.call.calc_rho()? * solved.multiplier)
+    }
+
+    /// Calculates the epsilon of the warrant.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, shares, warrants.
+    /// # Returns
+    /// f64 of the change in price for a unit change in the dividend yield.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, WarrantInputs, WarrantPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 50.0, 55.0, None, 0.04, 0.0, 3.0, Some(0.3));
+    /// let epsilon = WarrantInputs::new(underlying, 1_000_000.0, 200_000.0)
+    ///     .calc_warrant_epsilon()
+    ///     .unwrap();
+    /// ```
+    fn calc_warrant_epsilon(&self) -> Result<f64, String> {
+        let solved = self.solve()?;
+        Ok(solved.dilution * solved.call.calc_epsilon()? * solved.multiplier)
+    }
+
+    /// Calculates the price and all dilution-adjusted greeks of the warrant.
+    /// # Requires
+    /// s, k, r, q, t, sigma of the underlying, shares, warrants.
+    /// # Returns
+    /// HashMap of type <String, f64> with the price, delta, gamma, vega, theta, rho and epsilon.
+    /// # Example
+    /// ```
+    /// use blackscholes::{Inputs, OptionType, WarrantInputs, WarrantPricing};
+    /// let underlying = Inputs::new(OptionType::Call, 50.0, 55.0, None, 0.04, 0.0, 3.0, Some(0.3));
+    /// let greeks = WarrantInputs::new(underlying, 1_000_000.0, 200_000.0)
+    ///     .calc_all_warrant_greeks()
+    ///     .unwrap();
+    /// ```
+    fn calc_all_warrant_greeks(&self) -> Result<HashMap<String, f64>, String> {
+        let solved = self.solve()?;
+        let ratio = self.warrants / self.shares;
+        let scale = solved.dilution * solved.multiplier;
+        let delta = scale * solved.call.calc_delta()?;
+        let firm_delta = 1.0 + ratio * delta;
+        let mut greeks: HashMap<String, f64> = HashMap::with_capacity(7);
+        greeks.insert("price".into(), solved.price);
+        greeks.insert("delta".into(), delta);
+        greeks.insert(
+            "gamma".into(),
+            scale * solved.call.calc_gamma()? * firm_delta * firm_delta,
+        );
+        greeks.insert("vega".into(), scale * solved.call.calc_vega()?);
+        greeks.insert("theta".into(), scale * solved.call.calc_theta()?);
+        greeks.insert("rho".into(), scale * solved.call.calc_rho()?);
+        greeks.insert("epsilon".into(), scale * solved.call.calc_epsilon()?);
+        Ok(greeks)
+    }
+}
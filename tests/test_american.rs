@@ -0,0 +1,93 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{
+    AmericanInputs, ImpliedVolatility, Inputs, OptionType, Pricing, DEFAULT_TREE_STEPS,
+};
+
+#[test]
+fn tree_matches_known_american_put() {
+    // Hull's example: S = 50, K = 50, r = 10%, sigma = 40%, five months; the American put is worth about 4.28.
+    let underlying = Inputs::new(
+        OptionType::Put,
+        50.0,
+        50.0,
+        None,
+        0.1,
+        0.0,
+        5.0 / 12.0,
+        Some(0.4),
+    );
+    let price = AmericanInputs::new(underlying, 1000).calc_price().unwrap();
+    assert_approx_eq!(price, 4.28, 0.01);
+}
+
+#[test]
+fn de_americanised_volatility_is_unbiased() {
+    for (option_type, k, q) in [
+        (OptionType::Put, 90.0, 0.0),
+        (OptionType::Put, 100.0, 0.0),
+        (OptionType::Put, 120.0, 0.01),
+        (OptionType::Call, 90.0, 0.08),
+        (OptionType::Call, 110.0, 0.04),
+    ] {
+        let underlying = Inputs::new(option_type, 100.0, k, None, 0.06, q, 0.75, Some(0.3));
+        let mut inputs = AmericanInputs::new(underlying, DEFAULT_TREE_STEPS);
+        inputs.underlying.p = Some(inputs.calc_price().unwrap());
+        inputs.underlying.sigma = None;
+        let iv = inputs.calc_american_iv().unwrap();
+        assert_approx_eq!(iv.sigma, 0.3, 2e-3);
+        assert!(iv.early_exercise_premium > 0.0);
+        assert_approx_eq!(
+            iv.european_price + iv.early_exercise_premium,
+            inputs.underlying.p.unwrap(),
+            1e-12
+        );
+        // Treating the American premium as European overstates the volatility.
+        let naive = inputs.underlying.calc_rational_iv().unwrap();
+        assert!(naive > iv.sigma);
+    }
+}
+
+#[test]
+fn call_without_dividends_has_no_early_exercise_premium() {
+    let underlying = Inputs::new(
+        OptionType::Call,
+        100.0,
+        105.0,
+        None,
+        0.06,
+        0.0,
+        0.75,
+        Some(0.25),
+    );
+    let mut inputs = AmericanInputs::new(underlying, DEFAULT_TREE_STEPS);
+    inputs.underlying.p = Some(inputs.calc_price().unwrap());
+    inputs.underlying.sigma = None;
+    let iv = inputs.calc_american_iv().unwrap();
+    assert!(iv.early_exercise_premium.abs() < 1e-10);
+    assert_approx_eq!(iv.european_price, inputs.underlying.p.unwrap(), 1e-10);
+    let european = Inputs {
+        sigma: Some(iv.sigma),
+        ..inputs.underlying.clone()
+    };
+    assert_approx_eq!(european.calc_price().unwrap(), iv.european_price, 1e-10);
+}
+
+#[test]
+fn unattainable_premiums_are_rejected() {
+    let underlying = Inputs::new(OptionType::Put, 100.0, 120.0, None, 0.06, 0.0, 0.75, None);
+    let mut inputs = AmericanInputs::new(underlying, DEFAULT_TREE_STEPS);
+    inputs.underlying.p = Some(19.5);
+    assert!(inputs.calc_american_iv().is_err());
+    inputs.underlying.p = Some(120.0);
+    assert!(inputs.calc_american_iv().is_err());
+    inputs.underlying.p = None;
+    assert!(inputs.calc_american_iv().is_err());
+    let no_steps = AmericanInputs {
+        steps: 0,
+        underlying: Inputs {
+            p: Some(20.5),
+            ..inputs.underlying
+        },
+    };
+    assert!(no_steps.calc_american_iv().is_err());
+}
@@ -0,0 +1,181 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{
+    Bates, CarrMadan, CosMethod, Heston, ImpliedVolatility, Inputs, MertonInputs, MertonPricing,
+    OptionType, Pricing,
+};
+
+const INPUTS: Inputs = Inputs {
+    option_type: OptionType::Call,
+    s: 100.0,
+    k: 100.0,
+    p: None,
+    r: 0.0,
+    q: 0.0,
+    t: 1.0,
+    sigma: None,
+};
+
+const HESTON: Heston = Heston {
+    v0: 0.0175,
+    kappa: 1.5768,
+    theta: 0.0398,
+    xi: 0.5751,
+    rho: -0.5711,
+};
+
+#[test]
+fn heston_reference_price() {
+    // Fang & Oosterlee (2008), Heston reference value.
+    let price = CosMethod::default().calc_price(&HESTON, &INPUTS).unwrap();
+    assert_approx_eq!(price, 5.785155450, 1e-6);
+    let fft = CarrMadan::default()
+        .calc_prices(&HESTON, &INPUTS, &[100.0])
+        .unwrap();
+    assert_approx_eq!(fft[0], 5.785155450, 1e-5);
+}
+
+#[test]
+fn no_jumps_is_heston() {
+    let bates = Bates {
+        heston: HESTON,
+        lambda: 0.0,
+        jump_mean: -0.1,
+        jump_vol: 0.2,
+    };
+    for k in [80.0, 100.0, 120.0] {
+        let inputs = Inputs {
+            option_type: OptionType::Put,
+            k,
+            r: 0.03,
+            q: 0.01,
+            ..INPUTS
+        };
+        assert_approx_eq!(
+            CosMethod::default().calc_price(&bates, &inputs).unwrap(),
+            CosMethod::default().calc_price(&HESTON, &inputs).unwrap(),
+            1e-12
+        );
+    }
+}
+
+#[test]
+fn no_vol_of_vol_or_jumps_is_black_scholes() {
+    let bates = Bates {
+        heston: Heston {
+            v0: 0.04,
+            kappa: 2.0,
+            theta: 0.04,
+            xi: 0.0,
+            rho: -0.7,
+        },
+        lambda: 0.0,
+        jump_mean: 0.0,
+        jump_vol: 0.0,
+    };
+    for option_type in [OptionType::Call, OptionType::Put] {
+        for k in [80.0, 100.0, 120.0] {
+            let inputs = Inputs {
+                option_type,
+                k,
+                r: 0.05,
+                q: 0.02,
+                t: 0.5,
+                sigma: Some(0.2),
+                ..INPUTS
+            };
+            assert_approx_eq!(
+                CosMethod::default().calc_price(&bates, &inputs).unwrap(),
+                inputs.calc_price().unwrap(),
+                1e-8
+            );
+        }
+    }
+}
+
+#[test]
+fn no_vol_of_vol_is_merton() {
+    let bates = Bates {
+        heston: Heston {
+            v0: 0.04,
+            kappa: 1.0,
+            theta: 0.04,
+            xi: 0.0,
+            rho: 0.0,
+        },
+        lambda: 0.8,
+        jump_mean: -0.15,
+        jump_vol: 0.1,
+    };
+    for k in [80.0, 100.0, 120.0] {
+        let inputs = Inputs {
+            k,
+            r: 0.05,
+            q: 0.02,
+            t: 0.5,
+            sigma: Some(0.2),
+            ..INPUTS
+        };
+        let merton = MertonInputs::new(inputs.clone(), 0.8, -0.15, 0.1);
+        assert_approx_eq!(
+            CosMethod::default().calc_price(&bates, &inputs).unwrap(),
+            merton.calc_merton_price().unwrap(),
+            1e-8
+        );
+    }
+}
+
+#[test]
+fn calibration_recovers_smile() {
+    let model = Bates {
+        heston: Heston {
+            v0: 0.03,
+            kappa: 2.0,
+            theta: 0.05,
+            xi: 0.4,
+            rho: -0.6,
+        },
+        lambda: 0.3,
+        jump_mean: -0.12,
+        jump_vol: 0.1,
+    };
+    let market = Inputs {
+        r: 0.02,
+        q: 0.01,
+        ..INPUTS
+    };
+    let maturities = [0.25, 1.0];
+    let strikes = [80.0, 100.0, 120.0];
+    let vols: Vec<Vec<f64>> = maturities
+        .iter()
+        .map(|&t| {
+            strikes
+                .iter()
+                .map(|&k| {
+                    let option_type = if k < 100.0 {
+                        OptionType::Put
+                    } else {
+                        OptionType::Call
+                    };
+                    let mut inputs = Inputs {
+                        option_type,
+                        k,
+                        t,
+                        ..market.clone()
+                    };
+                    inputs.p = Some(CosMethod::default().calc_price(&model, &inputs).unwrap());
+                    inputs.calc_rational_iv().unwrap()
+                })
+                .collect()
+        })
+        .collect();
+    let calibration = Bates::calibrate(&market, &maturities, &strikes, &vols).unwrap();
+    assert!(calibration.converged);
+    assert!(calibration.rmse < 1e-3);
+    assert!(calibration.model.heston.rho < 0.0);
+}
+
+#[test]
+fn calibration_rejects_ragged_grid() {
+    let vols = vec![vec![0.2, 0.2], vec![0.2]];
+    assert!(Bates::calibrate(&INPUTS, &[0.5, 1.0], &[90.0, 110.0], &vols).is_err());
+}
@@ -0,0 +1,185 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{
+    lets_be_rational::{Black76Inputs, Exercise, Margining},
+    Greeks, Inputs, IvErrorKind, OptionType, Pricing,
+};
+
+const INPUTS: Black76Inputs = Black76Inputs {
+    option_type: OptionType::Call,
+    f: 100.0,
+    k: 95.0,
+    p: None,
+    r: 0.05,
+    t: 0.5,
+    sigma: Some(0.2),
+    margining: Margining::Premium,
+    exercise: Exercise::European,
+};
+
+fn spot_equivalent(inputs: &Black76Inputs, r: f64) -> Inputs {
+    // An option on a future is a Black-Scholes-Merton option with the dividend yield equal to the rate.
+    Inputs::new(
+        inputs.option_type,
+        inputs.f,
+        inputs.k,
+        None,
+        r,
+        r,
+        inputs.t,
+        inputs.sigma,
+    )
+}
+
+#[test]
+fn premium_margining_matches_black_scholes() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let inputs = Black76Inputs {
+            option_type,
+            ..INPUTS
+        };
+        let bsm = spot_equivalent(&inputs, 0.05);
+        assert_approx_eq!(
+            inputs.calc_price().unwrap(),
+            bsm.calc_price().unwrap(),
+            1e-12
+        );
+        assert_approx_eq!(
+            inputs.calc_delta().unwrap(),
+            bsm.calc_delta().unwrap(),
+            1e-12
+        );
+        assert_approx_eq!(
+            inputs.calc_gamma().unwrap(),
+            bsm.calc_gamma().unwrap(),
+            1e-12
+        );
+        assert_approx_eq!(inputs.calc_vega().unwrap(), bsm.calc_vega().unwrap(), 1e-12);
+        assert_approx_eq!(
+            inputs.calc_theta().unwrap(),
+            bsm.calc_theta().unwrap(),
+            1e-12
+        );
+    }
+}
+
+#[test]
+fn futures_style_is_undiscounted() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let premium = Black76Inputs {
+            option_type,
+            ..INPUTS
+        };
+        let futures_style = Black76Inputs {
+            margining: Margining::FuturesStyle,
+            ..premium.clone()
+        };
+        let discount = (-0.05_f64 * 0.5).exp();
+        let greeks = futures_style.calc_all_greeks().unwrap();
+        assert_approx_eq!(
+            greeks["price"] * discount,
+            premium.calc_price().unwrap(),
+            1e-12
+        );
+        assert_approx_eq!(
+            greeks["delta"] * discount,
+            premium.calc_delta().unwrap(),
+            1e-12
+        );
+        assert_eq!(greeks["rho"], 0.0);
+        // Asay prices equal Black-Scholes-Merton with zero rates on the futures price.
+        let zero_rate = spot_equivalent(&futures_style, 0.0);
+        assert_approx_eq!(greeks["price"], zero_rate.calc_price().unwrap(), 1e-12);
+        assert_approx_eq!(greeks["theta"], zero_rate.calc_theta().unwrap(), 1e-12);
+        // Put-call parity without discounting.
+        let other = Black76Inputs {
+            option_type: -option_type,
+            ..futures_style.clone()
+        };
+        let call_minus_put = option_type * (greeks["price"] - other.calc_price().unwrap());
+        assert_approx_eq!(call_minus_put, 100.0 - 95.0, 1e-12);
+    }
+}
+
+#[test]
+fn greeks_match_finite_differences() {
+    for margining in [Margining::Premium, Margining::FuturesStyle] {
+        let inputs = Black76Inputs {
+            option_type: OptionType::Put,
+            margining,
+            ..INPUTS
+        };
+        let price = |bump: &dyn Fn(&mut Black76Inputs)| {
+            let mut bumped = inputs.clone();
+            bump(&mut bumped);
+            bumped.calc_price().unwrap()
+        };
+        let h = 1e-5;
+        let rho = (price(&|i| i.r += h) - price(&|i| i.r -= h)) / (2.0 * h) / 100.0;
+        let theta = -(price(&|i| i.t += h) - price(&|i| i.t -= h)) / (2.0 * h) / 365.25;
+        assert_approx_eq!(inputs.calc_rho().unwrap(), rho, 1e-8);
+        assert_approx_eq!(inputs.calc_theta().unwrap(), theta, 1e-8);
+    }
+}
+
+#[test]
+fn implied_volatility_round_trips() {
+    for margining in [Margining::Premium, Margining::FuturesStyle] {
+        for k in [80.0, 95.0, 100.0, 120.0] {
+            let mut inputs = Black76Inputs {
+                k,
+                margining,
+                ..INPUTS
+            };
+            inputs.p = Some(inputs.calc_price().unwrap());
+            inputs.sigma = None;
+            assert_approx_eq!(inputs.calc_iv().unwrap(), 0.2, 1e-12);
+        }
+        // Calls are worth at most the discounted futures price and at least the discounted intrinsic value.
+        let above_maximum = Black76Inputs {
+            k: 95.0,
+            p: Some(150.0),
+            sigma: None,
+            margining,
+            ..INPUTS
+        };
+        let below_intrinsic = Black76Inputs {
+            p: Some(1.0),
+            ..above_maximum.clone()
+        };
+        assert_eq!(
+            above_maximum.calc_iv().unwrap_err(),
+            IvErrorKind::AboveMaximum.to_string()
+        );
+        assert_eq!(
+            below_intrinsic.calc_iv().unwrap_err(),
+            IvErrorKind::BelowIntrinsic.to_string()
+        );
+    }
+}
+
+#[test]
+fn american_exercise() {
+    let american = Black76Inputs {
+        margining: Margining::FuturesStyle,
+        exercise: Exercise::American,
+        option_type: OptionType::Put,
+        k: 130.0,
+        ..INPUTS
+    };
+    // Deep in the money the futures-style American option is still worth more than exercising.
+    let european = Black76Inputs {
+        exercise: Exercise::European,
+        ..american.clone()
+    };
+    assert_eq!(
+        american.calc_price().unwrap(),
+        european.calc_price().unwrap()
+    );
+    assert!(american.calc_price().unwrap() > 30.0);
+    let premium = Black76Inputs {
+        margining: Margining::Premium,
+        ..american
+    };
+    assert!(premium.calc_price().is_err());
+    assert!(premium.calc_delta().is_err());
+}
@@ -0,0 +1,127 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{CevInputs, Inputs, NumericalGreeks, OptionType, Pricing};
+
+const UNDERLYING: Inputs = Inputs {
+    option_type: OptionType::Call,
+    s: 100.0,
+    k: 95.0,
+    p: None,
+    r: 0.05,
+    q: 0.01,
+    t: 0.5,
+    sigma: Some(0.25),
+};
+
+fn cev(option_type: OptionType, k: f64, beta: f64) -> CevInputs {
+    CevInputs::from_local_vol(
+        Inputs {
+            option_type,
+            k,
+            ..UNDERLYING
+        },
+        beta,
+    )
+}
+
+#[test]
+fn unit_beta_is_black_scholes() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let bs = Inputs {
+            option_type,
+            ..UNDERLYING
+        };
+        let inputs = cev(option_type, 95.0, 1.0);
+        assert_approx_eq!(
+            inputs.calc_price().unwrap(),
+            bs.calc_price().unwrap(),
+            1e-12
+        );
+        // Close to one the chi-squared formulas converge to Black-Scholes-Merton.
+        for beta in [0.99, 1.01] {
+            let near = cev(option_type, 95.0, beta).calc_price().unwrap();
+            assert_approx_eq!(near, bs.calc_price().unwrap(), 5e-3);
+        }
+    }
+}
+
+#[test]
+fn put_call_parity() {
+    let Inputs { s, r, q, t, .. } = UNDERLYING;
+    for beta in [0.0, 0.5, 0.8, 1.3, 2.0] {
+        for k in [70.0, 95.0, 100.0, 130.0] {
+            let call = cev(OptionType::Call, k, beta).calc_price().unwrap();
+            let put = cev(OptionType::Put, k, beta).calc_price().unwrap();
+            let forward = s * (-q * t).exp() - k * (-r * t).exp();
+            assert_approx_eq!(call - put, forward, 1e-9);
+        }
+    }
+}
+
+#[test]
+fn black_iv_matches_hagan_approximation() {
+    // Hagan et al. (2002) expansion of the Black volatility for SABR without vol-of-vol, which is CEV.
+    let (s, t, beta, alpha) = (100.0, 0.5, 0.5, 2.5);
+    for k in [80.0, 90.0, 100.0, 110.0, 125.0] {
+        let option_type = if k < s {
+            OptionType::Put
+        } else {
+            OptionType::Call
+        };
+        let underlying = Inputs::new(option_type, s, k, None, 0.0, 0.0, t, Some(alpha));
+        let iv = CevInputs::new(underlying, beta).calc_black_iv().unwrap();
+        let one_minus: f64 = 1.0 - beta;
+        let log_moneyness: f64 = (s / k).ln();
+        let fk: f64 = (s * k).powf(one_minus);
+        let expected = alpha
+            / fk.sqrt()
+            / (1.0
+                + one_minus.powi(2) / 24.0 * log_moneyness.powi(2)
+                + one_minus.powi(4) / 1920.0 * log_moneyness.powi(4))
+            * (1.0 + one_minus.powi(2) / 24.0 * alpha * alpha / fk * t);
+        assert_approx_eq!(iv, expected, 2e-4);
+    }
+}
+
+#[test]
+fn skew_follows_beta() {
+    let ivs = |beta: f64| -> Vec<f64> {
+        [80.0, 90.0, 100.0, 110.0, 120.0]
+            .iter()
+            .map(|&k| cev(OptionType::Call, k, beta).calc_black_iv().unwrap())
+            .collect()
+    };
+    for pair in ivs(0.5).windows(2) {
+        assert!(pair[0] > pair[1]);
+    }
+    for pair in ivs(1.5).windows(2) {
+        assert!(pair[0] < pair[1]);
+    }
+}
+
+#[test]
+fn greeks_have_expected_signs() {
+    let call = cev(OptionType::Call, 95.0, 0.5);
+    let put = cev(OptionType::Put, 95.0, 0.5);
+    let call_delta = call.calc_delta().unwrap();
+    let put_delta = put.calc_delta().unwrap();
+    assert!(call_delta > 0.0 && call_delta < 1.0);
+    assert!(put_delta < 0.0 && put_delta > -1.0);
+    // Parity: the deltas differ by the dividend discount factor.
+    assert_approx_eq!(
+        call_delta - put_delta,
+        (-UNDERLYING.q * UNDERLYING.t).exp(),
+        1e-5
+    );
+    assert!(call.calc_gamma().unwrap() > 0.0);
+    assert!(call.calc_vega().unwrap() > 0.0);
+    assert!(call.calc_rho().unwrap() > 0.0);
+    assert!(put.calc_rho().unwrap() < 0.0);
+}
+
+#[test]
+fn rejects_invalid_inputs() {
+    let mut inputs = cev(OptionType::Call, 95.0, 0.5);
+    inputs.underlying.sigma = None;
+    assert!(inputs.calc_price().is_err());
+    assert!(cev(OptionType::Call, 0.0, 0.5).calc_price().is_err());
+}
@@ -0,0 +1,122 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{ChooserInputs, CompoundInputs, Inputs, NumericalGreeks, OptionType, Pricing};
+
+const UNDERLYING: Inputs = Inputs {
+    option_type: OptionType::Call,
+    s: 500.0,
+    k: 520.0,
+    p: None,
+    r: 0.08,
+    q: 0.03,
+    t: 0.5,
+    sigma: Some(0.35),
+};
+
+#[test]
+fn put_on_call_price() {
+    // Haug (2007), The Complete Guide to Option Pricing Formulas, Geske compound option example.
+    // The book's bivariate normal approximation is less accurate, hence the wider tolerance.
+    let inputs = CompoundInputs::new(OptionType::Put, 50.0, 0.25, UNDERLYING);
+    assert_approx_eq!(inputs.calc_price().unwrap(), 21.1965, 5e-4);
+}
+
+#[test]
+fn compound_put_call_parity() {
+    for underlying_type in [OptionType::Call, OptionType::Put] {
+        let underlying = Inputs {
+            option_type: underlying_type,
+            ..UNDERLYING
+        };
+        let call = CompoundInputs::new(OptionType::Call, 50.0, 0.25, underlying.clone());
+        let put = CompoundInputs::new(OptionType::Put, 50.0, 0.25, underlying.clone());
+        let forward_value = underlying.calc_price().unwrap() - 50.0 * (-0.08_f64 * 0.25).exp();
+        assert_approx_eq!(
+            call.calc_price().unwrap() - put.calc_price().unwrap(),
+            forward_value,
+            1e-8
+        );
+    }
+}
+
+#[test]
+fn simple_chooser_price() {
+    // Haug (2007) simple chooser example.
+    let inputs = Inputs::new(
+        OptionType::Call,
+        50.0,
+        50.0,
+        None,
+        0.08,
+        0.0,
+        0.5,
+        Some(0.25),
+    );
+    let chooser = ChooserInputs::simple(&inputs, 0.25);
+    assert_approx_eq!(chooser.calc_price().unwrap(), 6.1071, 1e-4);
+}
+
+#[test]
+fn complex_chooser_price() {
+    // Haug (2007) complex chooser example.
+    let inputs = Inputs::new(
+        OptionType::Call,
+        50.0,
+        50.0,
+        None,
+        0.1,
+        0.05,
+        0.5,
+        Some(0.35),
+    );
+    let chooser = ChooserInputs::complex(&inputs, 0.25, 55.0, 0.5, 48.0, 0.5833);
+    assert_approx_eq!(chooser.calc_price().unwrap(), 6.0508, 1e-4);
+}
+
+#[test]
+fn complex_chooser_matches_simple_chooser_in_the_limit() {
+    let inputs = Inputs::new(
+        OptionType::Call,
+        50.0,
+        50.0,
+        None,
+        0.08,
+        0.02,
+        0.5,
+        Some(0.25),
+    );
+    let simple = ChooserInputs::simple(&inputs, 0.25).calc_price().unwrap();
+    let complex = ChooserInputs::complex(&inputs, 0.25, 50.0, 0.5, 50.0 + 1e-9, 0.5)
+        .calc_price()
+        .unwrap();
+    assert_approx_eq!(simple, complex, 1e-7);
+}
+
+#[test]
+fn chooser_rejects_choice_at_or_after_expiry() {
+    let inputs = Inputs::new(
+        OptionType::Call,
+        50.0,
+        50.0,
+        None,
+        0.08,
+        0.0,
+        0.5,
+        Some(0.25),
+    );
+    assert!(ChooserInputs::simple(&inputs, 0.5).calc_price().is_err());
+    assert!(ChooserInputs::simple(&inputs, 0.75).calc_price().is_err());
+    assert!(ChooserInputs::simple(&inputs, 0.0).calc_price().is_err());
+    assert!(ChooserInputs::complex(&inputs, 0.25, 55.0, 0.5, 48.0, 0.25)
+        .calc_price()
+        .is_err());
+}
+
+#[test]
+fn compound_greeks() {
+    let inputs = CompoundInputs::new(OptionType::Call, 50.0, 0.25, UNDERLYING);
+    let greeks = inputs.calc_all_greeks().unwrap();
+    assert!(greeks["delta"] > 0.0);
+    assert!(greeks["gamma"] > 0.0);
+    assert!(greeks["vega"] > 0.0);
+    assert!(greeks["theta"] < 0.0);
+}
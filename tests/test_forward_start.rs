@@ -0,0 +1,96 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{CliquetInputs, ForwardStartInputs, NumericalGreeks, OptionType};
+
+const INPUTS_FORWARD_START: ForwardStartInputs = ForwardStartInputs {
+    option_type: OptionType::Call,
+    s: 60.0,
+    alpha: 1.1,
+    r: 0.08,
+    q: 0.04,
+    t_start: 0.25,
+    t: 1.0,
+    sigma: 0.3,
+};
+
+#[test]
+fn forward_start_price() {
+    // Haug (2007), The Complete Guide to Option Pricing Formulas, forward start option example.
+    assert_approx_eq!(INPUTS_FORWARD_START.calc_price().unwrap(), 4.4064, 1e-4);
+}
+
+#[test]
+fn forward_start_delta_is_price_over_spot() {
+    // The price is linear in the spot.
+    let greeks = INPUTS_FORWARD_START.calc_all_greeks().unwrap();
+    assert_approx_eq!(greeks["delta"], greeks["price"] / 60.0, 1e-8);
+    assert_approx_eq!(greeks["gamma"], 0.0, 1e-6);
+    assert!(greeks["vega"] > 0.0);
+}
+
+#[test]
+fn forward_start_rejects_invalid_inputs() {
+    for alpha in [0.0, -1.1] {
+        let inputs = ForwardStartInputs {
+            alpha,
+            ..INPUTS_FORWARD_START
+        };
+        assert!(inputs.calc_price().is_err());
+    }
+    let expired = ForwardStartInputs {
+        t: 0.25,
+        ..INPUTS_FORWARD_START
+    };
+    assert!(expired.calc_price().is_err());
+}
+
+#[test]
+fn cliquet_closed_form_matches_monte_carlo() {
+    let mut cliquet = CliquetInputs::new(0.03, 0.01, 0.2, vec![0.25, 0.5, 0.75, 1.0]);
+    cliquet.local_floor = Some(-0.02);
+    cliquet.local_cap = Some(0.04);
+    let closed_form = cliquet.calc_price().unwrap();
+
+    // A global floor that never binds forces the Monte Carlo path.
+    cliquet.global_floor = Some(-1.0);
+    let monte_carlo = cliquet.calc_price().unwrap();
+    assert_approx_eq!(closed_form, monte_carlo, 5e-4);
+}
+
+#[test]
+fn cliquet_global_floor_adds_value() {
+    let mut cliquet = CliquetInputs::new(0.03, 0.01, 0.2, vec![0.25, 0.5, 0.75, 1.0]);
+    cliquet.local_cap = Some(0.04);
+    cliquet.global_floor = Some(-1.0);
+    let unfloored = cliquet.calc_price().unwrap();
+    cliquet.global_floor = Some(0.0);
+    let floored = cliquet.calc_price().unwrap();
+    assert!(floored > unfloored);
+    assert!(floored >= 0.0);
+}
+
+#[test]
+fn cliquet_rejects_unordered_resets() {
+    let cliquet = CliquetInputs::new(0.03, 0.01, 0.2, vec![0.5, 0.25]);
+    assert!(cliquet.calc_price().is_err());
+}
+
+#[test]
+fn cliquet_greeks_match_monte_carlo() {
+    let mut cliquet = CliquetInputs::new(0.03, 0.01, 0.2, vec![0.25, 0.5, 0.75, 1.0]);
+    cliquet.local_floor = Some(0.0);
+    cliquet.local_cap = Some(0.04);
+    let closed_form = cliquet.calc_all_greeks().unwrap();
+    // The payoff depends only on returns.
+    assert_eq!(closed_form["delta"], 0.0);
+    assert_eq!(closed_form["gamma"], 0.0);
+
+    cliquet.global_floor = Some(-1.0);
+    let monte_carlo = cliquet.calc_all_greeks().unwrap();
+    for greek in ["vega", "theta", "rho", "epsilon"] {
+        assert_approx_eq!(
+            monte_carlo[greek],
+            closed_form[greek],
+            0.03 * closed_form[greek].abs()
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{
+    BsmModel, CarrMadan, Cgmy, CharacteristicFunction, Complex64, CosMethod, Inputs, MertonInputs,
+    MertonPricing, NormalInverseGaussian, OptionType, Pricing, VarianceGamma,
+};
+
+const INPUTS: Inputs = Inputs {
+    option_type: OptionType::Call,
+    s: 100.0,
+    k: 100.0,
+    p: None,
+    r: 0.1,
+    q: 0.0,
+    t: 1.0,
+    sigma: None,
+};
+
+#[test]
+fn bsm_matches_calc_price() {
+    let model = BsmModel { sigma: 0.25 };
+    let strikes = [70.0, 90.0, 100.0, 110.0, 140.0];
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let market = Inputs {
+            option_type,
+            q: 0.02,
+            t: 0.5,
+            ..INPUTS
+        };
+        let fft = CarrMadan::default()
+            .calc_prices(&model, &market, &strikes)
+            .unwrap();
+        for (&k, fft) in strikes.iter().zip(fft) {
+            let inputs = Inputs {
+                k,
+                sigma: Some(0.25),
+                ..market.clone()
+            };
+            let expected = inputs.calc_price().unwrap();
+            let cos = CosMethod::default().calc_price(&model, &inputs).unwrap();
+            assert_approx_eq!(cos, expected, 1e-8);
+            assert_approx_eq!(fft, expected, 1e-6);
+        }
+    }
+}
+
+#[test]
+fn variance_gamma_reference_price() {
+    // Fang & Oosterlee (2008), variance gamma reference value.
+    let model = VarianceGamma {
+        sigma: 0.12,
+        nu: 0.2,
+        theta: -0.14,
+    };
+    let inputs = Inputs {
+        k: 90.0,
+        t: 0.1,
+        ..INPUTS
+    };
+    // The short-dated variance gamma density has a cusp, so the cosine series converges slowly.
+    let cos = CosMethod {
+        terms: 8192,
+        ..CosMethod::default()
+    };
+    assert_approx_eq!(cos.calc_price(&model, &inputs).unwrap(), 10.993703187, 1e-6);
+    let fft = CarrMadan::default()
+        .calc_prices(&model, &inputs, &[90.0])
+        .unwrap();
+    assert_approx_eq!(fft[0], 10.993703187, 1e-5);
+}
+
+#[test]
+fn cgmy_reference_prices() {
+    // Fang & Oosterlee (2008), CGMY reference values.
+    for (y, expected) in [
+        (0.5, 19.812948843),
+        (1.5, 49.790905469),
+        (1.98, 99.999905510),
+    ] {
+        let model = Cgmy {
+            c: 1.0,
+            g: 5.0,
+            m: 5.0,
+            y,
+        };
+        let price = CosMethod::default().calc_price(&model, &INPUTS).unwrap();
+        assert_approx_eq!(price, expected, 1e-8);
+    }
+}
+
+#[test]
+fn engines_agree_for_nig() {
+    let model = NormalInverseGaussian {
+        alpha: 15.0,
+        beta: -5.0,
+        delta: 0.5,
+    };
+    let strikes = [80.0, 95.0, 100.0, 105.0, 120.0];
+    let market = Inputs {
+        option_type: OptionType::Put,
+        r: 0.03,
+        t: 0.5,
+        ..INPUTS
+    };
+    let fft = CarrMadan::default()
+        .calc_prices(&model, &market, &strikes)
+        .unwrap();
+    for (&k, fft) in strikes.iter().zip(fft) {
+        let inputs = Inputs {
+            k,
+            ..market.clone()
+        };
+        let cos = CosMethod::default().calc_price(&model, &inputs).unwrap();
+        assert_approx_eq!(cos, fft, 1e-6);
+    }
+}
+
+/// A model defined only by its characteristic function, relying on the default cumulants.
+struct MertonJumps {
+    sigma: f64,
+    lambda: f64,
+    mu: f64,
+    delta: f64,
+}
+
+impl CharacteristicFunction for MertonJumps {
+    fn calc_characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        let psi = |u: Complex64| {
+            -0.5 * self.sigma * self.sigma * u * u
+                + self.lambda
+                    * ((i * u * self.mu - 0.5 * self.delta * self.delta * u * u).exp() - 1.0)
+        };
+        (t * (psi(u) - i * u * psi(-i))).exp()
+    }
+}
+
+#[test]
+fn new_models_need_only_a_characteristic_function() {
+    let model = MertonJumps {
+        sigma: 0.2,
+        lambda: 1.0,
+        mu: -0.1,
+        delta: 0.15,
+    };
+    let inputs = Inputs {
+        k: 95.0,
+        r: 0.05,
+        q: 0.01,
+        t: 0.5,
+        ..INPUTS
+    };
+    let expected = MertonInputs::new(
+        Inputs {
+            sigma: Some(0.2),
+            ..inputs.clone()
+        },
+        1.0,
+        -0.1,
+        0.15,
+    )
+    .calc_merton_price()
+    .unwrap();
+    assert_approx_eq!(
+        CosMethod::default().calc_price(&model, &inputs).unwrap(),
+        expected,
+        1e-9
+    );
+}
+
+#[test]
+fn rejects_strikes_outside_the_grid() {
+    let model = BsmModel { sigma: 0.2 };
+    let (strikes, _) = CarrMadan::default()
+        .calc_strike_grid(&model, &INPUTS)
+        .unwrap();
+    let beyond = strikes.last().unwrap() * 2.0;
+    assert!(CarrMadan::default()
+        .calc_prices(&model, &INPUTS, &[beyond])
+        .is_err());
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use blackscholes::{Greeks, Inputs, OptionType};
+    use assert_approx_eq::assert_approx_eq;
+    use blackscholes::{Greeks, Inputs, OptionType, Pricing};
 
     #[test]
     fn test_calc_delta_zero_stock_price() {
@@ -106,4 +107,54 @@ mod tests {
         // assert
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_calc_dual_delta_is_strike_derivative() {
+        for option_type in [OptionType::Call, OptionType::Put] {
+            // arrange
+            let inputs = Inputs::new(option_type, 100.0, 95.0, None, 0.05, 0.02, 0.75, Some(0.25));
+            let h = 1e-4;
+            let up = Inputs {
+                k: 95.0 + h,
+                ..inputs.clone()
+            };
+            let down = Inputs {
+                k: 95.0 - h,
+                ..inputs.clone()
+            };
+
+            // act
+            let dual_delta = inputs.calc_dual_delta().unwrap();
+
+            // assert
+            let difference = (up.calc_price().unwrap() - down.calc_price().unwrap()) / (2.0 * h);
+            assert_approx_eq!(dual_delta, difference, 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_calc_dual_gamma_is_second_strike_derivative() {
+        for option_type in [OptionType::Call, OptionType::Put] {
+            // arrange
+            let inputs = Inputs::new(option_type, 100.0, 95.0, None, 0.05, 0.02, 0.75, Some(0.25));
+            let h = 1e-2;
+            let up = Inputs {
+                k: 95.0 + h,
+                ..inputs.clone()
+            };
+            let down = Inputs {
+                k: 95.0 - h,
+                ..inputs.clone()
+            };
+
+            // act
+            let dual_gamma = inputs.calc_dual_gamma().unwrap();
+
+            // assert
+            let difference = (up.calc_price().unwrap() - 2.0 * inputs.calc_price().unwrap()
+                + down.calc_price().unwrap())
+                / (h * h);
+            assert_approx_eq!(dual_gamma, difference, 1e-7);
+        }
+    }
 }
@@ -0,0 +1,163 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{CorrelationLevel, DispersionInputs, Inputs, OptionChain, OptionType, Pricing};
+
+const WEIGHTS: [f64; 3] = [0.5, 0.3, 0.2];
+const MONEYNESS: [f64; 7] = [0.8, 0.9, 0.95, 1.0, 1.05, 1.1, 1.2];
+
+/// Columns of a chain quoting out-of-the-money options over `MONEYNESS` at each expiry,
+/// with volatility `smile(expiry, ln(K / F))`.
+struct Columns {
+    option_types: Vec<OptionType>,
+    forwards: Vec<f64>,
+    strikes: Vec<f64>,
+    expiries: Vec<f64>,
+    premiums: Vec<f64>,
+}
+
+impl Columns {
+    fn new(forward: f64, expiries: &[f64], smile: impl Fn(f64, f64) -> f64) -> Self {
+        let mut columns = Columns {
+            option_types: vec![],
+            forwards: vec![],
+            strikes: vec![],
+            expiries: vec![],
+            premiums: vec![],
+        };
+        for &t in expiries {
+            for m in MONEYNESS {
+                let k = forward * m;
+                let option_type = if m < 1.0 {
+                    OptionType::Put
+                } else {
+                    OptionType::Call
+                };
+                // With no rates the price is undiscounted and the spot is the forward.
+                let sigma = smile(t, m.ln());
+                let inputs = Inputs::new(option_type, forward, k, None, 0.0, 0.0, t, Some(sigma));
+                columns.option_types.push(option_type);
+                columns.forwards.push(forward);
+                columns.strikes.push(k);
+                columns.expiries.push(t);
+                columns.premiums.push(inputs.calc_price().unwrap());
+            }
+        }
+        columns
+    }
+
+    fn chain(&self) -> OptionChain<'_> {
+        OptionChain::new(
+            &self.option_types,
+            &self.forwards,
+            &self.strikes,
+            &self.expiries,
+            &self.premiums,
+        )
+        .unwrap()
+    }
+}
+
+fn index_vol(vols: [f64; 3], correlation: f64) -> f64 {
+    let mut variance = 0.0;
+    for i in 0..3 {
+        for j in 0..3 {
+            let rho = if i == j { 1.0 } else { correlation };
+            variance += WEIGHTS[i] * WEIGHTS[j] * vols[i] * vols[j] * rho;
+        }
+    }
+    variance.sqrt()
+}
+
+#[test]
+fn recovers_correlation_per_expiry() {
+    let vols = [0.3, 0.25, 0.4];
+    let correlation = |t: f64| if t < 0.5 { 0.6 } else { 0.45 };
+    let expiries = [1.0, 0.25];
+    let index = Columns::new(4000.0, &expiries, |t, _| index_vol(vols, correlation(t)));
+    let stocks: Vec<Columns> = [150.0, 80.0, 320.0]
+        .iter()
+        .zip(vols)
+        .map(|(&forward, sigma)| Columns::new(forward, &expiries, |_, _| sigma))
+        .collect();
+    let chains: Vec<OptionChain> = stocks.iter().map(Columns::chain).collect();
+    let inputs = DispersionInputs::new(index.chain(), &chains, &WEIGHTS).unwrap();
+
+    for level in [
+        CorrelationLevel::AtTheMoney,
+        CorrelationLevel::Moneyness(0.92),
+        CorrelationLevel::Delta(0.45),
+    ] {
+        let result = inputs.calc_implied_correlation(level).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].expiry, 0.25);
+        assert_eq!(result[1].expiry, 1.0);
+        for row in &result {
+            assert_approx_eq!(row.correlation, correlation(row.expiry), 1e-9);
+            assert_approx_eq!(
+                row.index_iv,
+                index_vol(vols, correlation(row.expiry)),
+                1e-10
+            );
+            for (sigma, expected) in row.constituent_ivs.iter().zip(vols) {
+                assert_approx_eq!(sigma, expected, 1e-10);
+            }
+        }
+    }
+}
+
+#[test]
+fn skewed_smiles_are_read_at_the_strike_level() {
+    // Volatility linear in log-moneyness is interpolated exactly, so each level sees its own point on the smile.
+    let skews = [-0.3, -0.2, -0.4];
+    let vols = |m: f64| [0.3 + skews[0] * m, 0.25 + skews[1] * m, 0.4 + skews[2] * m];
+    let index = Columns::new(4000.0, &[0.5], |_, m| index_vol(vols(m), 0.5 + m));
+    let stocks: Vec<Columns> = (0..3)
+        .map(|i| Columns::new(100.0, &[0.5], move |_, m| vols(m)[i]))
+        .collect();
+    let chains: Vec<OptionChain> = stocks.iter().map(Columns::chain).collect();
+    let inputs = DispersionInputs::new(index.chain(), &chains, &WEIGHTS).unwrap();
+
+    for moneyness in [0.85, 1.0, 1.07] {
+        let m = f64::ln(moneyness);
+        let result = inputs
+            .calc_implied_correlation(CorrelationLevel::Moneyness(moneyness))
+            .unwrap();
+        // The index smile is not linear in m, so allow for interpolation error between quotes.
+        assert_approx_eq!(result[0].correlation, 0.5 + m, 5e-3);
+        for (sigma, expected) in result[0].constituent_ivs.iter().zip(vols(m)) {
+            assert_approx_eq!(sigma, expected, 1e-10);
+        }
+    }
+    let atm = inputs
+        .calc_implied_correlation(CorrelationLevel::AtTheMoney)
+        .unwrap();
+    assert_approx_eq!(atm[0].correlation, 0.5, 1e-9);
+}
+
+#[test]
+fn invalid_inputs_are_rejected() {
+    let index = Columns::new(4000.0, &[0.25, 1.0], |_, _| 0.2);
+    let short = Columns::new(100.0, &[0.25], |_, _| 0.3);
+    let both = Columns::new(100.0, &[0.25, 1.0], |_, _| 0.3);
+    let chains = [both.chain(), both.chain(), both.chain()];
+    assert!(DispersionInputs::new(index.chain(), &chains, &WEIGHTS[..2]).is_err());
+    assert!(DispersionInputs::new(index.chain(), &chains[..1], &WEIGHTS[..1]).is_err());
+    assert!(DispersionInputs::new(index.chain(), &chains, &[0.5, -0.3, 0.8]).is_err());
+
+    let inputs = DispersionInputs::new(index.chain(), &chains, &WEIGHTS).unwrap();
+    assert!(inputs
+        .calc_implied_correlation(CorrelationLevel::Moneyness(1.5))
+        .is_err());
+    assert!(inputs
+        .calc_implied_correlation(CorrelationLevel::Delta(0.01))
+        .is_err());
+    assert!(inputs
+        .calc_implied_correlation(CorrelationLevel::AtTheMoney)
+        .is_ok());
+
+    // A constituent missing the one-year expiry.
+    let missing = [both.chain(), short.chain(), both.chain()];
+    let inputs = DispersionInputs::new(index.chain(), &missing, &WEIGHTS).unwrap();
+    assert!(inputs
+        .calc_implied_correlation(CorrelationLevel::AtTheMoney)
+        .is_err());
+}
@@ -0,0 +1,134 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{Greeks, ImpliedParameter, ImpliedParameters, Inputs, OptionType, Pricing};
+
+const CALL: Inputs = Inputs {
+    option_type: OptionType::Call,
+    s: 100.0,
+    k: 95.0,
+    p: None,
+    r: 0.04,
+    q: 0.015,
+    t: 0.75,
+    sigma: Some(0.25),
+};
+
+#[test]
+fn recovers_each_pricing_input() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let inputs = Inputs {
+            option_type,
+            ..CALL
+        };
+        let p = Some(inputs.calc_price().unwrap());
+        // Each case moves the parameter away from the value that reprices the option.
+        let cases = [
+            (
+                ImpliedParameter::Spot,
+                50.0,
+                200.0,
+                CALL.s,
+                Inputs {
+                    s: 90.0,
+                    p,
+                    ..inputs
+                },
+            ),
+            (
+                ImpliedParameter::DividendYield,
+                -0.2,
+                0.2,
+                CALL.q,
+                Inputs {
+                    q: 0.05,
+                    p,
+                    ..inputs
+                },
+            ),
+            (
+                ImpliedParameter::Rate,
+                -0.2,
+                0.3,
+                CALL.r,
+                Inputs {
+                    r: 0.01,
+                    p,
+                    ..inputs
+                },
+            ),
+            (
+                ImpliedParameter::Strike,
+                50.0,
+                200.0,
+                CALL.k,
+                Inputs {
+                    k: 105.0,
+                    p,
+                    ..inputs
+                },
+            ),
+        ];
+        for (parameter, lower, upper, expected, quoted) in cases {
+            let result = quoted
+                .calc_implied_parameter(parameter, lower, upper)
+                .unwrap();
+            assert_approx_eq!(result.value, expected, 1e-9);
+            assert!(result.residual.abs() < 1e-10);
+            assert!(result.iterations > 0);
+        }
+    }
+}
+
+#[test]
+fn recovers_time_to_maturity() {
+    // A call on a non-dividend-paying stock gains value with time, so its price pins down the maturity.
+    let inputs = Inputs { q: 0.0, ..CALL };
+    let quoted = Inputs {
+        p: Some(inputs.calc_price().unwrap()),
+        t: 0.3,
+        ..inputs
+    };
+    let result = quoted
+        .calc_implied_parameter(ImpliedParameter::Time, 0.01, 5.0)
+        .unwrap();
+    assert_approx_eq!(result.value, 0.75, 1e-9);
+}
+
+#[test]
+fn finds_strike_for_target_delta() {
+    for (option_type, delta, lower, upper) in [
+        (OptionType::Call, 0.25, 100.0, 250.0),
+        (OptionType::Put, -0.25, 40.0, 100.0),
+    ] {
+        let inputs = Inputs {
+            option_type,
+            ..CALL
+        };
+        let result = inputs
+            .calc_implied_parameter(ImpliedParameter::StrikeForDelta(delta), lower, upper)
+            .unwrap();
+        let at_strike = Inputs {
+            k: result.value,
+            ..inputs
+        };
+        assert_approx_eq!(at_strike.calc_delta().unwrap(), delta, 1e-12);
+        assert!(result.residual.abs() < 1e-12);
+    }
+}
+
+#[test]
+fn unbracketed_targets_are_rejected() {
+    let inputs = Inputs {
+        p: Some(CALL.calc_price().unwrap()),
+        ..CALL
+    };
+    assert!(inputs
+        .calc_implied_parameter(ImpliedParameter::Spot, 150.0, 200.0)
+        .is_err());
+    assert!(inputs
+        .calc_implied_parameter(ImpliedParameter::Spot, 200.0, 50.0)
+        .is_err());
+    let missing = Inputs { p: None, ..inputs };
+    assert!(missing
+        .calc_implied_parameter(ImpliedParameter::Strike, 50.0, 200.0)
+        .is_err());
+}
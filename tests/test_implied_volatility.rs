@@ -1,3 +1,5 @@
+#![allow(clippy::unnecessary_literal_unwrap)]
+
 mod tests {
     use assert_approx_eq::assert_approx_eq;
     use blackscholes::{ImpliedVolatility, Inputs, OptionType, Pricing};
@@ -144,4 +146,40 @@ mod tests {
         println!("Put ATM: {}", iv);
         assert_approx_eq!(iv, sigma.unwrap(), TOLERANCE);
     }
+
+    #[test]
+    fn test_calc_iv_rejects_prices_without_implied_volatility() {
+        // arrange
+        let inputs_call_itm: Inputs = Inputs {
+            option_type: OptionType::Call,
+            s: 120.0,
+            k: 100.0,
+            p: Some(21.0),
+            r: 0.01,
+            q: 0.0,
+            t: 60.0 / 365.25,
+            sigma: None,
+        };
+        let expired = Inputs {
+            t: 0.0,
+            ..inputs_call_itm.clone()
+        };
+        let below_intrinsic = Inputs {
+            p: Some(20.0),
+            ..inputs_call_itm.clone()
+        };
+        let at_maximum = Inputs {
+            p: Some(120.0),
+            ..inputs_call_itm.clone()
+        };
+
+        // act
+        let iv = inputs_call_itm.calc_iv(TOLERANCE);
+
+        // assert
+        assert!(iv.is_ok());
+        assert!(expired.calc_iv(TOLERANCE).is_err());
+        assert!(below_intrinsic.calc_iv(TOLERANCE).is_err());
+        assert!(at_maximum.calc_iv(TOLERANCE).is_err());
+    }
 }
@@ -0,0 +1,170 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{
+    calc_rational_iv_batch, ImpliedVolatility, Inputs, IvErrorKind, OptionChain, OptionType,
+    Pricing,
+};
+
+#[test]
+fn batch_matches_scalar_exactly() {
+    // A grid of priced options across strikes, expiries and both option types.
+    let mut quotes = Vec::new();
+    for (i, &t) in [0.1, 0.25, 1.0, 3.0].iter().enumerate() {
+        for j in 0..25 {
+            let k = 70.0 + 2.5 * j as f64;
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let mut inputs = Inputs::new(
+                    option_type,
+                    100.0,
+                    k,
+                    None,
+                    0.03,
+                    0.01,
+                    t,
+                    Some(0.15 + 0.05 * i as f64 + 0.002 * j as f64),
+                );
+                inputs.p = Some(inputs.calc_price().unwrap());
+                inputs.sigma = None;
+                quotes.push(inputs);
+            }
+        }
+    }
+    let vols = calc_rational_iv_batch(&quotes, 1);
+    assert_eq!(vols.len(), quotes.len());
+    for (inputs, vol) in quotes.iter().zip(vols) {
+        match inputs.calc_rational_iv() {
+            Ok(expected) => assert_eq!(vol.unwrap().to_bits(), expected.to_bits()),
+            Err(_) => assert!(vol.is_err()),
+        }
+    }
+}
+
+#[test]
+fn parallel_matches_serial() {
+    // A grid of priced options across strikes, expiries and both option types.
+    let mut quotes = Vec::new();
+    for (i, &t) in [0.1, 0.25, 1.0, 3.0].iter().enumerate() {
+        for j in 0..25 {
+            let k = 70.0 + 2.5 * j as f64;
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let mut inputs = Inputs::new(
+                    option_type,
+                    100.0,
+                    k,
+                    None,
+                    0.03,
+                    0.01,
+                    t,
+                    Some(0.15 + 0.05 * i as f64 + 0.002 * j as f64),
+                );
+                inputs.p = Some(inputs.calc_price().unwrap());
+                inputs.sigma = None;
+                quotes.push(inputs);
+            }
+        }
+    }
+    let serial = calc_rational_iv_batch(&quotes, 1);
+    for threads in [0, 2, 3, 8, 1_000] {
+        assert_eq!(calc_rational_iv_batch(&quotes, threads), serial);
+    }
+}
+
+#[test]
+fn chain_matches_inputs() {
+    // A grid of priced options across strikes, expiries and both option types.
+    let mut quotes = Vec::new();
+    for (i, &t) in [0.1, 0.25, 1.0, 3.0].iter().enumerate() {
+        for j in 0..25 {
+            let k = 70.0 + 2.5 * j as f64;
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let mut inputs = Inputs::new(
+                    option_type,
+                    100.0,
+                    k,
+                    None,
+                    0.03,
+                    0.01,
+                    t,
+                    Some(0.15 + 0.05 * i as f64 + 0.002 * j as f64),
+                );
+                inputs.p = Some(inputs.calc_price().unwrap());
+                inputs.sigma = None;
+                quotes.push(inputs);
+            }
+        }
+    }
+    let option_types: Vec<_> = quotes.iter().map(|q| q.option_type).collect();
+    let forwards: Vec<_> = quotes
+        .iter()
+        .map(|q| q.s * ((q.r - q.q) * q.t).exp())
+        .collect();
+    let strikes: Vec<_> = quotes.iter().map(|q| q.k).collect();
+    let expiries: Vec<_> = quotes.iter().map(|q| q.t).collect();
+    let premiums: Vec<_> = quotes
+        .iter()
+        .map(|q| q.p.unwrap() * (q.r * q.t).exp())
+        .collect();
+    let chain = OptionChain::new(&option_types, &forwards, &strikes, &expiries, &premiums).unwrap();
+    assert_eq!(chain.len(), quotes.len());
+    let serial = chain.calc_rational_iv(1);
+    assert_eq!(chain.calc_rational_iv(4), serial);
+    for (inputs, vol) in quotes.iter().zip(serial) {
+        assert!((vol.unwrap() - inputs.calc_rational_iv().unwrap()).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn rows_report_why_they_failed() {
+    let chain = OptionChain::new(
+        &[OptionType::Call, OptionType::Put, OptionType::Call],
+        &[100.0, 100.0, 100.0],
+        &[90.0, 110.0, 100.0],
+        &[0.5, 0.5, 0.5],
+        &[5.0, 111.0, 4.0],
+    )
+    .unwrap();
+    let vols = chain.calc_rational_iv(2);
+    assert_eq!(vols[0], Err(IvErrorKind::BelowIntrinsic));
+    assert_eq!(vols[1], Err(IvErrorKind::AboveMaximum));
+    assert!(vols[2].is_ok());
+
+    let missing = Inputs::new(OptionType::Call, 100.0, 100.0, None, 0.05, 0.0, 0.5, None);
+    assert_eq!(
+        calc_rational_iv_batch(&[missing], 1),
+        vec![Err(IvErrorKind::MissingPrice)]
+    );
+}
+
+#[test]
+fn mismatched_columns_are_rejected() {
+    let chain = OptionChain::new(
+        &[OptionType::Call, OptionType::Put],
+        &[100.0, 100.0],
+        &[90.0],
+        &[0.5, 0.5],
+        &[12.0, 2.0],
+    );
+    assert!(chain.is_err());
+    assert!(OptionChain::new(&[], &[], &[], &[], &[])
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn deep_in_the_money_call_above_strike_has_implied_volatility() {
+    // A call's maximum value is the forward, so a price above the strike is attainable.
+    let mut inputs = Inputs::new(
+        OptionType::Call,
+        100.0,
+        50.0,
+        None,
+        0.0,
+        0.0,
+        2.0,
+        Some(0.8),
+    );
+    inputs.p = Some(inputs.calc_price().unwrap());
+    assert!(inputs.p.unwrap() > inputs.k);
+    inputs.sigma = None;
+    assert_approx_eq!(inputs.calc_rational_iv().unwrap(), 0.8, 1e-10);
+    assert_approx_eq!(calc_rational_iv_batch(&[inputs], 1)[0].unwrap(), 0.8, 1e-10);
+}
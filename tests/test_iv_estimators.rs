@@ -0,0 +1,120 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{ImpliedVolatility, Inputs, IvEstimator, OptionType, Pricing};
+
+const ESTIMATORS: [IvEstimator; 5] = [
+    IvEstimator::BrennerSubrahmanyam,
+    IvEstimator::CorradoMiller,
+    IvEstimator::ModifiedCorradoMiller,
+    IvEstimator::LiAtTheMoney,
+    IvEstimator::LiRational,
+];
+
+#[test]
+fn estimates_within_domain_are_within_five_percent() {
+    for estimator in ESTIMATORS {
+        let mut checked = 0;
+        for s in [25.0, 100.0, 2500.0] {
+            for t in [0.1, 0.5, 0.8, 1.0, 1.25, 2.0] {
+                for total_volatility in [0.1, 0.15, 0.3, 0.6, 1.0, 1.5] {
+                    for m in [-0.1, -0.02, 0.0, 0.02, 0.1] {
+                        for option_type in [OptionType::Call, OptionType::Put] {
+                            let sigma = total_volatility / f64::sqrt(t);
+                            // Strikes at log-moneyness m from the forward.
+                            let k = s * ((0.03 - 0.01) * t - m).exp();
+                            let mut inputs =
+                                Inputs::new(option_type, s, k, None, 0.03, 0.01, t, Some(sigma));
+                            inputs.p = Some(inputs.calc_price().unwrap());
+                            inputs.sigma = None;
+                            let estimate = estimator.estimate(&inputs).unwrap();
+                            if estimate.within_domain {
+                                checked += 1;
+                                assert!(
+                                    (estimate.sigma / sigma - 1.0).abs() <= 0.05,
+                                    "{estimator:?} at s {s}, t {t}, sigma {sigma}, m {m}: {}",
+                                    estimate.sigma
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        assert!(checked > 0, "{estimator:?} was never in its domain");
+    }
+}
+
+#[test]
+fn at_the_money_estimates() {
+    let k = 100.0 * (0.02_f64 * 0.5).exp();
+    let mut inputs = Inputs::new(OptionType::Call, 100.0, k, None, 0.03, 0.01, 0.5, Some(0.2));
+    inputs.p = Some(inputs.calc_price().unwrap());
+    inputs.sigma = None;
+    let brenner = IvEstimator::BrennerSubrahmanyam.estimate(&inputs).unwrap();
+    let corrado = IvEstimator::CorradoMiller.estimate(&inputs).unwrap();
+    let li = IvEstimator::LiAtTheMoney.estimate(&inputs).unwrap();
+    // Corrado-Miller reduces to Brenner-Subrahmanyam at the money, and Li's third-order root improves on both.
+    assert_approx_eq!(brenner.sigma, corrado.sigma, 1e-12);
+    assert!((li.sigma - 0.2).abs() < (brenner.sigma - 0.2).abs());
+    assert_approx_eq!(li.sigma, 0.2, 1e-5);
+}
+
+#[test]
+fn li_rational_is_accurate_far_from_the_money() {
+    for t in [0.1, 1.0, 5.0] {
+        for step in 0..=25 {
+            let total_volatility = 0.25 + 0.05 * step as f64;
+            for m in [-0.5, -0.35, -0.2, -0.05, 0.0, 0.05, 0.2, 0.35, 0.5] {
+                for option_type in [OptionType::Call, OptionType::Put] {
+                    let sigma = total_volatility / f64::sqrt(t);
+                    let k = 80.0 * ((0.03 - 0.01) * t - m).exp();
+                    let mut inputs =
+                        Inputs::new(option_type, 80.0, k, None, 0.03, 0.01, t, Some(sigma));
+                    inputs.p = Some(inputs.calc_price().unwrap());
+                    inputs.sigma = None;
+                    let estimate = IvEstimator::LiRational.estimate(&inputs).unwrap();
+                    assert!(
+                        (estimate.sigma / sigma - 1.0).abs() < 5e-3,
+                        "t {t}, sigma {sigma}, m {m}: {}",
+                        estimate.sigma
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn estimates_are_finite_outside_the_domain() {
+    // Far out of the money, where the Corrado-Miller discriminant is negative.
+    let k = 100.0 * (0.02_f64 * 0.25 + 0.3).exp();
+    let mut inputs = Inputs::new(
+        OptionType::Call,
+        100.0,
+        k,
+        None,
+        0.03,
+        0.01,
+        0.25,
+        Some(0.2),
+    );
+    inputs.p = Some(inputs.calc_price().unwrap());
+    inputs.sigma = None;
+    for estimator in ESTIMATORS {
+        let estimate = estimator.estimate(&inputs).unwrap();
+        assert!(estimate.sigma.is_finite());
+        assert!(!estimate.within_domain);
+    }
+    // calc_iv no longer fails when its starting estimate is unusable.
+    assert_approx_eq!(inputs.calc_iv(1e-12).unwrap(), 0.2, 1e-6);
+}
+
+#[test]
+fn unattainable_prices_are_rejected() {
+    let call = Inputs::new(OptionType::Call, 100.0, 90.0, None, 0.05, 0.0, 0.5, None);
+    for p in [None, Some(5.0), Some(100.0)] {
+        let inputs = Inputs { p, ..call.clone() };
+        for estimator in ESTIMATORS {
+            assert!(estimator.estimate(&inputs).is_err());
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{
+    Greeks, ImpliedVolatility, Inputs, IvMethod, IvOptions, IvStatus, IvTolerance, OptionType,
+    Pricing,
+};
+
+const METHODS: [IvMethod; 5] = [
+    IvMethod::Newton,
+    IvMethod::Halley,
+    IvMethod::Brent,
+    IvMethod::Bisection,
+    IvMethod::Rational,
+];
+
+fn options(method: IvMethod, tolerance: IvTolerance) -> IvOptions {
+    IvOptions {
+        method,
+        tolerance,
+        ..IvOptions::default()
+    }
+}
+
+#[test]
+fn every_method_recovers_volatility() {
+    for method in METHODS {
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for (k, t, sigma) in [(80.0, 0.5, 0.3), (100.0, 0.1, 0.15), (125.0, 2.0, 0.45)] {
+                let mut inputs =
+                    Inputs::new(option_type, 100.0, k, None, 0.04, 0.01, t, Some(sigma));
+                inputs.p = Some(inputs.calc_price().unwrap());
+                inputs.sigma = None;
+                let iv = inputs
+                    .calc_iv_with(&options(method, IvTolerance::Volatility(1e-12)))
+                    .unwrap();
+                assert_approx_eq!(iv, sigma, 1e-8);
+            }
+        }
+    }
+}
+
+#[test]
+fn price_tolerance_bounds_the_residual() {
+    let mut inputs = Inputs::new(
+        OptionType::Put,
+        100.0,
+        95.0,
+        None,
+        0.04,
+        0.01,
+        0.75,
+        Some(0.25),
+    );
+    inputs.p = Some(inputs.calc_price().unwrap());
+    inputs.sigma = None;
+    let p = inputs.p.unwrap();
+    for method in [
+        IvMethod::Newton,
+        IvMethod::Halley,
+        IvMethod::Brent,
+        IvMethod::Bisection,
+    ] {
+        let iv = inputs
+            .calc_iv_with(&options(method, IvTolerance::Price(1e-6)))
+            .unwrap();
+        let repriced = Inputs {
+            sigma: Some(iv),
+            ..inputs.clone()
+        };
+        assert!((repriced.calc_price().unwrap() - p).abs() <= 1e-6);
+    }
+}
+
+#[test]
+fn newton_survives_vanishing_vega() {
+    // Deep out of the money and short dated, where the vega at the initial guess is tiny.
+    for (option_type, k) in [(OptionType::Call, 160.0), (OptionType::Put, 60.0)] {
+        let mut inputs = Inputs::new(option_type, 100.0, k, None, 0.04, 0.01, 0.08, Some(0.9));
+        inputs.p = Some(inputs.calc_price().unwrap());
+        inputs.sigma = None;
+        for method in [IvMethod::Newton, IvMethod::Halley] {
+            let iv = inputs
+                .calc_iv_with(&options(method, IvTolerance::Volatility(1e-12)))
+                .unwrap();
+            assert_approx_eq!(iv, 0.9, 1e-7);
+        }
+    }
+}
+
+#[test]
+fn iteration_cap_is_enforced() {
+    let mut inputs = Inputs::new(
+        OptionType::Call,
+        100.0,
+        110.0,
+        None,
+        0.04,
+        0.01,
+        1.0,
+        Some(0.2),
+    );
+    inputs.p = Some(inputs.calc_price().unwrap());
+    inputs.sigma = None;
+    let capped = IvOptions::new(
+        IvMethod::Bisection,
+        5,
+        1e-6,
+        10.0,
+        IvTolerance::Volatility(1e-12),
+    );
+    assert!(inputs.calc_iv_with(&capped).is_err());
+    let enough = IvOptions {
+        max_iterations: 100,
+        ..capped
+    };
+    assert_approx_eq!(inputs.calc_iv_with(&enough).unwrap(), 0.2, 1e-10);
+}
+
+#[test]
+fn prices_outside_the_bracket_are_rejected() {
+    let mut inputs = Inputs::new(
+        OptionType::Call,
+        100.0,
+        100.0,
+        None,
+        0.04,
+        0.01,
+        0.5,
+        Some(0.2),
+    );
+    inputs.p = Some(inputs.calc_price().unwrap());
+    inputs.sigma = None;
+    let narrow = IvOptions {
+        lower: 0.3,
+        upper: 0.5,
+        ..IvOptions::default()
+    };
+    assert!(inputs.calc_iv_with(&narrow).is_err());
+    let high = IvOptions {
+        lower: 0.05,
+        upper: 0.1,
+        ..IvOptions::default()
+    };
+    assert!(inputs.calc_iv_with(&high).is_err());
+    let inverted = IvOptions {
+        lower: 0.5,
+        upper: 0.1,
+        ..IvOptions::default()
+    };
+    assert!(inputs.calc_iv_with(&inverted).is_err());
+    let missing = Inputs {
+        p: None,
+        ..inputs.clone()
+    };
+    assert!(missing.calc_iv_with(&IvOptions::default()).is_err());
+}
+
+#[test]
+fn result_reports_solve_diagnostics() {
+    let mut inputs = Inputs::new(
+        OptionType::Call,
+        100.0,
+        105.0,
+        None,
+        0.04,
+        0.01,
+        0.5,
+        Some(0.3),
+    );
+    inputs.p = Some(inputs.calc_price().unwrap());
+    inputs.sigma = None;
+    let newton = inputs
+        .calc_iv_result_with(&options(IvMethod::Newton, IvTolerance::Volatility(1e-12)))
+        .unwrap();
+    let bisection = inputs
+        .calc_iv_result_with(&options(
+            IvMethod::Bisection,
+            IvTolerance::Volatility(1e-12),
+        ))
+        .unwrap();
+    for result in [newton, bisection] {
+        assert_eq!(result.status, IvStatus::Converged);
+        assert_approx_eq!(result.sigma, 0.3, 1e-10);
+        assert!(result.residual.abs() < 1e-9);
+        let at_solution = Inputs {
+            sigma: Some(result.sigma),
+            ..inputs.clone()
+        };
+        assert_approx_eq!(result.vega, at_solution.calc_vega().unwrap(), 1e-12);
+    }
+    assert!(newton.iterations > 0 && newton.iterations < 10);
+    assert!(bisection.iterations > newton.iterations);
+
+    let rational = inputs.calc_rational_iv_result().unwrap();
+    assert_eq!(rational.status, IvStatus::Converged);
+    assert_eq!(rational.sigma, inputs.calc_rational_iv().unwrap());
+    assert!(rational.residual.abs() < 1e-12);
+}
+
+#[test]
+fn result_flags_prices_outside_the_attainable_range() {
+    let call = Inputs::new(OptionType::Call, 100.0, 90.0, None, 0.05, 0.0, 0.5, None);
+    let intrinsic = 100.0 - 90.0 * (-0.05_f64 * 0.5).exp();
+    let below = Inputs {
+        p: Some(intrinsic - 0.5),
+        ..call.clone()
+    };
+    let above = Inputs {
+        p: Some(100.5),
+        ..call.clone()
+    };
+    for result in [
+        below.calc_iv_result_with(&IvOptions::default()).unwrap(),
+        below.calc_rational_iv_result().unwrap(),
+    ] {
+        assert_eq!(result.status, IvStatus::BelowIntrinsic);
+        assert_eq!(result.sigma, 0.0);
+        assert_approx_eq!(result.residual, 0.5, 1e-10);
+    }
+    for result in [
+        above.calc_iv_result_with(&IvOptions::default()).unwrap(),
+        above.calc_rational_iv_result().unwrap(),
+    ] {
+        assert_eq!(result.status, IvStatus::AboveMaximum);
+        assert!(result.sigma.is_infinite());
+        assert_approx_eq!(result.residual, -0.5, 1e-10);
+    }
+    assert!(below.calc_iv_with(&IvOptions::default()).is_err());
+    assert!(above.calc_rational_iv().is_err());
+}
+
+#[test]
+fn result_clamps_to_the_bracket() {
+    let mut inputs = Inputs::new(
+        OptionType::Put,
+        100.0,
+        100.0,
+        None,
+        0.04,
+        0.01,
+        1.0,
+        Some(0.6),
+    );
+    inputs.p = Some(inputs.calc_price().unwrap());
+    inputs.sigma = None;
+    let bracket = IvOptions {
+        lower: 0.1,
+        upper: 0.4,
+        ..IvOptions::default()
+    };
+    let result = inputs.calc_iv_result_with(&bracket).unwrap();
+    assert_eq!(result.status, IvStatus::Clamped);
+    assert_eq!(result.sigma, 0.4);
+    assert!(result.residual < 0.0);
+    assert!(result.vega > 0.0);
+}
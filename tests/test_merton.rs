@@ -0,0 +1,151 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{ImpliedVolatility, Inputs, MertonInputs, MertonPricing, OptionType, Pricing};
+
+const UNDERLYING: Inputs = Inputs {
+    option_type: OptionType::Call,
+    s: 100.0,
+    k: 95.0,
+    p: None,
+    r: 0.05,
+    q: 0.01,
+    t: 0.25,
+    sigma: Some(0.2),
+};
+
+#[test]
+fn no_jumps_is_black_scholes() {
+    let inputs = MertonInputs::new(UNDERLYING, 0.0, -0.1, 0.15);
+    assert_approx_eq!(
+        inputs.calc_merton_price().unwrap(),
+        UNDERLYING.calc_price().unwrap(),
+        1e-12
+    );
+}
+
+#[test]
+fn matches_rate_adjusted_series() {
+    // Merton's original form: each term is priced at the rate r_n = r - lambda kappa + n ln(1 + kappa) / T
+    // and weighted with the intensity lambda (1 + kappa).
+    let (lambda, mu, delta) = (2.0, -0.08, 0.12);
+    let inputs = MertonInputs::new(UNDERLYING, lambda, mu, delta);
+    let kappa: f64 = (mu + 0.5 * delta * delta).exp() - 1.0;
+    let lambda_t = lambda * (1.0 + kappa) * UNDERLYING.t;
+    let mut weight = (-lambda_t).exp();
+    let mut expected = 0.0;
+    for n in 0..60 {
+        let n = n as f64;
+        let r_n = 0.05 - lambda * kappa + n * (1.0 + kappa).ln() / UNDERLYING.t;
+        let term = Inputs {
+            r: r_n,
+            sigma: Some((0.04 + n * delta * delta / UNDERLYING.t).sqrt()),
+            ..UNDERLYING
+        };
+        expected += weight * term.calc_price().unwrap();
+        weight *= lambda_t / (n + 1.0);
+    }
+    assert_approx_eq!(inputs.calc_merton_price().unwrap(), expected, 1e-10);
+}
+
+#[test]
+fn put_call_parity() {
+    let call = MertonInputs::new(UNDERLYING, 1.5, -0.1, 0.2);
+    let put = MertonInputs::new(
+        Inputs {
+            option_type: OptionType::Put,
+            ..UNDERLYING
+        },
+        1.5,
+        -0.1,
+        0.2,
+    );
+    let forward_value = 100.0 * (-0.01_f64 * 0.25).exp() - 95.0 * (-0.05_f64 * 0.25).exp();
+    assert_approx_eq!(
+        call.calc_merton_price().unwrap() - put.calc_merton_price().unwrap(),
+        forward_value,
+        1e-10
+    );
+}
+
+#[test]
+fn greeks_match_finite_differences() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let inputs = MertonInputs::new(
+            Inputs {
+                option_type,
+                ..UNDERLYING
+            },
+            3.0,
+            -0.05,
+            0.1,
+        );
+        let greeks = inputs.calc_all_merton_greeks().unwrap();
+        let price = |bump: &dyn Fn(&mut Inputs)| {
+            let mut bumped = inputs.clone();
+            bump(&mut bumped.underlying);
+            bumped.calc_merton_price().unwrap()
+        };
+        let h = 1e-4;
+        let delta = (price(&|u| u.s += h) - price(&|u| u.s -= h)) / (2.0 * h);
+        let gamma =
+            (price(&|u| u.s += 1e-2) - 2.0 * greeks["price"] + price(&|u| u.s -= 1e-2)) / 1e-4;
+        let vega = (price(&|u| u.sigma = Some(0.2 + h)) - price(&|u| u.sigma = Some(0.2 - h)))
+            / (2.0 * h)
+            / 100.0;
+        let theta = -(price(&|u| u.t += h) - price(&|u| u.t -= h)) / (2.0 * h) / 365.25;
+        let rho = (price(&|u| u.r += h) - price(&|u| u.r -= h)) / (2.0 * h) / 100.0;
+        let epsilon = (price(&|u| u.q += h) - price(&|u| u.q -= h)) / (2.0 * h);
+        assert_approx_eq!(greeks["delta"], delta, 1e-7);
+        assert_approx_eq!(greeks["gamma"], gamma, 1e-6);
+        assert_approx_eq!(greeks["vega"], vega, 1e-7);
+        assert_approx_eq!(greeks["theta"], theta, 1e-7);
+        assert_approx_eq!(greeks["rho"], rho, 1e-7);
+        assert_approx_eq!(greeks["epsilon"], epsilon, 1e-7);
+    }
+}
+
+#[test]
+fn calibration_recovers_jump_parameters() {
+    let market = Inputs {
+        t: 0.1,
+        ..UNDERLYING
+    };
+    let truth = MertonInputs::new(market.clone(), 0.8, -0.15, 0.1);
+    let strikes = [80.0, 85.0, 90.0, 95.0, 100.0, 105.0, 110.0, 115.0];
+    let vols: Vec<f64> = strikes
+        .iter()
+        .map(|&k| {
+            let mut priced = MertonInputs {
+                underlying: Inputs {
+                    k,
+                    ..market.clone()
+                },
+                ..truth.clone()
+            };
+            priced.underlying.p = Some(priced.calc_merton_price().unwrap());
+            priced.underlying.sigma = None;
+            priced.underlying.calc_rational_iv().unwrap()
+        })
+        .collect();
+    let calibration = MertonInputs::calibrate(&market, &strikes, &vols).unwrap();
+    assert!(calibration.converged);
+    assert!(calibration.rmse < 1e-5);
+    assert_approx_eq!(calibration.inputs.underlying.sigma.unwrap(), 0.2, 1e-2);
+    assert_approx_eq!(calibration.inputs.lambda, 0.8, 1e-2);
+    assert_approx_eq!(calibration.inputs.jump_mean, -0.15, 1e-2);
+    assert_approx_eq!(calibration.inputs.jump_vol, 0.1, 1e-2);
+}
+
+#[test]
+fn calibration_penalises_parameters_the_series_cannot_price() {
+    // From the starting intensity of one jump a year, the first simplex steps to more jumps in 600 years
+    // than the series can price.
+    let market = Inputs {
+        t: 600.0,
+        sigma: None,
+        ..UNDERLYING
+    };
+    let calibration =
+        MertonInputs::calibrate(&market, &[90.0, 100.0, 110.0], &[0.2, 0.2, 0.2]).unwrap();
+    assert!(calibration.rmse.is_finite());
+    assert!(calibration.inputs.lambda * market.t < 745.0);
+}
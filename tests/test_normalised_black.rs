@@ -0,0 +1,119 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{
+    lets_be_rational::{
+        black, implied_volatility_from_a_transformed_rational_guess_with_limited_iterations,
+        normalised_black,
+        normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations,
+        normalised_vega, normalised_volga, VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM,
+        VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC,
+    },
+    OptionType,
+};
+
+const OPTION_TYPES: [OptionType; 2] = [OptionType::Call, OptionType::Put];
+
+#[test]
+fn normalised_price_scales_to_black() {
+    for option_type in OPTION_TYPES {
+        for k in [60.0_f64, 95.0, 100.0, 130.0] {
+            let (f, sigma, t) = (100.0_f64, 0.3, 1.5_f64);
+            let b = normalised_black((f / k).ln(), sigma * t.sqrt(), option_type);
+            assert_approx_eq!(
+                (f * k).sqrt() * b,
+                black(f, k, sigma, t, option_type),
+                1e-11
+            );
+        }
+    }
+}
+
+#[test]
+fn expansion_boundaries_do_not_panic() {
+    // Total volatilities either side of the thresholds at which the small-t and asymptotic expansions are chosen.
+    for s in [0.1, 0.4215, 0.4224, 0.4227, 0.43] {
+        for x in [-5.0, -1.06, -0.1] {
+            let b = normalised_black(x, s, OptionType::Call);
+            assert!(b.is_finite() && b >= 0.0);
+        }
+    }
+}
+
+#[test]
+fn vega_and_volga_are_derivatives_in_total_volatility() {
+    let h = 1e-5;
+    for x in [-1.0, -0.2, 0.0, 0.3] {
+        for s in [0.1, 0.5, 1.2] {
+            let price = |s: f64| normalised_black(x, s, OptionType::Call);
+            let vega = (price(s + h) - price(s - h)) / (2.0 * h);
+            assert_approx_eq!(normalised_vega(x, s), vega, 1e-8);
+            let volga = (normalised_vega(x, s + h) - normalised_vega(x, s - h)) / (2.0 * h);
+            assert_approx_eq!(normalised_volga(x, s), volga, 1e-8 * (1.0 + volga.abs()));
+        }
+    }
+}
+
+#[test]
+fn normalised_implied_volatility_round_trips() {
+    for option_type in OPTION_TYPES {
+        // Out of the money, where the time value is not lost to rounding against the intrinsic value.
+        for moneyness in [1.0_f64, 0.5, 0.01, 0.0] {
+            let x: f64 = -(option_type * moneyness);
+            for s in [0.1, 0.3, 0.421, 1.0, 2.5] {
+                let beta = normalised_black(x, s, option_type);
+                let solved =
+                    normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
+                        beta,
+                        x,
+                        option_type,
+                        2,
+                    );
+                assert_approx_eq!(solved, s, 1e-12 * (1.0 + s));
+            }
+        }
+    }
+}
+
+#[test]
+fn iteration_count_controls_refinement() {
+    let (f, k, t, sigma) = (100.0, 120.0, 0.25, 0.35);
+    let price = black(f, k, sigma, t, OptionType::Call);
+    let error = |iterations: i32| {
+        (implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
+            price,
+            f,
+            k,
+            t,
+            OptionType::Call,
+            iterations,
+        ) - sigma)
+            .abs()
+    };
+    assert!(error(0) < 1e-4);
+    assert!(error(2) < 1e-14);
+    assert!(error(2) <= error(0));
+}
+
+#[test]
+fn sentinels_flag_unattainable_prices() {
+    let (x, option_type) = (0.4, OptionType::Call);
+    let intrinsic = normalised_black(x, 0.0, option_type);
+    assert_eq!(
+        normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
+            intrinsic - 1e-3,
+            x,
+            option_type,
+            2
+        ),
+        VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC
+    );
+    // A call is worth at most the forward, normalised to exp(x / 2).
+    assert_eq!(
+        normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
+            (0.5_f64 * x).exp(),
+            x,
+            option_type,
+            2
+        ),
+        VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM
+    );
+}
@@ -0,0 +1,74 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{ImpliedVolatility, Inputs, OptionType, ParityInputs, Pricing};
+
+const S: f64 = 100.0;
+const R: f64 = 0.045;
+const Q: f64 = 0.02;
+const T: f64 = 0.75;
+
+fn price(option_type: OptionType, k: f64) -> f64 {
+    let sigma = 0.2 + 0.001 * (k - 100.0).abs();
+    Inputs::new(option_type, S, k, None, R, Q, T, Some(sigma))
+        .calc_price()
+        .unwrap()
+}
+
+fn chain() -> ParityInputs {
+    let strikes: Vec<f64> = (0..15).map(|i| 70.0 + 5.0 * i as f64).collect();
+    let calls = strikes
+        .iter()
+        .map(|&k| price(OptionType::Call, k))
+        .collect();
+    let puts = strikes.iter().map(|&k| price(OptionType::Put, k)).collect();
+    ParityInputs::new(T, strikes, calls, puts)
+}
+
+#[test]
+fn recovers_forward_and_discount_factor() {
+    let fit = chain().calc_parity_fit().unwrap();
+    assert_approx_eq!(fit.discount_factor, (-R * T).exp(), 1e-12);
+    assert_approx_eq!(fit.forward, S * ((R - Q) * T).exp(), 1e-10);
+    assert_approx_eq!(fit.rate, R, 1e-12);
+    assert!(fit.residuals.iter().all(|residual| residual.abs() < 1e-10));
+}
+
+#[test]
+fn mispriced_pairs_do_not_move_the_fit() {
+    let mut inputs = chain();
+    inputs.calls[2] += 1.5;
+    inputs.puts[9] -= 0.8;
+    inputs.calls[13] += 0.6;
+    let fit = inputs.calc_parity_fit().unwrap();
+    assert_approx_eq!(fit.discount_factor, (-R * T).exp(), 1e-10);
+    assert_approx_eq!(fit.forward, S * ((R - Q) * T).exp(), 1e-8);
+    assert_approx_eq!(fit.residuals[2], 1.5, 1e-8);
+    assert_approx_eq!(fit.residuals[9], 0.8, 1e-8);
+    assert!(fit.residuals[5].abs() < 1e-8);
+}
+
+#[test]
+fn fit_drives_rational_implied_volatility() {
+    let fit = chain().calc_parity_fit().unwrap();
+    for (option_type, k) in [(OptionType::Call, 110.0), (OptionType::Put, 85.0)] {
+        let p = price(option_type, k);
+        let expected = Inputs::new(option_type, S, k, Some(p), R, Q, T, None)
+            .calc_rational_iv()
+            .unwrap();
+        let iv = fit
+            .inputs(option_type, k, Some(p))
+            .calc_rational_iv()
+            .unwrap();
+        assert_approx_eq!(iv, expected, 1e-9);
+    }
+}
+
+#[test]
+fn invalid_chains_are_rejected() {
+    let mut ragged = chain();
+    ragged.puts.pop();
+    assert!(ragged.calc_parity_fit().is_err());
+    let single = ParityInputs::new(T, vec![100.0], vec![5.0], vec![4.0]);
+    assert!(single.calc_parity_fit().is_err());
+    let expired = ParityInputs { t: 0.0, ..chain() };
+    assert!(expired.calc_parity_fit().is_err());
+}
@@ -0,0 +1,101 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{Inputs, NumericalGreeks, OptionType, PerpetualInputs, Pricing};
+
+const UNDERLYING: Inputs = Inputs {
+    option_type: OptionType::Put,
+    s: 100.0,
+    k: 90.0,
+    p: None,
+    r: 0.05,
+    q: 0.02,
+    t: 0.0,
+    sigma: Some(0.25),
+};
+
+fn perpetual(option_type: OptionType, s: f64) -> PerpetualInputs {
+    PerpetualInputs::new(Inputs {
+        option_type,
+        s,
+        ..UNDERLYING
+    })
+}
+
+#[test]
+fn satisfies_pricing_equation() {
+    // Before exercise the value solves sigma^2 S^2 V'' / 2 + (r - q) S V' - r V = 0.
+    let Inputs { r, q, .. } = UNDERLYING;
+    for (option_type, s) in [(OptionType::Put, 100.0), (OptionType::Call, 95.0)] {
+        let inputs = perpetual(option_type, s);
+        let price = inputs.calc_price().unwrap();
+        let delta = inputs.calc_delta().unwrap();
+        let gamma = inputs.calc_gamma().unwrap();
+        let residual = 0.5 * 0.0625 * s * s * gamma + (r - q) * s * delta - r * price;
+        assert_approx_eq!(residual, 0.0, 1e-5);
+    }
+}
+
+#[test]
+fn smooth_pasting_at_boundary() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let boundary = perpetual(option_type, 100.0)
+            .calc_exercise_boundary()
+            .unwrap();
+        let at = perpetual(option_type, boundary);
+        assert_approx_eq!(
+            at.calc_price().unwrap(),
+            option_type * (boundary - 90.0),
+            1e-10
+        );
+        // Just inside the continuation region the slope already matches the intrinsic value.
+        let inside = perpetual(option_type, boundary * (1.0 - option_type * 1e-3));
+        assert_approx_eq!(inside.calc_delta().unwrap(), option_type * 1.0_f64, 1e-2);
+        let exercised = perpetual(option_type, boundary * (1.0 + option_type * 0.1));
+        assert_approx_eq!(
+            exercised.calc_price().unwrap(),
+            option_type * (exercised.underlying.s - 90.0),
+            1e-12
+        );
+    }
+}
+
+#[test]
+fn bounded_by_finite_maturity_american_values() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let perpetual = perpetual(option_type, 100.0).calc_price().unwrap();
+        let european = Inputs {
+            option_type,
+            t: 30.0,
+            ..UNDERLYING
+        }
+        .calc_price()
+        .unwrap();
+        let intrinsic = (option_type * (100.0_f64 - 90.0)).max(0.0);
+        assert!(perpetual > intrinsic);
+        assert!(perpetual > european);
+    }
+}
+
+#[test]
+fn greeks_have_expected_signs() {
+    let put = perpetual(OptionType::Put, 100.0);
+    assert!(put.calc_delta().unwrap() < 0.0);
+    assert!(put.calc_gamma().unwrap() > 0.0);
+    assert!(put.calc_vega().unwrap() > 0.0);
+    assert!(put.calc_rho().unwrap() < 0.0);
+    assert_eq!(put.calc_theta().unwrap(), 0.0);
+}
+
+#[test]
+fn rejects_options_never_exercised() {
+    let call = PerpetualInputs::new(Inputs {
+        option_type: OptionType::Call,
+        q: 0.0,
+        ..UNDERLYING
+    });
+    assert!(call.calc_price().is_err());
+    let put = PerpetualInputs::new(Inputs {
+        r: 0.0,
+        ..UNDERLYING
+    });
+    assert!(put.calc_exercise_boundary().is_err());
+}
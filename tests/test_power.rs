@@ -0,0 +1,193 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{Inputs, OptionType, PowerInputs, PowerPayoff, PowerPricing, Pricing};
+
+const UNDERLYING: Inputs = Inputs {
+    option_type: OptionType::Call,
+    s: 100.0,
+    k: 95.0,
+    p: None,
+    r: 0.05,
+    q: 0.02,
+    t: 0.75,
+    sigma: Some(0.25),
+};
+
+const PAYOFFS: [PowerPayoff; 5] = [
+    PowerPayoff::Symmetric { exponent: 2 },
+    PowerPayoff::Asymmetric { exponent: 1.5 },
+    PowerPayoff::Asymmetric { exponent: -0.5 },
+    PowerPayoff::Capped {
+        exponent: 1.2,
+        cap: 20.0,
+    },
+    PowerPayoff::LogContract,
+];
+
+fn with_strike(payoff: PowerPayoff, option_type: OptionType) -> PowerInputs {
+    let k = match payoff {
+        PowerPayoff::Asymmetric { exponent } | PowerPayoff::Capped { exponent, .. } => {
+            95.0_f64.powf(exponent)
+        }
+        _ => 95.0,
+    };
+    PowerInputs::new(
+        payoff,
+        Inputs {
+            option_type,
+            k,
+            ..UNDERLYING
+        },
+    )
+}
+
+/// Discounted expectation of `payoff(S_T)` by integrating over the lognormal density.
+fn expectation(payoff: impl Fn(f64) -> f64) -> f64 {
+    let Inputs { s, r, q, t, .. } = UNDERLYING;
+    let sigma = 0.25;
+    let mean = s.ln() + (r - q - 0.5 * sigma * sigma) * t;
+    let width = sigma * t.sqrt();
+    let n = 200_000;
+    let h = 20.0 / n as f64;
+    let total: f64 = (0..=n)
+        .map(|i| {
+            let z = -10.0 + i as f64 * h;
+            let weight = if i == 0 || i == n { 0.5 } else { 1.0 };
+            weight * payoff((mean + width * z).exp()) * (-0.5 * z * z).exp()
+        })
+        .sum();
+    (-r * t).exp() * total * h / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+#[test]
+fn unit_exponent_is_vanilla() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let vanilla = Inputs {
+            option_type,
+            ..UNDERLYING
+        }
+        .calc_price()
+        .unwrap();
+        for payoff in [
+            PowerPayoff::Symmetric { exponent: 1 },
+            PowerPayoff::Asymmetric { exponent: 1.0 },
+        ] {
+            let inputs = with_strike(payoff, option_type);
+            assert_approx_eq!(inputs.calc_power_price().unwrap(), vanilla, 1e-10);
+        }
+    }
+}
+
+type Payoff = fn(f64) -> f64;
+
+#[test]
+fn prices_match_numerical_integration() {
+    let cases: [(PowerPayoff, OptionType, Payoff); 6] = [
+        (
+            PowerPayoff::Symmetric { exponent: 3 },
+            OptionType::Call,
+            |s| (s - 95.0).max(0.0).powi(3),
+        ),
+        (
+            PowerPayoff::Symmetric { exponent: 2 },
+            OptionType::Put,
+            |s| (95.0 - s).max(0.0).powi(2),
+        ),
+        (
+            PowerPayoff::Asymmetric { exponent: -0.5 },
+            OptionType::Call,
+            |s| (s.powf(-0.5) - 95.0_f64.powf(-0.5)).max(0.0),
+        ),
+        (
+            PowerPayoff::Capped {
+                exponent: 1.2,
+                cap: 20.0,
+            },
+            OptionType::Call,
+            |s| (s.powf(1.2) - 95.0_f64.powf(1.2)).clamp(0.0, 20.0),
+        ),
+        (
+            PowerPayoff::Capped {
+                exponent: 1.2,
+                cap: 20.0,
+            },
+            OptionType::Put,
+            |s| (95.0_f64.powf(1.2) - s.powf(1.2)).clamp(0.0, 20.0),
+        ),
+        (PowerPayoff::LogContract, OptionType::Call, |s| {
+            (s / 95.0).ln()
+        }),
+    ];
+    for (payoff, option_type, terminal) in cases {
+        let inputs = with_strike(payoff, option_type);
+        let expected = expectation(terminal);
+        assert_approx_eq!(
+            inputs.calc_power_price().unwrap(),
+            expected,
+            1e-8 * expected.abs().max(100.0)
+        );
+    }
+}
+
+#[test]
+fn large_cap_matches_uncapped() {
+    let capped = with_strike(
+        PowerPayoff::Capped {
+            exponent: 1.5,
+            cap: 1e6,
+        },
+        OptionType::Call,
+    );
+    let uncapped = with_strike(PowerPayoff::Asymmetric { exponent: 1.5 }, OptionType::Call);
+    assert_approx_eq!(
+        capped.calc_power_price().unwrap(),
+        uncapped.calc_power_price().unwrap(),
+        1e-10
+    );
+}
+
+#[test]
+fn greeks_match_finite_differences() {
+    for payoff in PAYOFFS {
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let inputs = with_strike(payoff, option_type);
+            let greeks = inputs.calc_all_power_greeks().unwrap();
+            let price = |bump: &dyn Fn(&mut Inputs)| {
+                let mut bumped = inputs.clone();
+                bump(&mut bumped.underlying);
+                bumped.calc_power_price().unwrap()
+            };
+            let h = 1e-4;
+            let delta = (price(&|u| u.s += h) - price(&|u| u.s -= h)) / (2.0 * h);
+            let gamma =
+                (price(&|u| u.s += 1e-2) - 2.0 * greeks["price"] + price(&|u| u.s -= 1e-2)) / 1e-4;
+            let vega = (price(&|u| u.sigma = Some(0.25 + h))
+                - price(&|u| u.sigma = Some(0.25 - h)))
+                / (2.0 * h)
+                / 100.0;
+            let rho = (price(&|u| u.r += h) - price(&|u| u.r -= h)) / (2.0 * h) / 100.0;
+            let epsilon = (price(&|u| u.q += h) - price(&|u| u.q -= h)) / (2.0 * h);
+            let theta = -(price(&|u| u.t += h) - price(&|u| u.t -= h)) / (2.0 * h) / 365.25;
+            let scale = greeks["price"].abs().max(1.0);
+            assert_approx_eq!(greeks["delta"], delta, 1e-6 * scale);
+            assert_approx_eq!(greeks["gamma"], gamma, 1e-5 * scale);
+            assert_approx_eq!(greeks["vega"], vega, 1e-6 * scale);
+            assert_approx_eq!(greeks["rho"], rho, 1e-6 * scale);
+            assert_approx_eq!(greeks["epsilon"], epsilon, 1e-6 * scale);
+            assert_approx_eq!(greeks["theta"], theta, 1e-6 * scale);
+        }
+    }
+}
+
+#[test]
+fn rejects_invalid_payoffs() {
+    let zero = with_strike(PowerPayoff::Symmetric { exponent: 0 }, OptionType::Call);
+    assert!(zero.calc_power_price().is_err());
+    let no_cap = with_strike(
+        PowerPayoff::Capped {
+            exponent: 2.0,
+            cap: 0.0,
+        },
+        OptionType::Call,
+    );
+    assert!(no_cap.calc_power_price().is_err());
+}
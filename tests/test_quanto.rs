@@ -0,0 +1,153 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{Inputs, OptionType, Pricing, QuantoInputs, QuantoPricing, QuantoStyle};
+
+const INPUTS: QuantoInputs = QuantoInputs {
+    option_type: OptionType::Call,
+    s: 100.0,
+    k: 105.0,
+    fx: 1.5,
+    r: 0.08,
+    r_foreign: 0.05,
+    q: 0.04,
+    t: 0.5,
+    sigma: 0.2,
+    sigma_fx: 0.1,
+    rho: 0.3,
+};
+
+const STYLES: [QuantoStyle; 3] = [
+    QuantoStyle::FixedRate { fixed_fx: 1.5 },
+    QuantoStyle::Composite,
+    QuantoStyle::EquityLinkedFx,
+];
+
+fn inputs_for(style: QuantoStyle, option_type: OptionType) -> QuantoInputs {
+    let k = match style {
+        QuantoStyle::FixedRate { .. } => 105.0,
+        QuantoStyle::Composite => 155.0,
+        QuantoStyle::EquityLinkedFx => 1.52,
+    };
+    QuantoInputs {
+        option_type,
+        k,
+        ..INPUTS
+    }
+}
+
+fn bumped_difference(
+    inputs: &QuantoInputs,
+    style: QuantoStyle,
+    bump: impl Fn(&mut QuantoInputs, f64),
+    h: f64,
+) -> f64 {
+    let mut up = inputs.clone();
+    bump(&mut up, h);
+    let mut down = inputs.clone();
+    bump(&mut down, -h);
+    (up.calc_quanto_price(style).unwrap() - down.calc_quanto_price(style).unwrap()) / (2.0 * h)
+}
+
+#[test]
+fn fixed_rate_quanto_price() {
+    // Haug (2007), The Complete Guide to Option Pricing Formulas, fixed exchange rate foreign equity option example.
+    let price = INPUTS
+        .calc_quanto_price(QuantoStyle::FixedRate { fixed_fx: 1.5 })
+        .unwrap();
+    assert_approx_eq!(price, 5.3280, 1e-4);
+}
+
+#[test]
+fn composite_without_fx_risk_is_vanilla() {
+    let inputs = QuantoInputs {
+        k: 155.0,
+        sigma_fx: 0.0,
+        ..INPUTS
+    };
+    let vanilla = Inputs::new(
+        OptionType::Call,
+        150.0,
+        155.0,
+        None,
+        0.08,
+        0.04,
+        0.5,
+        Some(0.2),
+    );
+    assert_approx_eq!(
+        inputs.calc_quanto_price(QuantoStyle::Composite).unwrap(),
+        vanilla.calc_price().unwrap(),
+        1e-12
+    );
+}
+
+#[test]
+fn put_call_parity() {
+    for style in STYLES {
+        let call = inputs_for(style, OptionType::Call);
+        let put = inputs_for(style, OptionType::Put);
+        let forward = match style {
+            QuantoStyle::FixedRate { fixed_fx } => {
+                let drift = 0.05 - 0.04 - 0.2 * 0.1 * 0.3;
+                fixed_fx * (100.0 * (drift * 0.5_f64).exp() - 105.0) * (-0.08 * 0.5_f64).exp()
+            }
+            QuantoStyle::Composite => {
+                150.0 * (-0.04 * 0.5_f64).exp() - 155.0 * (-0.08 * 0.5_f64).exp()
+            }
+            QuantoStyle::EquityLinkedFx => {
+                let drift = 0.05 - 0.2 * 0.1 * 0.3 - 0.04 - 0.08;
+                150.0 * (-0.04 * 0.5_f64).exp() - 1.52 * 100.0 * (drift * 0.5_f64).exp()
+            }
+        };
+        assert_approx_eq!(
+            call.calc_quanto_price(style).unwrap() - put.calc_quanto_price(style).unwrap(),
+            forward,
+            1e-10
+        );
+    }
+}
+
+#[test]
+fn greeks_match_finite_differences() {
+    for style in STYLES {
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let inputs = inputs_for(style, option_type);
+            let greeks = inputs.calc_all_quanto_greeks(style).unwrap();
+            let delta = bumped_difference(&inputs, style, |i, h| i.s += h, 1e-3);
+            let vega = bumped_difference(&inputs, style, |i, h| i.sigma += h, 1e-5) / 100.0;
+            let fx_vega = bumped_difference(&inputs, style, |i, h| i.sigma_fx += h, 1e-5) / 100.0;
+            let corr = bumped_difference(&inputs, style, |i, h| i.rho += h, 1e-5);
+            let rho = bumped_difference(&inputs, style, |i, h| i.r += h, 1e-5) / 100.0;
+            let theta = -bumped_difference(&inputs, style, |i, h| i.t += h, 1e-5) / 365.25;
+            assert_approx_eq!(greeks["delta"], delta, 1e-6);
+            assert_approx_eq!(greeks["vega"], vega, 1e-6);
+            assert_approx_eq!(greeks["fx_vega"], fx_vega, 1e-6);
+            assert_approx_eq!(greeks["corr_sensitivity"], corr, 1e-6);
+            assert_approx_eq!(greeks["rho"], rho, 1e-6);
+            assert_approx_eq!(greeks["theta"], theta, 1e-6);
+        }
+    }
+}
+
+#[test]
+fn gamma_matches_finite_differences() {
+    for style in STYLES {
+        let inputs = inputs_for(style, OptionType::Call);
+        let up = QuantoInputs {
+            s: 100.01,
+            ..inputs.clone()
+        };
+        let down = QuantoInputs {
+            s: 99.99,
+            ..inputs.clone()
+        };
+        let gamma =
+            (up.calc_quanto_delta(style).unwrap() - down.calc_quanto_delta(style).unwrap()) / 0.02;
+        assert_approx_eq!(inputs.calc_quanto_gamma(style).unwrap(), gamma, 1e-6);
+    }
+}
+
+#[test]
+fn rejects_invalid_correlation() {
+    let inputs = QuantoInputs { rho: 1.5, ..INPUTS };
+    assert!(inputs.calc_quanto_price(QuantoStyle::Composite).is_err());
+}
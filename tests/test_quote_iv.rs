@@ -0,0 +1,117 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{Greeks, ImpliedVolatility, Inputs, OptionType, Pricing, QuoteInputs};
+
+const UNDERLYING: Inputs = Inputs {
+    option_type: OptionType::Call,
+    s: 100.0,
+    k: 105.0,
+    p: None,
+    r: 0.04,
+    q: 0.01,
+    t: 0.5,
+    sigma: None,
+};
+
+fn price(sigma: f64) -> f64 {
+    Inputs {
+        sigma: Some(sigma),
+        ..UNDERLYING
+    }
+    .calc_price()
+    .unwrap()
+}
+
+fn rational_iv(p: f64) -> f64 {
+    Inputs {
+        p: Some(p),
+        ..UNDERLYING
+    }
+    .calc_rational_iv()
+    .unwrap()
+}
+
+#[test]
+fn sides_match_scalar_implied_volatility() {
+    let (bid, ask) = (price(0.22), price(0.24));
+    let quote = QuoteInputs::new(UNDERLYING, bid, ask)
+        .calc_quote_iv()
+        .unwrap();
+    assert_eq!(quote.bid.unwrap(), rational_iv(bid));
+    assert_eq!(quote.ask.unwrap(), rational_iv(ask));
+    assert_eq!(quote.mid.unwrap(), rational_iv(0.5 * (bid + ask)));
+    assert_approx_eq!(quote.bid.unwrap(), 0.22, 1e-10);
+    assert_approx_eq!(quote.ask.unwrap(), 0.24, 1e-10);
+}
+
+#[test]
+fn fair_volatility_is_vega_weighted() {
+    let (bid, ask) = (price(0.2), price(0.3));
+    let quote = QuoteInputs::new(UNDERLYING, bid, ask)
+        .calc_quote_iv()
+        .unwrap();
+    let vega = |sigma: f64| {
+        Inputs {
+            sigma: Some(sigma),
+            ..UNDERLYING
+        }
+        .calc_vega()
+        .unwrap()
+    };
+    let (bid_iv, ask_iv) = (quote.bid.unwrap(), quote.ask.unwrap());
+    let expected = (vega(bid_iv) * bid_iv + vega(ask_iv) * ask_iv) / (vega(bid_iv) + vega(ask_iv));
+    assert_approx_eq!(quote.fair.unwrap(), expected, 1e-14);
+    assert!(quote.fair.unwrap() > 0.2 && quote.fair.unwrap() < 0.3);
+}
+
+#[test]
+fn uncertainty_is_half_spread_over_vega() {
+    let (bid, ask) = (price(0.25) - 0.05, price(0.25) + 0.05);
+    let quote = QuoteInputs::new(UNDERLYING, bid, ask)
+        .calc_quote_iv()
+        .unwrap();
+    let vega = Inputs {
+        sigma: quote.mid,
+        ..UNDERLYING
+    }
+    .calc_vega()
+    .unwrap();
+    assert_approx_eq!(quote.uncertainty.unwrap(), 0.05 / (100.0 * vega), 1e-14);
+    // The uncertainty is about half the width of the bid-ask volatility range for a tight spread.
+    let width = quote.ask.unwrap() - quote.bid.unwrap();
+    assert_approx_eq!(quote.uncertainty.unwrap(), 0.5 * width, 1e-5);
+}
+
+#[test]
+fn unattainable_sides_have_no_volatility() {
+    let zero_bid = QuoteInputs::new(UNDERLYING, 0.0, price(0.2))
+        .calc_quote_iv()
+        .unwrap();
+    assert!(zero_bid.bid.is_none());
+    assert!(zero_bid.fair.is_none());
+    assert!(zero_bid.ask.is_some() && zero_bid.mid.is_some());
+    assert!(zero_bid.uncertainty.is_some());
+
+    let itm = Inputs {
+        k: 80.0,
+        ..UNDERLYING
+    };
+    let intrinsic = 100.0 * (-0.01_f64 * 0.5).exp() - 80.0 * (-0.04_f64 * 0.5).exp();
+    let below = QuoteInputs::new(itm, intrinsic - 0.5, intrinsic + 2.0)
+        .calc_quote_iv()
+        .unwrap();
+    assert!(below.bid.is_none());
+    assert!(below.ask.is_some());
+}
+
+#[test]
+fn crossed_or_negative_quotes_are_rejected() {
+    assert!(QuoteInputs::new(UNDERLYING, 3.0, 2.0)
+        .calc_quote_iv()
+        .is_err());
+    assert!(QuoteInputs::new(UNDERLYING, -1.0, 2.0)
+        .calc_quote_iv()
+        .is_err());
+    assert!(QuoteInputs::new(UNDERLYING, 0.0, 0.0)
+        .calc_quote_iv()
+        .is_err());
+}
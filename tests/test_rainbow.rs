@@ -0,0 +1,80 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{Inputs, OptionType, Pricing, RainbowPayoff, RainbowPricing, TwoAssetInputs};
+
+const INPUTS_RAINBOW: TwoAssetInputs = TwoAssetInputs {
+    option_type: OptionType::Call,
+    s1: 100.0,
+    s2: 105.0,
+    k: 98.0,
+    r: 0.05,
+    q1: 0.02,
+    q2: 0.01,
+    t: 0.5,
+    sigma1: 0.11,
+    sigma2: 0.16,
+    rho: 0.63,
+};
+
+fn vanilla(option_type: OptionType, s: f64, q: f64, sigma: f64) -> f64 {
+    Inputs::new(
+        option_type,
+        s,
+        INPUTS_RAINBOW.k,
+        None,
+        INPUTS_RAINBOW.r,
+        q,
+        INPUTS_RAINBOW.t,
+        Some(sigma),
+    )
+    .calc_price()
+    .unwrap()
+}
+
+#[test]
+fn best_of_plus_worst_of_equals_two_vanillas() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let inputs = TwoAssetInputs {
+            option_type,
+            ..INPUTS_RAINBOW
+        };
+        let best = inputs.calc_rainbow_price(RainbowPayoff::BestOf).unwrap();
+        let worst = inputs.calc_rainbow_price(RainbowPayoff::WorstOf).unwrap();
+        let vanillas =
+            vanilla(option_type, 100.0, 0.02, 0.11) + vanilla(option_type, 105.0, 0.01, 0.16);
+        assert_approx_eq!(best + worst, vanillas, 1e-10);
+    }
+}
+
+#[test]
+fn best_of_call_dominates_single_asset_calls() {
+    let best = INPUTS_RAINBOW
+        .calc_rainbow_price(RainbowPayoff::BestOf)
+        .unwrap();
+    let worst = INPUTS_RAINBOW
+        .calc_rainbow_price(RainbowPayoff::WorstOf)
+        .unwrap();
+    let call1 = vanilla(OptionType::Call, 100.0, 0.02, 0.11);
+    let call2 = vanilla(OptionType::Call, 105.0, 0.01, 0.16);
+    assert!(best > call1.max(call2));
+    assert!(worst < call1.min(call2));
+}
+
+#[test]
+fn correlation_option_reduces_to_vanilla_when_trigger_is_certain() {
+    let payoff = RainbowPayoff::Correlation {
+        trigger_strike: 1e-6,
+    };
+    let price = INPUTS_RAINBOW.calc_rainbow_price(payoff).unwrap();
+    assert_approx_eq!(price, vanilla(OptionType::Call, 105.0, 0.01, 0.16), 1e-10);
+}
+
+#[test]
+fn rainbow_greeks() {
+    let greeks = INPUTS_RAINBOW
+        .calc_all_rainbow_greeks(RainbowPayoff::BestOf)
+        .unwrap();
+    assert!(greeks["delta1"] > 0.0 && greeks["delta2"] > 0.0);
+    assert!(greeks["vega1"] > 0.0 && greeks["vega2"] > 0.0);
+    // A best-of call is worth less when the assets move together.
+    assert!(greeks["corr_sensitivity"] < 0.0);
+}
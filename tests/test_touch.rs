@@ -0,0 +1,204 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{
+    Inputs, NumericalGreeks, OptionType, PayoutCurrency, Settlement, Touch, TouchInputs,
+};
+
+const MARKET: Inputs = Inputs {
+    option_type: OptionType::Call,
+    s: 1.35,
+    k: 1.35,
+    p: None,
+    r: 0.03,
+    q: 0.02,
+    t: 0.5,
+    sigma: Some(0.1),
+};
+
+fn price(touch: Touch, currency: PayoutCurrency, market: &Inputs) -> f64 {
+    TouchInputs::new(touch, 1.0, currency, market.clone())
+        .calc_price()
+        .unwrap()
+}
+
+fn one_touch(barrier: f64, settlement: Settlement) -> Touch {
+    Touch::OneTouch {
+        barrier,
+        settlement,
+    }
+}
+
+#[test]
+fn one_touch_and_no_touch_sum_to_bond() {
+    for barrier in [1.25, 1.45] {
+        for currency in [PayoutCurrency::Domestic, PayoutCurrency::Foreign] {
+            let touch = price(one_touch(barrier, Settlement::AtExpiry), currency, &MARKET);
+            let no_touch = price(Touch::NoTouch { barrier }, currency, &MARKET);
+            let bond = match currency {
+                PayoutCurrency::Domestic => (-MARKET.r * MARKET.t).exp(),
+                PayoutCurrency::Foreign => MARKET.s * (-MARKET.q * MARKET.t).exp(),
+            };
+            assert_approx_eq!(touch + no_touch, bond, 1e-14);
+            assert!(touch > 0.0 && no_touch > 0.0);
+        }
+    }
+}
+
+#[test]
+fn hit_and_expiry_settlement_agree_without_discounting() {
+    let market = Inputs {
+        r: 0.0,
+        q: 0.01,
+        ..MARKET
+    };
+    for barrier in [1.25, 1.45] {
+        let at_hit = price(
+            one_touch(barrier, Settlement::AtHit),
+            PayoutCurrency::Domestic,
+            &market,
+        );
+        let at_expiry = price(
+            one_touch(barrier, Settlement::AtExpiry),
+            PayoutCurrency::Domestic,
+            &market,
+        );
+        assert_approx_eq!(at_hit, at_expiry, 1e-12);
+    }
+    // With positive rates, receiving the payout early is worth more.
+    let at_hit = price(
+        one_touch(1.45, Settlement::AtHit),
+        PayoutCurrency::Domestic,
+        &MARKET,
+    );
+    let at_expiry = price(
+        one_touch(1.45, Settlement::AtExpiry),
+        PayoutCurrency::Domestic,
+        &MARKET,
+    );
+    assert!(at_hit > at_expiry);
+}
+
+#[test]
+fn foreign_payout_matches_inverted_pair() {
+    // A foreign payout on the pair equals a domestic payout on the inverted pair, converted at spot.
+    let inverted = Inputs {
+        s: 1.0 / MARKET.s,
+        r: MARKET.q,
+        q: MARKET.r,
+        ..MARKET
+    };
+    let cases = [
+        (
+            one_touch(1.45, Settlement::AtHit),
+            one_touch(1.0 / 1.45, Settlement::AtHit),
+        ),
+        (
+            one_touch(1.25, Settlement::AtExpiry),
+            one_touch(1.0 / 1.25, Settlement::AtExpiry),
+        ),
+        (
+            Touch::NoTouch { barrier: 1.45 },
+            Touch::NoTouch {
+                barrier: 1.0 / 1.45,
+            },
+        ),
+        (
+            Touch::DoubleNoTouch {
+                lower: 1.25,
+                upper: 1.45,
+            },
+            Touch::DoubleNoTouch {
+                lower: 1.0 / 1.45,
+                upper: 1.0 / 1.25,
+            },
+        ),
+    ];
+    for (touch, mirrored) in cases {
+        assert_approx_eq!(
+            price(touch, PayoutCurrency::Foreign, &MARKET),
+            MARKET.s * price(mirrored, PayoutCurrency::Domestic, &inverted),
+            1e-12
+        );
+    }
+}
+
+#[test]
+fn double_no_touch_reduces_to_no_touch() {
+    for currency in [PayoutCurrency::Domestic, PayoutCurrency::Foreign] {
+        let single = price(Touch::NoTouch { barrier: 1.45 }, currency, &MARKET);
+        let double = price(
+            Touch::DoubleNoTouch {
+                lower: 0.5,
+                upper: 1.45,
+            },
+            currency,
+            &MARKET,
+        );
+        assert_approx_eq!(single, double, 1e-10);
+    }
+    let narrow = price(
+        Touch::DoubleNoTouch {
+            lower: 1.25,
+            upper: 1.45,
+        },
+        PayoutCurrency::Domestic,
+        &MARKET,
+    );
+    assert!(
+        narrow
+            < price(
+                Touch::NoTouch { barrier: 1.45 },
+                PayoutCurrency::Domestic,
+                &MARKET
+            )
+    );
+}
+
+#[test]
+fn greeks_and_touched_barriers() {
+    let up = TouchInputs::new(
+        one_touch(1.45, Settlement::AtHit),
+        1_000_000.0,
+        PayoutCurrency::Domestic,
+        MARKET,
+    );
+    assert!(up.calc_delta().unwrap() > 0.0);
+    assert!(up.calc_vega().unwrap() > 0.0);
+    let dnt = TouchInputs::new(
+        Touch::DoubleNoTouch {
+            lower: 1.25,
+            upper: 1.45,
+        },
+        1_000_000.0,
+        PayoutCurrency::Domestic,
+        MARKET,
+    );
+    assert!(dnt.calc_vega().unwrap() < 0.0);
+    assert!(dnt.calc_gamma().unwrap() < 0.0);
+
+    assert_eq!(
+        price(
+            one_touch(1.35, Settlement::AtHit),
+            PayoutCurrency::Domestic,
+            &MARKET
+        ),
+        1.0
+    );
+    assert_eq!(
+        price(
+            Touch::NoTouch { barrier: 1.35 },
+            PayoutCurrency::Domestic,
+            &MARKET
+        ),
+        0.0
+    );
+    let invalid = TouchInputs::new(
+        Touch::DoubleNoTouch {
+            lower: 1.45,
+            upper: 1.25,
+        },
+        1.0,
+        PayoutCurrency::Domestic,
+        MARKET,
+    );
+    assert!(invalid.calc_price().is_err());
+}
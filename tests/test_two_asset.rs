@@ -0,0 +1,124 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{Inputs, OptionType, Pricing, SpreadModel, SpreadPricing, TwoAssetInputs};
+use statrs::distribution::{ContinuousCDF, Normal};
+
+const INPUTS_EXCHANGE: TwoAssetInputs = TwoAssetInputs {
+    option_type: OptionType::Call,
+    s1: 22.0,
+    s2: 20.0,
+    k: 0.0,
+    r: 0.1,
+    q1: 0.06,
+    q2: 0.04,
+    t: 0.1,
+    sigma1: 0.2,
+    sigma2: 0.25,
+    rho: -0.5,
+};
+const INPUTS_SPREAD: TwoAssetInputs = TwoAssetInputs {
+    option_type: OptionType::Call,
+    s1: 122.0,
+    s2: 120.0,
+    k: 3.0,
+    r: 0.1,
+    q1: 0.1,
+    q2: 0.1,
+    t: 0.1,
+    sigma1: 0.2,
+    sigma2: 0.2,
+    rho: 0.5,
+};
+
+#[test]
+fn margrabe_matches_black_scholes_in_asset_two_numeraire() {
+    // With asset 2 as numeraire the exchange option is a call on S1/S2 struck at 1,
+    // where asset 2's yield plays the role of the rate.
+    let price = INPUTS_EXCHANGE
+        .calc_spread_price(SpreadModel::Margrabe)
+        .unwrap();
+    let sigma = (0.2_f64.powi(2) + 0.25_f64.powi(2) + 2.0 * 0.5 * 0.2 * 0.25).sqrt();
+    let inputs = Inputs::new(
+        OptionType::Call,
+        22.0 / 20.0,
+        1.0,
+        None,
+        0.04,
+        0.06,
+        0.1,
+        Some(sigma),
+    );
+    assert_approx_eq!(price, 20.0 * inputs.calc_price().unwrap(), 1e-12);
+}
+
+#[test]
+fn spread_models_reduce_to_margrabe_with_zero_strike() {
+    let margrabe = INPUTS_EXCHANGE
+        .calc_spread_price(SpreadModel::Margrabe)
+        .unwrap();
+    let kirk = INPUTS_EXCHANGE
+        .calc_spread_price(SpreadModel::Kirk)
+        .unwrap();
+    let bjerksund_stensland = INPUTS_EXCHANGE
+        .calc_spread_price(SpreadModel::BjerksundStensland)
+        .unwrap();
+    assert_approx_eq!(kirk, margrabe, 1e-12);
+    assert_approx_eq!(bjerksund_stensland, margrabe, 1e-12);
+}
+
+#[test]
+fn spread_put_call_parity() {
+    let put = TwoAssetInputs {
+        option_type: OptionType::Put,
+        ..INPUTS_SPREAD
+    };
+    let df = (-INPUTS_SPREAD.r * INPUTS_SPREAD.t).exp();
+    let forward_spread = INPUTS_SPREAD.s1 - INPUTS_SPREAD.s2 - INPUTS_SPREAD.k;
+    for model in [SpreadModel::Kirk, SpreadModel::BjerksundStensland] {
+        let call_price = INPUTS_SPREAD.calc_spread_price(model).unwrap();
+        let put_price = put.calc_spread_price(model).unwrap();
+        assert_approx_eq!(call_price - put_price, df * forward_spread, 1e-10);
+    }
+}
+
+#[test]
+fn kirk_and_bjerksund_stensland_agree_closely() {
+    let kirk = INPUTS_SPREAD.calc_spread_price(SpreadModel::Kirk).unwrap();
+    let bjerksund_stensland = INPUTS_SPREAD
+        .calc_spread_price(SpreadModel::BjerksundStensland)
+        .unwrap();
+    assert!((kirk - bjerksund_stensland).abs() < 0.01 * kirk);
+}
+
+#[test]
+fn margrabe_greeks_match_closed_form() {
+    let greeks = INPUTS_EXCHANGE
+        .calc_all_spread_greeks(SpreadModel::Margrabe)
+        .unwrap();
+    let t = INPUTS_EXCHANGE.t;
+    let sigma = (0.2_f64.powi(2) + 0.25_f64.powi(2) + 2.0 * 0.5 * 0.2 * 0.25).sqrt();
+    let d1 =
+        ((22.0_f64 / 20.0).ln() + (0.04 - 0.06 + 0.5 * sigma * sigma) * t) / (sigma * t.sqrt());
+    let d2 = d1 - sigma * t.sqrt();
+    let normal = Normal::standard();
+    assert_approx_eq!(greeks["delta1"], (-0.06 * t).exp() * normal.cdf(d1), 1e-6);
+    assert_approx_eq!(greeks["delta2"], -(-0.04 * t).exp() * normal.cdf(d2), 1e-6);
+    // Exchange options are homogeneous of degree one in (S1, S2).
+    assert_approx_eq!(
+        greeks["delta1"] * 22.0 + greeks["delta2"] * 20.0,
+        greeks["price"],
+        1e-6
+    );
+    assert!(greeks["gamma1"] > 0.0);
+    assert!(greeks["gamma2"] > 0.0);
+    assert!(greeks["cross_gamma"] < 0.0);
+    assert!(greeks["corr_sensitivity"] < 0.0);
+}
+
+#[test]
+fn invalid_correlation_is_an_error() {
+    let inputs = TwoAssetInputs {
+        rho: 1.5,
+        ..INPUTS_SPREAD
+    };
+    assert!(inputs.calc_spread_price(SpreadModel::Kirk).is_err());
+}
@@ -0,0 +1,150 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{Greeks, ImpliedVolatility, Inputs, OptionType, Pricing, VannaVolgaSmile};
+
+const MARKET: Inputs = Inputs {
+    option_type: OptionType::Call,
+    s: 1.35,
+    k: 1.35,
+    p: None,
+    r: 0.03,
+    q: 0.02,
+    t: 0.5,
+    sigma: Some(0.1),
+};
+
+fn smile() -> VannaVolgaSmile {
+    VannaVolgaSmile::new(MARKET, -0.012, 0.004)
+}
+
+fn vanilla(option_type: OptionType, k: f64, sigma: f64) -> Inputs {
+    Inputs {
+        option_type,
+        k,
+        sigma: Some(sigma),
+        ..MARKET
+    }
+}
+
+#[test]
+fn pivots_match_quoted_deltas() {
+    let [(k1, s1), (k2, s2), (k3, s3)] = smile().calc_pivots().unwrap();
+    assert_approx_eq!(s3 - s1, -0.012, 1e-15);
+    assert_approx_eq!(0.5 * (s1 + s3) - s2, 0.004, 1e-15);
+    assert_approx_eq!(
+        vanilla(OptionType::Put, k1, s1).calc_delta().unwrap(),
+        -0.25,
+        1e-12
+    );
+    assert_approx_eq!(
+        vanilla(OptionType::Call, k3, s3).calc_delta().unwrap(),
+        0.25,
+        1e-12
+    );
+    let straddle = vanilla(OptionType::Call, k2, s2).calc_delta().unwrap()
+        + vanilla(OptionType::Put, k2, s2).calc_delta().unwrap();
+    assert_approx_eq!(straddle, 0.0, 1e-12);
+}
+
+#[test]
+fn smile_is_pinned_at_pivots() {
+    let smile = smile();
+    for (k, vol) in smile.calc_pivots().unwrap() {
+        assert_approx_eq!(smile.calc_smile_vol(k).unwrap(), vol, 1e-12);
+        for option_type in [OptionType::Call, OptionType::Put] {
+            assert_approx_eq!(
+                smile.calc_vanilla_price(option_type, k).unwrap(),
+                vanilla(option_type, k, vol).calc_price().unwrap(),
+                1e-12
+            );
+        }
+    }
+}
+
+#[test]
+fn flat_quotes_give_black_scholes() {
+    let flat = VannaVolgaSmile::new(MARKET, 0.0, 0.0);
+    for k in [1.2, 1.3, 1.4, 1.5] {
+        assert_approx_eq!(flat.calc_smile_vol(k).unwrap(), 0.1, 1e-12);
+        assert_approx_eq!(
+            flat.calc_vanilla_price(OptionType::Call, k).unwrap(),
+            vanilla(OptionType::Call, k, 0.1).calc_price().unwrap(),
+            1e-12
+        );
+    }
+}
+
+#[test]
+fn vanilla_prices_are_consistent_with_smile() {
+    let smile = smile();
+    for k in [1.25, 1.3, 1.33, 1.38, 1.42, 1.45] {
+        let call = smile.calc_vanilla_price(OptionType::Call, k).unwrap();
+        let put = smile.calc_vanilla_price(OptionType::Put, k).unwrap();
+        let forward = MARKET.s * (-MARKET.q * MARKET.t).exp() - k * (-MARKET.r * MARKET.t).exp();
+        assert_approx_eq!(call - put, forward, 1e-12);
+        let iv = Inputs {
+            k,
+            p: Some(call),
+            sigma: None,
+            ..MARKET
+        }
+        .calc_rational_iv()
+        .unwrap();
+        assert_approx_eq!(iv, smile.calc_smile_vol(k).unwrap(), 2e-4);
+    }
+}
+
+#[test]
+fn exotic_adjustment_uses_survival_weight() {
+    let smile = smile();
+    let inputs = vanilla(OptionType::Call, 1.42, 0.1);
+    let (price, vega, vanna, vomma) = (
+        inputs.calc_price().unwrap(),
+        inputs.calc_vega().unwrap(),
+        inputs.calc_vanna().unwrap(),
+        inputs.calc_vomma().unwrap(),
+    );
+    let full = smile
+        .calc_exotic_price(price, vega, vanna, vomma, 1.0)
+        .unwrap();
+    assert_approx_eq!(
+        full,
+        smile.calc_vanilla_price(OptionType::Call, 1.42).unwrap(),
+        1e-14
+    );
+    let none = smile
+        .calc_exotic_price(price, vega, vanna, vomma, 0.0)
+        .unwrap();
+    assert_approx_eq!(none, price, 1e-14);
+    let half = smile
+        .calc_exotic_price(price, vega, vanna, vomma, 0.5)
+        .unwrap();
+    assert_approx_eq!(half, 0.5 * (full + price), 1e-14);
+    assert!(smile
+        .calc_exotic_price(price, vega, vanna, vomma, 1.5)
+        .is_err());
+}
+
+#[test]
+fn survival_probabilities() {
+    let smile = smile();
+    assert_eq!(smile.calc_survival_probability(None, None).unwrap(), 1.0);
+    assert_eq!(
+        smile.calc_survival_probability(Some(1.4), None).unwrap(),
+        0.0
+    );
+    let up = smile.calc_survival_probability(None, Some(1.45)).unwrap();
+    let down = smile.calc_survival_probability(Some(1.25), None).unwrap();
+    // A distant second barrier barely changes the single-barrier probabilities.
+    let up_double = smile
+        .calc_survival_probability(Some(0.5), Some(1.45))
+        .unwrap();
+    let down_double = smile
+        .calc_survival_probability(Some(1.25), Some(4.0))
+        .unwrap();
+    assert_approx_eq!(up, up_double, 1e-10);
+    assert_approx_eq!(down, down_double, 1e-10);
+    let both = smile
+        .calc_survival_probability(Some(1.25), Some(1.45))
+        .unwrap();
+    assert!(both < up.min(down) && both > up + down - 1.0);
+}
@@ -0,0 +1,79 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::VarianceSwapInputs;
+
+fn flat_strip(lower: u32, upper: u32, step: usize) -> VarianceSwapInputs {
+    let strikes: Vec<f64> = (lower..=upper).step_by(step).map(f64::from).collect();
+    VarianceSwapInputs::from_smile(100.0, 0.05, 0.02, 1.0, strikes, |_| 0.2).unwrap()
+}
+
+#[test]
+fn flat_smile_recovers_variance() {
+    let replication = flat_strip(50, 200, 1).calc_fair_variance().unwrap();
+    assert_approx_eq!(replication.fair_variance(), 0.04, 1e-6);
+    assert_approx_eq!(replication.fair_volatility(), 0.2, 1e-5);
+}
+
+#[test]
+fn corrections_handle_sparse_and_narrow_strips() {
+    // Wide spacing overstates the variance; the discretisation estimate removes most of it.
+    let sparse = flat_strip(60, 160, 10).calc_fair_variance().unwrap();
+    assert!(sparse.strip_variance - 0.04 > 1e-3);
+    assert_approx_eq!(sparse.fair_variance(), 0.04, 1e-4);
+
+    // A narrow strip misses the wings; the flat-wing extrapolation restores them.
+    let narrow = flat_strip(80, 125, 5).calc_fair_variance().unwrap();
+    assert!(0.04 - narrow.strip_variance > 4e-3);
+    assert_approx_eq!(narrow.fair_variance(), 0.04, 1e-4);
+}
+
+#[test]
+fn skew_raises_fair_variance_above_atm_variance() {
+    let strikes: Vec<f64> = (20..=300).map(f64::from).collect();
+    let inputs = VarianceSwapInputs::from_smile(100.0, 0.0, 0.0, 1.0, strikes, |k| {
+        0.2 - 0.1 * (k / 100.0).ln()
+    })
+    .unwrap();
+    let replication = inputs.calc_fair_variance().unwrap();
+    assert!(replication.fair_volatility() > 0.2);
+}
+
+#[test]
+fn corridors_add_up_to_the_full_swap() {
+    let full = flat_strip(50, 200, 1);
+    let forward = full.forward();
+    let mut down = full.clone();
+    down.corridor = Some((0.0, forward));
+    let mut up = full.clone();
+    up.corridor = Some((forward, f64::INFINITY));
+    let full = full.calc_fair_variance().unwrap().fair_variance();
+    let down = down.calc_fair_variance().unwrap().fair_variance();
+    let up = up.calc_fair_variance().unwrap().fair_variance();
+    assert!(down > 0.0 && up > 0.0);
+    assert_approx_eq!(down + up, full, 1e-12);
+}
+
+#[test]
+fn volatility_swap_convexity_adjustment() {
+    let replication = flat_strip(50, 200, 1).calc_fair_variance().unwrap();
+    assert_approx_eq!(
+        replication.volatility_swap_strike(0.0),
+        replication.fair_volatility(),
+        1e-15
+    );
+    let variance = replication.fair_variance();
+    assert_approx_eq!(
+        replication.volatility_swap_strike(0.0001),
+        variance.sqrt() - 0.0001 / (8.0 * variance.powf(1.5)),
+        1e-15
+    );
+}
+
+#[test]
+fn rejects_invalid_strips() {
+    let unsorted =
+        VarianceSwapInputs::new(100.0, 0.0, 0.0, 1.0, vec![90.0, 110.0, 100.0], vec![1.0; 3]);
+    assert!(unsorted.calc_fair_variance().is_err());
+    let mismatched =
+        VarianceSwapInputs::new(100.0, 0.0, 0.0, 1.0, vec![90.0, 100.0, 110.0], vec![1.0; 2]);
+    assert!(mismatched.calc_fair_variance().is_err());
+}
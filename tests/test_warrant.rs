@@ -0,0 +1,122 @@
+use assert_approx_eq::assert_approx_eq;
+use blackscholes::{Inputs, OptionType, Pricing, WarrantInputs, WarrantPricing};
+
+const UNDERLYING: Inputs = Inputs {
+    option_type: OptionType::Call,
+    s: 50.0,
+    k: 55.0,
+    p: None,
+    r: 0.04,
+    q: 0.01,
+    t: 3.0,
+    sigma: Some(0.3),
+};
+
+const SHARES: f64 = 1_000_000.0;
+const WARRANTS: f64 = 200_000.0;
+
+#[test]
+fn no_warrants_is_black_scholes() {
+    let inputs = WarrantInputs::new(UNDERLYING, SHARES, 0.0);
+    assert_approx_eq!(
+        inputs.calc_warrant_price().unwrap(),
+        UNDERLYING.calc_price().unwrap(),
+        1e-10
+    );
+}
+
+#[test]
+fn price_solves_dilution_fixed_point() {
+    let inputs = WarrantInputs::new(UNDERLYING, SHARES, WARRANTS);
+    let warrant = inputs.calc_warrant_price().unwrap();
+    let firm_call = Inputs {
+        s: UNDERLYING.s + WARRANTS / SHARES * warrant,
+        ..UNDERLYING
+    }
+    .calc_price()
+    .unwrap();
+    assert_approx_eq!(warrant, SHARES / (SHARES + WARRANTS) * firm_call, 1e-10);
+    assert!(warrant < UNDERLYING.calc_price().unwrap());
+}
+
+#[test]
+fn negative_dividend_yield_prices_above_spot() {
+    let underlying = Inputs {
+        k: 10.0,
+        q: -0.1,
+        ..UNDERLYING
+    };
+    let inputs = WarrantInputs::new(underlying.clone(), SHARES, WARRANTS);
+    let warrant = inputs.calc_warrant_price().unwrap();
+    let firm_call = Inputs {
+        s: underlying.s + WARRANTS / SHARES * warrant,
+        ..underlying.clone()
+    }
+    .calc_price()
+    .unwrap();
+    assert!(warrant > underlying.s);
+    assert_approx_eq!(warrant, SHARES / (SHARES + WARRANTS) * firm_call, 1e-10);
+}
+
+#[test]
+fn greeks_match_finite_differences() {
+    let inputs = WarrantInputs::new(UNDERLYING, SHARES, WARRANTS);
+    let greeks = inputs.calc_all_warrant_greeks().unwrap();
+    let price = |bump: &dyn Fn(&mut Inputs)| {
+        let mut bumped = inputs.clone();
+        bump(&mut bumped.underlying);
+        bumped.calc_warrant_price().unwrap()
+    };
+    let h = 1e-4;
+    let delta = (price(&|u| u.s += h) - price(&|u| u.s -= h)) / (2.0 * h);
+    let gamma = (price(&|u| u.s += 1e-2) - 2.0 * greeks["price"] + price(&|u| u.s -= 1e-2)) / 1e-4;
+    let vega = (price(&|u| u.sigma = Some(0.3 + h)) - price(&|u| u.sigma = Some(0.3 - h)))
+        / (2.0 * h)
+        / 100.0;
+    let theta = -(price(&|u| u.t += h) - price(&|u| u.t -= h)) / (2.0 * h) / 365.25;
+    let rho = (price(&|u| u.r += h) - price(&|u| u.r -= h)) / (2.0 * h) / 100.0;
+    let epsilon = (price(&|u| u.q += h) - price(&|u| u.q -= h)) / (2.0 * h);
+    assert_approx_eq!(greeks["delta"], delta, 1e-7);
+    assert_approx_eq!(greeks["gamma"], gamma, 1e-6);
+    assert_approx_eq!(greeks["vega"], vega, 1e-7);
+    assert_approx_eq!(greeks["theta"], theta, 1e-7);
+    assert_approx_eq!(greeks["rho"], rho, 1e-7);
+    assert_approx_eq!(greeks["epsilon"], epsilon, 1e-5);
+    assert_approx_eq!(inputs.calc_warrant_delta().unwrap(), greeks["delta"], 1e-15);
+    assert_approx_eq!(inputs.calc_warrant_gamma().unwrap(), greeks["gamma"], 1e-15);
+}
+
+#[test]
+fn implied_volatility_round_trips() {
+    for k in [40.0, 55.0, 80.0] {
+        let inputs = WarrantInputs::new(Inputs { k, ..UNDERLYING }, SHARES, WARRANTS);
+        let observed = inputs.calc_warrant_price().unwrap();
+        let quoted = WarrantInputs::new(
+            Inputs {
+                k,
+                p: Some(observed),
+                sigma: None,
+                ..UNDERLYING
+            },
+            SHARES,
+            WARRANTS,
+        );
+        assert_approx_eq!(quoted.calc_warrant_iv().unwrap(), 0.3, 1e-9);
+    }
+}
+
+#[test]
+fn rejects_invalid_inputs() {
+    let put = WarrantInputs::new(
+        Inputs {
+            option_type: OptionType::Put,
+            ..UNDERLYING
+        },
+        SHARES,
+        WARRANTS,
+    );
+    assert!(put.calc_warrant_price().is_err());
+    assert!(WarrantInputs::new(UNDERLYING, 0.0, WARRANTS)
+        .calc_warrant_price()
+        .is_err());
+}